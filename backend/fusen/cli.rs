@@ -0,0 +1,53 @@
+use anyhow::{anyhow, Result};
+use interface::archive::ArchiveFormat;
+use std::io::{self, Read, Write};
+use usecase::port::*;
+
+const USAGE: &str = "usage: app <export|import> <owner_id> <board_id> <ndjson|markdown_tar>";
+
+// export は標準出力に archive を書き出し、import は標準入力の archive を取り込む
+pub async fn run<Export, Import>(args: &[String], export: Export, import: Import) -> Result<()>
+where
+    Export: Port<ExportFusenInputData, ExportFusenOutputData>,
+    Import: Port<ImportFusenInputData, ImportFusenOutputData>,
+{
+    let (command, owner_id, board_id, format) = match args {
+        [command, owner_id, board_id, format] => (
+            command.as_str(),
+            owner_id.to_string(),
+            board_id.to_string(),
+            format.parse::<ArchiveFormat>()?,
+        ),
+        _ => return Err(anyhow!(USAGE)),
+    };
+
+    match command {
+        "export" => {
+            let mut output = export
+                .handle(ExportFusenInputData::new(owner_id, board_id))
+                .await?;
+            let mut stdout = io::stdout();
+            while let Some(fusen) = output.fusens.recv().await {
+                stdout.write_all(&format.encode(&fusen?))?;
+            }
+            stdout.write_all(&format.finish())?;
+            stdout.flush()?;
+        }
+        "import" => {
+            let mut data = Vec::new();
+            io::stdin().read_to_end(&mut data)?;
+            let (records, failures) = format.decode_records(&data);
+            let output = import
+                .handle(ImportFusenInputData::new(owner_id, board_id, records))
+                .await?;
+
+            for failure in failures.merge(output.failures) {
+                eprintln!("#{} {}: {}", failure.index, failure.id, failure.reason);
+            }
+            eprintln!("imported {} fusens", output.fusens.len());
+        }
+        _ => return Err(anyhow!(USAGE)),
+    }
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
+use crate::entity::Board as EntityBoard;
 use crate::entity::Entity;
 use crate::entity::Fusen as EntityFusen;
 
-pub trait AggregateRoot: Entity {}
+pub trait AggregateRoot: Entity + Send + Sync {}
 
 pub type Fusen = EntityFusen;
 impl AggregateRoot for Fusen {}
+
+pub type Board = EntityBoard;
+impl AggregateRoot for Board {}
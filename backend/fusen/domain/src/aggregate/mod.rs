@@ -2,4 +2,5 @@
 mod aggregate;
 
 pub use self::aggregate::AggregateRoot;
+pub use self::aggregate::Board;
 pub use self::aggregate::Fusen;
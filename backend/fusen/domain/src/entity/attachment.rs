@@ -0,0 +1,68 @@
+use crate::entity::{Entity, Fusen};
+use crate::vo::{Checksum, ContentType, FileName, Id, Timestamp};
+use derive_builder::Builder;
+use getset::Getters;
+
+// fusen に貼り付けたファイル。内容は BlobStore に保存し、ここには検証に使う情報だけを持つ
+#[derive(Clone, Debug, Getters, Builder, Eq)]
+#[builder(setter(into))]
+pub struct Attachment {
+    #[getset(get = "pub")]
+    id: Id<Attachment>,
+    #[getset(get = "pub")]
+    fusen_id: Id<Fusen>,
+
+    #[getset(get = "pub")]
+    file_name: FileName,
+    #[getset(get = "pub")]
+    content_type: ContentType,
+    // バイト数
+    #[getset(get = "pub")]
+    size: u64,
+    #[getset(get = "pub")]
+    checksum: Checksum,
+
+    #[getset(get = "pub")]
+    create_at: Timestamp,
+}
+
+impl Attachment {
+    // 添付ファイルは変更しないので id をそのまま BlobStore のキーに使う
+    pub fn blob_key(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Entity for Attachment {}
+
+impl PartialEq for Attachment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment() {
+        let attachment = AttachmentBuilder::default()
+            .id("01FNATTACHXKQ7B1GZ1Z0K0T8R".parse::<Id<Attachment>>().unwrap())
+            .fusen_id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .file_name("screenshot.png".parse::<FileName>().unwrap())
+            .content_type("image/png".parse::<ContentType>().unwrap())
+            .size(0u64)
+            .checksum(
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+                    .parse::<Checksum>()
+                    .unwrap(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(attachment.blob_key(), "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string());
+        assert_eq!(attachment.content_type().essence(), "image/png");
+    }
+}
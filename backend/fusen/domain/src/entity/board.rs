@@ -0,0 +1,120 @@
+use crate::entity::Entity;
+use crate::error::DomainError;
+use crate::vo::{BoardName, Id, OwnerId, Timestamp};
+use derive_builder::Builder;
+use getset::{Getters, Setters};
+
+#[derive(Clone, Debug, Getters, Setters, Builder, Eq)]
+#[builder(setter(into))]
+pub struct Board {
+    #[builder(pattern = "immutable")]
+    #[getset(get = "pub")]
+    id: Id<Board>,
+    #[builder(pattern = "immutable")]
+    #[getset(get = "pub")]
+    owner_id: OwnerId,
+
+    #[getset(get = "pub", set = "pub")]
+    name: BoardName,
+    // owner 以外で board の fusen を扱えるユーザー
+    #[builder(default)]
+    #[getset(get = "pub", set = "pub")]
+    member_ids: Vec<OwnerId>,
+
+    #[getset(get = "pub")]
+    create_at: Timestamp,
+    #[getset(get = "pub", set = "pub")]
+    update_at: Timestamp,
+}
+
+impl Board {
+    pub fn is_member(&self, owner_id: &OwnerId) -> bool {
+        &self.owner_id == owner_id || self.member_ids.contains(owner_id)
+    }
+
+    // board 内の fusen の作成・参照はメンバー全員に許可する
+    pub fn authorize(&self, owner_id: &OwnerId) -> Result<(), DomainError> {
+        if self.is_member(owner_id) {
+            Ok(())
+        } else {
+            Err(DomainError::PermissionDenied(format!(
+                "board {} is not shared with {}",
+                self.id.to_string(),
+                owner_id.to_string()
+            )))
+        }
+    }
+
+    // board 自体の変更・削除は owner のみに許可する
+    pub fn authorize_owner(&self, owner_id: &OwnerId) -> Result<(), DomainError> {
+        if &self.owner_id == owner_id {
+            Ok(())
+        } else {
+            Err(DomainError::PermissionDenied(format!(
+                "board {} is not owned by {}",
+                self.id.to_string(),
+                owner_id.to_string()
+            )))
+        }
+    }
+}
+
+impl Entity for Board {}
+
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_board() -> Board {
+        BoardBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Board>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_board() {
+        assert!(BoardBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Board>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+            .member_ids()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_board_authorize() {
+        let board = new_board();
+        let owner = "owner".parse::<OwnerId>().unwrap();
+        let member = "member".parse::<OwnerId>().unwrap();
+        let another = "another".parse::<OwnerId>().unwrap();
+
+        assert!(board.authorize(&owner).is_ok());
+        assert!(board.authorize(&member).is_ok());
+        assert!(matches!(
+            board.authorize(&another),
+            Err(DomainError::PermissionDenied(_))
+        ));
+
+        assert!(board.authorize_owner(&owner).is_ok());
+        assert!(matches!(
+            board.authorize_owner(&member),
+            Err(DomainError::PermissionDenied(_))
+        ));
+    }
+}
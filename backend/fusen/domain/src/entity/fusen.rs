@@ -1,5 +1,9 @@
-use crate::entity::Entity;
-use crate::vo::{FusenNote, FusenTitle, Id};
+use crate::entity::{Board, Entity};
+use crate::error::DomainError;
+use crate::event::{FusenCreated, FusenDeleted, FusenDomainEvent, FusenUpdated};
+use crate::vo::{
+    FusenColor, FusenNote, FusenSize, FusenTitle, Id, OwnerId, Position, Timestamp, ZIndex,
+};
 use derive_builder::Builder;
 use getset::{Getters, Setters};
 
@@ -9,11 +13,86 @@ pub struct Fusen {
     #[builder(pattern = "immutable")]
     #[getset(get = "pub")]
     id: Id<Fusen>,
+    #[builder(pattern = "immutable")]
+    #[getset(get = "pub")]
+    owner_id: OwnerId,
+    #[builder(pattern = "immutable")]
+    #[getset(get = "pub")]
+    board_id: Id<Board>,
 
     #[getset(get = "pub", set = "pub")]
     title: FusenTitle,
     #[getset(get = "pub", set = "pub")]
     note: FusenNote,
+
+    // 配置は未指定の場合に既定値を使う
+    #[builder(default)]
+    #[getset(get = "pub", set = "pub")]
+    position: Position,
+    #[builder(default)]
+    #[getset(get = "pub", set = "pub")]
+    size: FusenSize,
+    #[builder(default = "FusenColor::Yellow")]
+    #[getset(get = "pub", set = "pub")]
+    color: FusenColor,
+    #[builder(default)]
+    #[getset(get = "pub", set = "pub")]
+    z_index: ZIndex,
+
+    #[getset(get = "pub")]
+    create_at: Timestamp,
+    #[getset(get = "pub", set = "pub")]
+    update_at: Timestamp,
+
+    // 発行したドメインイベントは永続化するときにリポジトリが取り出す
+    #[builder(setter(skip))]
+    events: Vec<FusenDomainEvent>,
+}
+
+impl Fusen {
+    pub fn authorize(&self, owner_id: &OwnerId) -> Result<(), DomainError> {
+        if &self.owner_id == owner_id {
+            Ok(())
+        } else {
+            Err(DomainError::PermissionDenied(format!(
+                "fusen {} is not owned by {}",
+                self.id.to_string(),
+                owner_id.to_string()
+            )))
+        }
+    }
+
+    pub fn record_created(&mut self, occurred_at: Timestamp) {
+        let event = FusenCreated::new(self.snapshot(), occurred_at);
+        self.events.push(FusenDomainEvent::Created(event));
+    }
+
+    // 変更を終えてから呼び出す
+    pub fn record_updated(&mut self, occurred_at: Timestamp) {
+        let event = FusenUpdated::new(self.snapshot(), occurred_at);
+        self.events.push(FusenDomainEvent::Updated(event));
+    }
+
+    pub fn record_deleted(&mut self, occurred_at: Timestamp) {
+        let event = FusenDeleted::new(self.snapshot(), occurred_at);
+        self.events.push(FusenDomainEvent::Deleted(event));
+    }
+
+    pub fn events(&self) -> &[FusenDomainEvent] {
+        &self.events
+    }
+
+    pub fn take_events(&mut self) -> Vec<FusenDomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    // イベントに含める fusen は未発行のイベントを持たない
+    fn snapshot(&self) -> Fusen {
+        Fusen {
+            events: Vec::new(),
+            ..self.clone()
+        }
+    }
 }
 
 impl Entity for Fusen {}
@@ -27,14 +106,17 @@ impl PartialEq for Fusen {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::vo::{FusenNote, FusenTitle, Id};
 
     #[test]
     fn test_fusen() {
         assert!(FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id::<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .is_ok());
     }
@@ -43,8 +125,12 @@ mod tests {
     fn test_fusen_eq() {
         let before = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
@@ -53,13 +139,81 @@ mod tests {
 
         let another = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
         assert_eq!(before, after);
+        assert_eq!(before.position(), &Position::default());
+        assert_eq!(before.size(), &FusenSize::default());
+        assert_eq!(before.color(), &FusenColor::Yellow);
+        assert_eq!(before.z_index(), &ZIndex::default());
         assert_ne!(before, another);
         assert_ne!(after, another);
     }
+
+    #[test]
+    fn test_fusen_authorize() {
+        let fusen = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        assert!(fusen
+            .authorize(&"owner".parse::<OwnerId>().unwrap())
+            .is_ok());
+        assert!(matches!(
+            fusen.authorize(&"another".parse::<OwnerId>().unwrap()),
+            Err(DomainError::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn test_fusen_events() {
+        let mut fusen = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        assert!(fusen.events().is_empty());
+
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        fusen.record_created(now);
+        fusen.set_title("updated".parse::<FusenTitle>().unwrap());
+        fusen.record_updated(now);
+        fusen.record_deleted(now);
+
+        let events = fusen.take_events();
+        assert!(fusen.events().is_empty());
+        assert_eq!(
+            events.iter().map(|e| e.name()).collect::<Vec<_>>(),
+            vec!["FusenCreated", "FusenUpdated", "FusenDeleted"]
+        );
+        assert!(events.iter().all(|e| e.fusen_id() == fusen.id()));
+        assert!(events.iter().all(|e| e.occurred_at() == &now));
+        match &events[1] {
+            FusenDomainEvent::Updated(event) => {
+                assert_eq!(event.fusen().title().to_string(), "updated".to_string());
+                // 先に発行したイベントは含まない
+                assert!(event.fusen().events().is_empty());
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
 }
@@ -1,7 +1,11 @@
 #[allow(clippy::module_inception)]
 mod entity;
 
+mod attachment;
+mod board;
 mod fusen;
 
+pub use self::attachment::{Attachment, AttachmentBuilder};
+pub use self::board::{Board, BoardBuilder};
 pub use self::entity::Entity;
 pub use self::fusen::{Fusen, FusenBuilder};
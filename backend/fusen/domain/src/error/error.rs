@@ -0,0 +1,68 @@
+use crate::vo::TitleError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DomainError {
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+
+    // 受け付けない理由をクライアントにフィールド単位で返す
+    #[error("invalid argument: {0}")]
+    InvalidTitle(TitleError),
+
+    #[error("conflict: {0}")]
+    Conflict(String),
+
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("failed precondition: {0}")]
+    FailedPrecondition(String),
+
+    #[error("unavailable: {0}")]
+    Unavailable(String),
+
+    #[error(transparent)]
+    Unexpected(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_domain_error_to_string() {
+        assert_eq!(
+            DomainError::NotFound("fusen".to_string()).to_string(),
+            "not found: fusen".to_string()
+        );
+        assert_eq!(
+            DomainError::InvalidArgument("title".to_string()).to_string(),
+            "invalid argument: title".to_string()
+        );
+        assert_eq!(
+            DomainError::Conflict("fusen".to_string()).to_string(),
+            "conflict: fusen".to_string()
+        );
+        assert_eq!(
+            DomainError::PermissionDenied("fusen".to_string()).to_string(),
+            "permission denied: fusen".to_string()
+        );
+        assert_eq!(
+            DomainError::FailedPrecondition("board".to_string()).to_string(),
+            "failed precondition: board".to_string()
+        );
+        assert_eq!(
+            DomainError::Unavailable("database".to_string()).to_string(),
+            "unavailable: database".to_string()
+        );
+        assert_eq!(
+            DomainError::from(anyhow!("unexpected")).to_string(),
+            "unexpected".to_string()
+        );
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod error;
+
+pub use self::error::DomainError;
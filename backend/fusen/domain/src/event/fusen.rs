@@ -0,0 +1,114 @@
+use crate::entity::{Board, Fusen};
+use crate::vo::{Id, OwnerId, Timestamp};
+use derive_new::new;
+use getset::Getters;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FusenEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+// Deleted の場合は削除直前の fusen を持つ
+#[derive(new, Clone, Debug, Getters, PartialEq, Eq)]
+pub struct FusenEvent {
+    #[getset(get = "pub")]
+    kind: FusenEventKind,
+    #[getset(get = "pub")]
+    fusen: Fusen,
+    #[getset(get = "pub")]
+    occurred_at: Timestamp,
+}
+
+// 他のサービスに通知するために Fusen の集約が発行するドメインイベント。
+// 状態の変更と同じトランザクションで outbox に書き出す
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FusenDomainEvent {
+    Created(FusenCreated),
+    Updated(FusenUpdated),
+    Deleted(FusenDeleted),
+}
+
+// Watch に流すイベントはドメインイベントから作る
+impl From<&FusenDomainEvent> for FusenEvent {
+    fn from(event: &FusenDomainEvent) -> Self {
+        match event {
+            FusenDomainEvent::Created(e) => {
+                FusenEvent::new(FusenEventKind::Created, e.fusen().clone(), *e.occurred_at())
+            }
+            FusenDomainEvent::Updated(e) => {
+                FusenEvent::new(FusenEventKind::Updated, e.fusen().clone(), *e.occurred_at())
+            }
+            FusenDomainEvent::Deleted(e) => {
+                FusenEvent::new(FusenEventKind::Deleted, e.fusen().clone(), *e.occurred_at())
+            }
+        }
+    }
+}
+
+impl FusenDomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FusenDomainEvent::Created(_) => "FusenCreated",
+            FusenDomainEvent::Updated(_) => "FusenUpdated",
+            FusenDomainEvent::Deleted(_) => "FusenDeleted",
+        }
+    }
+
+    pub fn fusen_id(&self) -> &Id<Fusen> {
+        match self {
+            FusenDomainEvent::Created(event) => event.fusen().id(),
+            FusenDomainEvent::Updated(event) => event.fusen().id(),
+            FusenDomainEvent::Deleted(event) => event.id(),
+        }
+    }
+
+    pub fn occurred_at(&self) -> &Timestamp {
+        match self {
+            FusenDomainEvent::Created(event) => event.occurred_at(),
+            FusenDomainEvent::Updated(event) => event.occurred_at(),
+            FusenDomainEvent::Deleted(event) => event.occurred_at(),
+        }
+    }
+}
+
+#[derive(new, Clone, Debug, Getters, PartialEq, Eq)]
+pub struct FusenCreated {
+    #[getset(get = "pub")]
+    fusen: Fusen,
+    #[getset(get = "pub")]
+    occurred_at: Timestamp,
+}
+
+// 変更後の fusen を持つ
+#[derive(new, Clone, Debug, Getters, PartialEq, Eq)]
+pub struct FusenUpdated {
+    #[getset(get = "pub")]
+    fusen: Fusen,
+    #[getset(get = "pub")]
+    occurred_at: Timestamp,
+}
+
+// 削除直前の fusen を持つ
+#[derive(new, Clone, Debug, Getters, PartialEq, Eq)]
+pub struct FusenDeleted {
+    #[getset(get = "pub")]
+    fusen: Fusen,
+    #[getset(get = "pub")]
+    occurred_at: Timestamp,
+}
+
+impl FusenDeleted {
+    pub fn id(&self) -> &Id<Fusen> {
+        self.fusen.id()
+    }
+
+    pub fn owner_id(&self) -> &OwnerId {
+        self.fusen.owner_id()
+    }
+
+    pub fn board_id(&self) -> &Id<Board> {
+        self.fusen.board_id()
+    }
+}
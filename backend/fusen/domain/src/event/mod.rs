@@ -0,0 +1,5 @@
+mod fusen;
+
+pub use self::fusen::{
+    FusenCreated, FusenDeleted, FusenDomainEvent, FusenEvent, FusenEventKind, FusenUpdated,
+};
@@ -1,4 +1,6 @@
 pub mod aggregate;
 pub mod entity;
+pub mod error;
+pub mod event;
 pub mod repository;
 pub mod vo;
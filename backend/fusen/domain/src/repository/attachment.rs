@@ -0,0 +1,21 @@
+use crate::entity::{Attachment, Fusen};
+use crate::error::DomainError;
+use crate::vo::Id;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    // BlobStore に内容を書き込んでから呼び出す
+    async fn add(&self, attachment: Attachment) -> Result<(), DomainError>;
+
+    async fn get(&self, id: Id<Attachment>) -> Result<Attachment, DomainError>;
+
+    // id 順
+    async fn list(&self, fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError>;
+
+    // 削除された fusen に残っている添付ファイルを id 順に返す
+    async fn orphaned(&self, limit: usize) -> Result<Vec<Attachment>, DomainError>;
+
+    // BlobStore から内容を削除してから呼び出す。存在しない id は無視する
+    async fn remove(&self, ids: Vec<Id<Attachment>>) -> Result<(), DomainError>;
+}
@@ -0,0 +1,89 @@
+use crate::error::DomainError;
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::sync::Arc;
+
+pub type BlobStream = BoxStream<'static, Result<Vec<u8>, DomainError>>;
+
+// 添付ファイルの内容をキーごとに保存する。キーは英数字のみを使う
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    // content を最後まで読み込めた場合のみ保存し、書き込んだバイト数を返す。
+    // content がエラーを返した場合は途中まで書いた内容を残さず、そのエラーを返す
+    async fn put(&self, key: &str, content: BlobStream) -> Result<u64, DomainError>;
+
+    async fn get(&self, key: &str) -> Result<BlobStream, DomainError>;
+
+    // 存在しないキーは削除済みとして扱う
+    async fn delete(&self, key: &str) -> Result<(), DomainError>;
+}
+
+// 設定で選んだ実装を Arc<dyn BlobStore> で共有する
+#[async_trait]
+impl<T: BlobStore + ?Sized> BlobStore for Arc<T> {
+    async fn put(&self, key: &str, content: BlobStream) -> Result<u64, DomainError> {
+        (**self).put(key, content).await
+    }
+
+    async fn get(&self, key: &str) -> Result<BlobStream, DomainError> {
+        (**self).get(key).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DomainError> {
+        (**self).delete(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt, TryStreamExt};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct DummyBlobStore {
+        blobs: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl BlobStore for DummyBlobStore {
+        async fn put(&self, key: &str, content: BlobStream) -> Result<u64, DomainError> {
+            let content = content.try_concat().await?;
+            let size = content.len() as u64;
+            self.blobs.lock().unwrap().insert(key.to_string(), content);
+            Ok(size)
+        }
+
+        async fn get(&self, key: &str) -> Result<BlobStream, DomainError> {
+            match self.blobs.lock().unwrap().get(key) {
+                Some(content) => Ok(stream::iter(vec![Ok(content.clone())]).boxed()),
+                None => Err(DomainError::NotFound(format!("blob {}", key))),
+            }
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), DomainError> {
+            self.blobs.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blob_store_for_arc() {
+        let sut: Arc<dyn BlobStore> = Arc::new(DummyBlobStore::default());
+        let content = stream::iter(vec![Ok(b"ab".to_vec()), Ok(b"c".to_vec())]).boxed();
+
+        assert_eq!(sut.put("key", content).await.unwrap(), 3);
+        assert_eq!(
+            sut.get("key").await.unwrap().try_concat().await.unwrap(),
+            b"abc".to_vec()
+        );
+
+        sut.delete("key").await.unwrap();
+        assert!(sut.delete("key").await.is_ok());
+        assert!(matches!(
+            sut.get("key").await,
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::vo::Timestamp;
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Timestamp;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock {}
+
+    impl Clock for FixedClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_clock() {
+        let sut = FixedClock {};
+        assert_eq!(sut.now(), sut.now());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::DomainError;
+use crate::vo::{EventId, Timestamp};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+pub type EventStream<E> = BoxStream<'static, Result<(EventId, E), DomainError>>;
+
+#[async_trait]
+pub trait SubscribeRepository<E>: Send + Sync
+where
+    E: Send + 'static,
+{
+    // after を指定した場合はそれより後に発行されたイベントから、指定しない場合は購読開始以降のイベントを返す
+    async fn subscribe(&self, after: Option<EventId>) -> Result<EventStream<E>, DomainError>;
+}
+
+#[async_trait]
+pub trait PruneRepository: Send + Sync {
+    // before より前に発生したイベントを limit 件まで消し、消した件数を返す。
+    // 消したイベントの後からは購読を再開できない
+    async fn prune(&self, before: Timestamp, limit: usize) -> Result<usize, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct DummyEventRepository {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl DummyEventRepository {
+        fn push(&self, event: &str) -> EventId {
+            let mut events = self.events.lock().unwrap();
+            events.push(event.to_string());
+            EventId::new(events.len() as u64)
+        }
+    }
+
+    #[async_trait]
+    impl SubscribeRepository<String> for DummyEventRepository {
+        async fn subscribe(
+            &self,
+            after: Option<EventId>,
+        ) -> Result<EventStream<String>, DomainError> {
+            let after = after.map(|id| id.value()).unwrap_or(0);
+            let events = self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(|(i, event)| (EventId::new(i as u64 + 1), event.clone()))
+                .filter(|(id, _)| id.value() > after)
+                .map(Ok)
+                .collect::<Vec<_>>();
+
+            Ok(stream::iter(events).boxed())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_repository() {
+        let sut = DummyEventRepository::default();
+        assert_eq!(sut.push("a"), EventId::new(1));
+        assert_eq!(sut.push("b"), EventId::new(2));
+
+        let events = sut
+            .subscribe(Some(EventId::new(1)))
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].as_ref().unwrap(),
+            &(EventId::new(2), "b".to_string())
+        );
+    }
+}
@@ -1,34 +1,109 @@
 use crate::aggregate::AggregateRoot;
+use crate::error::DomainError;
+use crate::repository::ListQuery;
 use crate::vo::Id;
-use anyhow::{Error, Result};
+use async_trait::async_trait;
 
-pub trait CreateRepository<T>
+// 一括操作の一部が失敗した場合の扱い
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchMode {
+    // 最初の失敗を返し、ひとつも反映しない
+    AllOrNothing,
+    // 失敗したものだけを取り消し、要素ごとの結果を返す
+    PartialSuccess,
+}
+
+#[async_trait]
+pub trait CreateRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn create(&self, entity: T) -> Result<(), DomainError>;
+}
+
+// 結果は entities と同じ順に並ぶ
+#[async_trait]
+pub trait CreateAllRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn create_all(
+        &self,
+        entities: Vec<T>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError>;
+}
+
+#[async_trait]
+pub trait GetRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn get(&self, id: Id<T>) -> Result<T, DomainError>;
+}
+
+// 見つからない id は結果に含めない
+#[async_trait]
+pub trait GetAllRepository<T>: Send + Sync
 where
     T: AggregateRoot,
 {
-    fn create(&self, entity: T) -> Result<(), Error>;
+    async fn get_all(&self, ids: Vec<Id<T>>) -> Result<Vec<T>, DomainError>;
 }
 
-pub trait GetRepository<T>
+#[async_trait]
+pub trait ListRepository<T>: Send + Sync
 where
     T: AggregateRoot,
 {
-    fn get(&self, id: Id<T>) -> Result<T, Error>;
+    async fn list(&self, query: ListQuery<T>) -> Result<Vec<T>, DomainError>;
 }
 
-pub trait DeleteRepository<T>
+#[async_trait]
+pub trait UpdateRepository<T>: Send + Sync
 where
     T: AggregateRoot,
 {
-    fn delete(&self, entity: T) -> Result<(), Error>;
+    async fn update(&self, entity: T) -> Result<(), DomainError>;
+}
+
+// 位置と大きさ、重なり順と更新日時だけを書き戻し、他の項目への同時の更新を上書きしない。
+// すべて更新するか、ひとつも更新しない
+#[async_trait]
+pub trait MoveAllRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn move_all(&self, entities: Vec<T>) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+pub trait DeleteRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn delete(&self, entity: T) -> Result<(), DomainError>;
+}
+
+// 結果は entities と同じ順に並ぶ
+#[async_trait]
+pub trait DeleteAllRepository<T>: Send + Sync
+where
+    T: AggregateRoot,
+{
+    async fn delete_all(
+        &self,
+        entities: Vec<T>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError>;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::Entity;
+    use crate::repository::SortOrder;
     use crate::vo::Id;
-    use anyhow::bail;
     use std::collections::HashMap;
     use std::hash::Hash;
     use std::sync::Arc;
@@ -53,71 +128,311 @@ mod tests {
         }
     }
 
+    #[async_trait]
     impl CreateRepository<DummyEntity> for DummyEntityRepository {
-        fn create(&self, entity: DummyEntity) -> Result<(), Error> {
+        async fn create(&self, entity: DummyEntity) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             m.insert(entity.id.clone(), entity);
             Ok(())
         }
     }
 
+    #[async_trait]
+    impl CreateAllRepository<DummyEntity> for DummyEntityRepository {
+        async fn create_all(
+            &self,
+            entities: Vec<DummyEntity>,
+            mode: BatchMode,
+        ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+            let mut m = self.store.lock().unwrap();
+            let results = entities
+                .iter()
+                .map(|entity| match m.contains_key(&entity.id) {
+                    true => Err(DomainError::Conflict("duplicate entity".to_string())),
+                    false => Ok(()),
+                })
+                .collect::<Vec<_>>();
+            if mode == BatchMode::AllOrNothing && results.iter().any(Result::is_err) {
+                return Err(DomainError::Conflict("duplicate entity".to_string()));
+            }
+            for (entity, result) in entities.into_iter().zip(&results) {
+                if result.is_ok() {
+                    m.insert(entity.id.clone(), entity);
+                }
+            }
+            Ok(results)
+        }
+    }
+
+    #[async_trait]
     impl GetRepository<DummyEntity> for DummyEntityRepository {
-        fn get(&self, id: Id<DummyEntity>) -> Result<DummyEntity, Error> {
+        async fn get(&self, id: Id<DummyEntity>) -> Result<DummyEntity, DomainError> {
             let m = self.store.lock().unwrap();
             match m.get(&id) {
                 Some(aggregate_root) => Ok(aggregate_root.clone()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
+    #[async_trait]
+    impl GetAllRepository<DummyEntity> for DummyEntityRepository {
+        async fn get_all(
+            &self,
+            ids: Vec<Id<DummyEntity>>,
+        ) -> Result<Vec<DummyEntity>, DomainError> {
+            let m = self.store.lock().unwrap();
+            Ok(ids.iter().filter_map(|id| m.get(id).cloned()).collect())
+        }
+    }
+
+    #[async_trait]
+    impl ListRepository<DummyEntity> for DummyEntityRepository {
+        async fn list(
+            &self,
+            query: ListQuery<DummyEntity>,
+        ) -> Result<Vec<DummyEntity>, DomainError> {
+            let m = self.store.lock().unwrap();
+            let mut entities = m.values().cloned().collect::<Vec<_>>();
+            entities.sort_by_key(|entity| entity.id.to_string());
+            if query.order == SortOrder::Desc {
+                entities.reverse();
+            }
+            Ok(entities.into_iter().take(query.limit).collect())
+        }
+    }
+
+    #[async_trait]
+    impl UpdateRepository<DummyEntity> for DummyEntityRepository {
+        async fn update(&self, entity: DummyEntity) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            match m.get_mut(&entity.id) {
+                Some(aggregate_root) => {
+                    *aggregate_root = entity;
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MoveAllRepository<DummyEntity> for DummyEntityRepository {
+        async fn move_all(&self, entities: Vec<DummyEntity>) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            if entities.iter().any(|entity| !m.contains_key(&entity.id)) {
+                return Err(DomainError::NotFound("not found entity".to_string()));
+            }
+            for entity in entities {
+                m.insert(entity.id.clone(), entity);
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait]
     impl DeleteRepository<DummyEntity> for DummyEntityRepository {
-        fn delete(&self, entity: DummyEntity) -> Result<(), Error> {
+        async fn delete(&self, entity: DummyEntity) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             match m.remove(&entity.id) {
                 Some(_) => Ok(()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DeleteAllRepository<DummyEntity> for DummyEntityRepository {
+        async fn delete_all(
+            &self,
+            entities: Vec<DummyEntity>,
+            mode: BatchMode,
+        ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+            let mut m = self.store.lock().unwrap();
+            if mode == BatchMode::AllOrNothing
+                && entities.iter().any(|entity| !m.contains_key(&entity.id))
+            {
+                return Err(DomainError::NotFound("not found entity".to_string()));
             }
+            Ok(entities
+                .iter()
+                .map(|entity| match m.remove(&entity.id) {
+                    Some(_) => Ok(()),
+                    None => Err(DomainError::NotFound("not found entity".to_string())),
+                })
+                .collect())
         }
     }
 
-    #[test]
-    fn test_create_repository_for_entity() {
+    #[tokio::test]
+    async fn test_create_repository_for_entity() {
         let entity = DummyEntity {
-            id: "hogehoge".parse::<Id<DummyEntity>>().unwrap(),
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
         };
 
         let sut = DummyEntityRepository::new();
 
-        assert!(sut.create(entity).is_ok());
+        assert!(sut.create(entity).await.is_ok());
     }
 
-    #[test]
-    fn test_get_repository_for_entity() {
+    #[tokio::test]
+    async fn test_get_repository_for_entity() {
         let entity = DummyEntity {
-            id: "hogehoge".parse::<Id<DummyEntity>>().unwrap(),
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
         };
 
         let sut = DummyEntityRepository::new();
-        sut.create(entity).unwrap();
+        sut.create(entity).await.unwrap();
 
         assert!(sut
-            .get("hogehoge".parse::<Id<DummyEntity>>().unwrap())
+            .get(
+                "01F8MECHZX3TBDSZ7XRADM79XE"
+                    .parse::<Id<DummyEntity>>()
+                    .unwrap()
+            )
+            .await
             .is_ok());
     }
 
-    #[test]
-    fn test_delete_repository_for_entity() {
+    #[tokio::test]
+    async fn test_list_repository_for_entity() {
+        let sut = DummyEntityRepository::new();
+        sut.create(DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        })
+        .await
+        .unwrap();
+        sut.create(DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XF"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        })
+        .await
+        .unwrap();
+
+        let entities = sut
+            .list(ListQuery::new(None, 1, SortOrder::Desc, None, None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            entities,
+            vec![DummyEntity {
+                id: "01F8MECHZX3TBDSZ7XRADM79XF"
+                    .parse::<Id<DummyEntity>>()
+                    .unwrap(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_repository_for_entity() {
+        let entity = DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        };
+
+        let sut = DummyEntityRepository::new();
+
+        assert!(sut.update(entity.clone()).await.is_err());
+
+        sut.create(entity.clone()).await.unwrap();
+
+        assert!(sut.update(entity).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_move_all_repository_for_entity() {
         let entity = DummyEntity {
-            id: "hogehoge".parse::<Id<DummyEntity>>().unwrap(),
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        };
+        let missing = DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XF"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
         };
 
         let sut = DummyEntityRepository::new();
-        sut.create(entity).unwrap();
+        sut.create(entity.clone()).await.unwrap();
+
+        assert!(sut.move_all(vec![entity.clone()]).await.is_ok());
+        assert!(sut.move_all(vec![entity, missing]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_repository_for_entity() {
+        let entity = DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        };
+
+        let sut = DummyEntityRepository::new();
+        sut.create(entity).await.unwrap();
         let target = sut
-            .get("hogehoge".parse::<Id<DummyEntity>>().unwrap())
+            .get(
+                "01F8MECHZX3TBDSZ7XRADM79XE"
+                    .parse::<Id<DummyEntity>>()
+                    .unwrap(),
+            )
+            .await
             .unwrap();
 
-        assert!(sut.delete(target).is_ok());
+        assert!(sut.delete(target).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_repository_for_entity() {
+        let entity = DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        };
+        let another = DummyEntity {
+            id: "01F8MECHZX3TBDSZ7XRADM79XF"
+                .parse::<Id<DummyEntity>>()
+                .unwrap(),
+        };
+
+        let sut = DummyEntityRepository::new();
+        sut.create(entity.clone()).await.unwrap();
+
+        assert!(sut
+            .create_all(
+                vec![another.clone(), entity.clone()],
+                BatchMode::AllOrNothing
+            )
+            .await
+            .is_err());
+        assert_eq!(
+            sut.get_all(vec![entity.id.clone(), another.id.clone()])
+                .await
+                .unwrap(),
+            vec![entity.clone()]
+        );
+
+        let results = sut
+            .create_all(
+                vec![another.clone(), entity.clone()],
+                BatchMode::PartialSuccess,
+            )
+            .await
+            .unwrap();
+        assert!(results[0].is_ok() && results[1].is_err());
+
+        assert!(sut
+            .delete_all(vec![entity, another], BatchMode::AllOrNothing)
+            .await
+            .is_ok());
+        assert!(sut.get_all(vec![]).await.unwrap().is_empty());
     }
 }
@@ -1,8 +1,8 @@
+use crate::error::DomainError;
 use crate::vo::Id;
-use anyhow::{Error, Result};
 
-pub trait IdRepository {
-    fn generate<T>(&self) -> Result<Id<T>, Error>;
+pub trait IdRepository: Send + Sync {
+    fn generate<T>(&self) -> Result<Id<T>, DomainError>;
 }
 
 #[cfg(test)]
@@ -17,7 +17,7 @@ mod tests {
     struct MockIdRepository {}
 
     impl IdRepository for MockIdRepository {
-        fn generate<T>(&self) -> Result<Id<T>, Error> {
+        fn generate<T>(&self) -> Result<Id<T>, DomainError> {
             Ok("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<T>>().unwrap())
         }
     }
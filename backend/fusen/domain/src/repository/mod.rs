@@ -1,5 +1,24 @@
+mod attachment;
+mod blob;
+mod clock;
+mod event;
 mod fusen;
 mod id;
+mod outbox;
+mod query;
+mod renderer;
+mod unit_of_work;
 
-pub use self::fusen::{CreateRepository, DeleteRepository, GetRepository};
+pub use self::attachment::AttachmentRepository;
+pub use self::blob::{BlobStore, BlobStream};
+pub use self::clock::Clock;
+pub use self::event::{EventStream, PruneRepository, SubscribeRepository};
+pub use self::fusen::{
+    BatchMode, CreateAllRepository, CreateRepository, DeleteAllRepository, DeleteRepository,
+    GetAllRepository, GetRepository, ListRepository, MoveAllRepository, UpdateRepository,
+};
 pub use self::id::IdRepository;
+pub use self::outbox::{OutboxMessage, OutboxRepository};
+pub use self::query::{ListQuery, SortOrder};
+pub use self::renderer::NoteRenderer;
+pub use self::unit_of_work::UnitOfWork;
@@ -0,0 +1,34 @@
+use crate::error::DomainError;
+use crate::vo::{EventId, Timestamp};
+use async_trait::async_trait;
+use derive_new::new;
+use getset::Getters;
+use std::time::Duration;
+
+// 他のサービスに配信するまで outbox に保存するメッセージ。payload はシリアライズ済みのイベント
+#[derive(new, Clone, Debug, Getters, PartialEq, Eq)]
+pub struct OutboxMessage {
+    #[getset(get = "pub")]
+    id: EventId,
+    #[getset(get = "pub")]
+    event_type: String,
+    #[getset(get = "pub")]
+    aggregate_id: String,
+    #[getset(get = "pub")]
+    payload: String,
+    #[getset(get = "pub")]
+    occurred_at: Timestamp,
+}
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    // 未配信のメッセージを古い順に取り出す。lease の間は他の relay に取り出させない
+    async fn claim(&self, limit: usize, lease: Duration)
+        -> Result<Vec<OutboxMessage>, DomainError>;
+
+    // 配信を終えたメッセージを削除する
+    async fn acknowledge(&self, ids: Vec<EventId>) -> Result<(), DomainError>;
+
+    // 配信できなかったメッセージを lease の終了を待たずに取り出せるようにする
+    async fn release(&self, ids: Vec<EventId>) -> Result<(), DomainError>;
+}
@@ -0,0 +1,166 @@
+use crate::entity::Board;
+use crate::error::DomainError;
+use crate::vo::{Id, OwnerId};
+use derive_new::new;
+
+const PAGE_TOKEN_VERSION: &str = "v1";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+// id (ULID) の順序でページングする。
+// cursor 自体は含まず、order の順で cursor より後ろの集約だけを返す
+#[derive(new, Clone, Debug, PartialEq, Eq)]
+pub struct ListQuery<T> {
+    pub cursor: Option<Id<T>>,
+    pub limit: usize,
+    pub order: SortOrder,
+    // 集約のテキスト項目に対する大文字小文字を区別しない部分一致
+    pub keyword: Option<String>,
+    // 指定した owner の集約に絞り込む。board は owner ではなくメンバーかどうかで絞り込む
+    pub owner_id: Option<OwnerId>,
+    // 指定した board に置かれた集約に絞り込む
+    pub board_id: Option<Id<Board>>,
+}
+
+impl<T> ListQuery<T> {
+    // 次のページを取得するための page token を返す。
+    // 並び順や絞り込みの条件を変えて使われた場合に拒否できるよう、cursor と一緒に条件も詰める
+    pub fn page_token(&self, last: &Id<T>) -> String {
+        let token = format!("{}\n{}", self.conditions(), last.to_string());
+
+        base64::encode_config(token, base64::URL_SAFE_NO_PAD)
+    }
+
+    // page token の条件がこの query と一致する場合のみ cursor を設定する
+    pub fn with_page_token(mut self, token: &str) -> Result<Self, DomainError> {
+        let invalid = || DomainError::InvalidArgument("invalid page token".to_string());
+        let decoded = base64::decode_config(token, base64::URL_SAFE_NO_PAD)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let (conditions, cursor) = decoded.rsplit_once('\n').ok_or_else(invalid)?;
+        if conditions != self.conditions() {
+            return Err(DomainError::InvalidArgument(
+                "page token does not match the request".to_string(),
+            ));
+        }
+
+        self.cursor = Some(cursor.parse::<Id<T>>().map_err(|_| invalid())?);
+        Ok(self)
+    }
+
+    // keyword は改行を含みうるため最後に置く。id は改行を含まないので末尾の cursor と区別できる
+    fn conditions(&self) -> String {
+        let order = match self.order {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        };
+
+        format!(
+            "{}\n{}\n{}\n{}\n{}",
+            PAGE_TOKEN_VERSION,
+            order,
+            self.owner_id
+                .as_ref()
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            self.board_id
+                .as_ref()
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            self.keyword.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct Entity {}
+
+    fn new_query(order: SortOrder, keyword: Option<&str>) -> ListQuery<Entity> {
+        ListQuery::new(
+            None,
+            10,
+            order,
+            keyword.map(str::to_string),
+            None,
+            Some("01F8MECHZX3TBDSZ7XRADM79XG".parse::<Id<Board>>().unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_list_query() {
+        let query = ListQuery::<Entity>::new(
+            Some("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Entity>>().unwrap()),
+            10,
+            SortOrder::Desc,
+            None,
+            Some("owner".parse::<OwnerId>().unwrap()),
+            Some("01F8MECHZX3TBDSZ7XRADM79XG".parse::<Id<Board>>().unwrap()),
+        );
+
+        assert_eq!(
+            query.cursor,
+            Some("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Entity>>().unwrap())
+        );
+        assert_eq!(query.limit, 10);
+        assert_eq!(query.order, SortOrder::Desc);
+        assert_eq!(query.keyword, None);
+        assert_eq!(query.owner_id, Some("owner".parse::<OwnerId>().unwrap()));
+        assert_eq!(
+            query.board_id,
+            Some("01F8MECHZX3TBDSZ7XRADM79XG".parse::<Id<Board>>().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_page_token() {
+        let last = "01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Entity>>().unwrap();
+        let token = new_query(SortOrder::Desc, Some("rust\nclean")).page_token(&last);
+
+        // id をそのまま露出しない
+        assert!(!token.contains(&last.to_string()));
+        assert_eq!(
+            new_query(SortOrder::Desc, Some("rust\nclean"))
+                .with_page_token(&token)
+                .unwrap()
+                .cursor,
+            Some(last)
+        );
+
+        // 条件を変えて使うことはできない
+        for query in [
+            new_query(SortOrder::Asc, Some("rust\nclean")),
+            new_query(SortOrder::Desc, Some("rust")),
+            new_query(SortOrder::Desc, None),
+            ListQuery::new(None, 10, SortOrder::Desc, None, None, None),
+        ] {
+            assert!(matches!(
+                query.with_page_token(&token),
+                Err(DomainError::InvalidArgument(_))
+            ));
+        }
+
+        for token in [
+            "01F8MECHZX3TBDSZ7XRADM79XE",
+            "!",
+            &base64::encode_config(
+                "v0\ndesc\n\n\n\n01F8MECHZX3TBDSZ7XRADM79XE",
+                base64::URL_SAFE_NO_PAD,
+            ),
+        ] {
+            assert!(matches!(
+                new_query(SortOrder::Desc, None).with_page_token(token),
+                Err(DomainError::InvalidArgument(_))
+            ));
+        }
+    }
+}
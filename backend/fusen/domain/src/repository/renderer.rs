@@ -0,0 +1,28 @@
+use crate::vo::{FusenNote, RenderFormat};
+
+pub trait NoteRenderer: Send + Sync {
+    fn render(&self, note: &FusenNote, format: RenderFormat) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RawRenderer {}
+
+    impl NoteRenderer for RawRenderer {
+        fn render(&self, note: &FusenNote, _format: RenderFormat) -> String {
+            note.to_string()
+        }
+    }
+
+    #[test]
+    fn test_note_renderer() {
+        let sut = RawRenderer {};
+        let note = "**note**".parse::<FusenNote>().unwrap();
+        assert_eq!(
+            sut.render(&note, RenderFormat::Html),
+            sut.render(&note, RenderFormat::PlainText)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::DomainError;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+
+// 複数のリポジトリ操作をまとめて反映する。work がエラーを返した場合はひとつも反映しない
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    // work の中でのみ使えるリポジトリ
+    type Repositories: Send + Sync;
+
+    async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+            + Send
+            + 'static;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct DummyRepositories {
+        values: Mutex<Vec<u32>>,
+    }
+
+    #[derive(Default)]
+    struct DummyUnitOfWork {
+        committed: Mutex<Vec<u32>>,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for DummyUnitOfWork {
+        type Repositories = DummyRepositories;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            let repositories = DummyRepositories::default();
+            let result = work(&repositories).await;
+            if result.is_ok() {
+                let values = repositories.values.into_inner().unwrap();
+                self.committed.lock().unwrap().extend(values);
+            }
+            result
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work() {
+        let sut = DummyUnitOfWork::default();
+
+        assert!(sut
+            .run(|repositories| {
+                async move {
+                    repositories.values.lock().unwrap().push(1);
+                    Ok(())
+                }
+                .boxed()
+            })
+            .await
+            .is_ok());
+        assert!(sut
+            .run(|repositories| {
+                async move {
+                    repositories.values.lock().unwrap().push(2);
+                    Err::<(), _>(DomainError::Conflict("value".to_string()))
+                }
+                .boxed()
+            })
+            .await
+            .is_err());
+
+        assert_eq!(*sut.committed.lock().unwrap(), vec![1]);
+    }
+}
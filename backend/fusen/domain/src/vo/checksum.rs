@@ -0,0 +1,74 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+const SHA256_HEX_LENGTH: usize = 64;
+
+// 添付ファイルの内容の SHA-256 を小文字の16進数で保持する
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checksum(String);
+
+impl ValueObject for Checksum {}
+
+impl Checksum {
+    pub fn from_sha256(digest: &[u8]) -> Result<Self, DomainError> {
+        digest
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+            .parse()
+    }
+}
+
+impl FromStr for Checksum {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != SHA256_HEX_LENGTH || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid checksum {}",
+                s
+            )));
+        }
+
+        Ok(Self(s.to_ascii_lowercase()))
+    }
+}
+
+impl ToString for Checksum {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(
+            EMPTY_SHA256
+                .to_ascii_uppercase()
+                .parse::<Checksum>()
+                .unwrap()
+                .to_string(),
+            EMPTY_SHA256.to_string()
+        );
+        assert_eq!(
+            Checksum::from_sha256(&[0xab; 32]).unwrap().to_string(),
+            "ab".repeat(32)
+        );
+
+        assert!("".parse::<Checksum>().is_err());
+        assert!(EMPTY_SHA256[1..].parse::<Checksum>().is_err());
+        assert!("g".repeat(SHA256_HEX_LENGTH).parse::<Checksum>().is_err());
+        assert!(matches!(
+            Checksum::from_sha256(&[0; 16]),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
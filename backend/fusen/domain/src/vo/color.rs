@@ -0,0 +1,69 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+// 付箋の色は決まったパレットから選ぶ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FusenColor {
+    Yellow,
+    Pink,
+    Blue,
+    Green,
+    Orange,
+    Purple,
+}
+
+impl ValueObject for FusenColor {}
+
+impl FromStr for FusenColor {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "yellow" => Ok(FusenColor::Yellow),
+            "pink" => Ok(FusenColor::Pink),
+            "blue" => Ok(FusenColor::Blue),
+            "green" => Ok(FusenColor::Green),
+            "orange" => Ok(FusenColor::Orange),
+            "purple" => Ok(FusenColor::Purple),
+            _ => Err(DomainError::InvalidArgument(format!("invalid color {}", s))),
+        }
+    }
+}
+
+impl ToString for FusenColor {
+    fn to_string(&self) -> String {
+        match self {
+            FusenColor::Yellow => "yellow",
+            FusenColor::Pink => "pink",
+            FusenColor::Blue => "blue",
+            FusenColor::Green => "green",
+            FusenColor::Orange => "orange",
+            FusenColor::Purple => "purple",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fusen_color() {
+        for color in &["yellow", "pink", "blue", "green", "orange", "purple"] {
+            assert_eq!(
+                color.parse::<FusenColor>().unwrap().to_string(),
+                color.to_string()
+            );
+        }
+
+        assert!("".parse::<FusenColor>().is_err());
+        assert!("Yellow".parse::<FusenColor>().is_err());
+        assert!(matches!(
+            "#ffff00".parse::<FusenColor>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+const MAX_LENGTH: usize = 255;
+
+// MIME タイプ。type/subtype は小文字に揃え、charset などのパラメータはそのまま残す
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContentType {
+    essence: String,
+    parameters: String,
+}
+
+impl ValueObject for ContentType {}
+
+impl ContentType {
+    // パラメータを除いた type/subtype
+    pub fn essence(&self) -> &str {
+        &self.essence
+    }
+
+    // pattern は image/png のような完全一致か、image/* のような type 単位の指定
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim().to_ascii_lowercase();
+        match pattern.strip_suffix("/*") {
+            Some("*") => true,
+            Some(kind) => self.essence.split('/').next() == Some(kind),
+            None => self.essence == pattern,
+        }
+    }
+}
+
+impl FromStr for ContentType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DomainError::InvalidArgument(format!("invalid content type {}", s));
+        if s.len() > MAX_LENGTH || s.chars().any(char::is_control) {
+            return Err(invalid());
+        }
+
+        let (essence, parameters) = match s.split_once(';') {
+            Some((essence, parameters)) => (essence, parameters.trim()),
+            None => (s, ""),
+        };
+        let essence = essence.trim().to_ascii_lowercase();
+        match essence.split_once('/') {
+            Some((kind, subtype)) if is_token(kind) && is_token(subtype) => Ok(Self {
+                essence,
+                parameters: parameters.to_string(),
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl ToString for ContentType {
+    fn to_string(&self) -> String {
+        if self.parameters.is_empty() {
+            self.essence.clone()
+        } else {
+            format!("{}; {}", self.essence, self.parameters)
+        }
+    }
+}
+
+// RFC 6838 の restricted-name
+fn is_token(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type() {
+        let content_type = "Text/Plain;charset=UTF-8".parse::<ContentType>().unwrap();
+        assert_eq!(content_type.essence(), "text/plain");
+        assert_eq!(
+            content_type.to_string(),
+            "text/plain; charset=UTF-8".to_string()
+        );
+        assert_eq!(
+            "image/svg+xml".parse::<ContentType>().unwrap().to_string(),
+            "image/svg+xml".to_string()
+        );
+
+        assert!("".parse::<ContentType>().is_err());
+        assert!("image".parse::<ContentType>().is_err());
+        assert!("image/".parse::<ContentType>().is_err());
+        assert!("image/png png".parse::<ContentType>().is_err());
+        assert!(matches!(
+            "image/png\n".parse::<ContentType>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_content_type_matches() {
+        let content_type = "image/png".parse::<ContentType>().unwrap();
+
+        assert!(content_type.matches("image/png"));
+        assert!(content_type.matches("IMAGE/*"));
+        assert!(content_type.matches("*/*"));
+        assert!(!content_type.matches("image/jpeg"));
+        assert!(!content_type.matches("application/*"));
+        assert!(!content_type.matches("image"));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+// イベントの発行順に採番される連番
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId(u64);
+
+impl ValueObject for EventId {}
+
+impl EventId {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for EventId {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<u64>() {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(DomainError::InvalidArgument(format!(
+                "invalid event id {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToString for EventId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_id() {
+        assert_eq!("42".parse::<EventId>().unwrap(), EventId::new(42));
+        assert_eq!(EventId::new(42).to_string(), "42".to_string());
+        assert!(EventId::new(1) < EventId::new(2));
+
+        assert!("".parse::<EventId>().is_err());
+        assert!("-1".parse::<EventId>().is_err());
+        assert!("01F8MECHZX3TBDSZ7XRADM79XE".parse::<EventId>().is_err());
+    }
+}
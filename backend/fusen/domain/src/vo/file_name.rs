@@ -0,0 +1,63 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+const MAX_LENGTH: usize = 255;
+
+// 添付ファイルの名前。ダウンロード時に返すだけなので、パスの区切り文字は含めない
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileName(String);
+
+impl ValueObject for FileName {}
+
+impl FromStr for FileName {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty()
+            || s.chars().count() > MAX_LENGTH
+            || s.chars().any(|c| c.is_control() || c == '/' || c == '\\')
+        {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid file name {}",
+                s
+            )));
+        }
+
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl ToString for FileName {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(
+            "スクリーンショット 1.png"
+                .parse::<FileName>()
+                .unwrap()
+                .to_string(),
+            "スクリーンショット 1.png".to_string()
+        );
+        assert!("a".repeat(MAX_LENGTH).parse::<FileName>().is_ok());
+
+        assert!("".parse::<FileName>().is_err());
+        assert!("  ".parse::<FileName>().is_err());
+        assert!("a".repeat(MAX_LENGTH + 1).parse::<FileName>().is_err());
+        assert!("../secret".parse::<FileName>().is_err());
+        assert!("C:\\secret".parse::<FileName>().is_err());
+        assert!(matches!(
+            "a\nb".parse::<FileName>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
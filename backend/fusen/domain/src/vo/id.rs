@@ -1,28 +1,51 @@
-use crate::vo::ValueObject;
-use anyhow::Error;
+use crate::error::DomainError;
+use crate::vo::{Timestamp, ValueObject};
+use chrono::{DateTime, Utc};
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use std::string::ToString;
+use std::time::{Duration, UNIX_EPOCH};
+use ulid::Ulid;
 
 #[derive(Default, Clone, Debug, Eq)]
 pub struct Id<T> {
     value: String,
+    timestamp_ms: u64,
 
     _phantom: PhantomData<T>,
 }
 
 impl<T> ValueObject for Id<T> {}
 
+impl<T> Id<T> {
+    // ULID の先頭 48bit に埋め込まれた生成時刻
+    pub fn timestamp(&self) -> Timestamp {
+        Timestamp::new(DateTime::<Utc>::from(
+            UNIX_EPOCH + Duration::from_millis(self.timestamp_ms),
+        ))
+    }
+}
+
 impl<T> FromStr for Id<T> {
-    type Err = Error;
+    type Err = DomainError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            value: s.to_string(),
-            _phantom: PhantomData,
-        })
+        // 先頭が 8 以上だと 128bit に収まらない
+        if !s.starts_with(|c: char| ('0'..='7').contains(&c)) {
+            return Err(DomainError::InvalidArgument(format!("invalid id {}", s)));
+        }
+
+        match Ulid::from_string(s) {
+            // 小文字で渡されても大文字の正規形で保持する
+            Ok(ulid) => Ok(Self {
+                value: ulid.to_string(),
+                timestamp_ms: ulid.timestamp_ms(),
+                _phantom: PhantomData,
+            }),
+            Err(_) => Err(DomainError::InvalidArgument(format!("invalid id {}", s))),
+        }
     }
 }
 
@@ -68,6 +91,50 @@ mod tests {
             .is_ok());
     }
 
+    #[test]
+    fn test_id_invalid() {
+        assert!("".parse::<Id<Entity>>().is_err());
+        assert!("NOTFOUND_ID".parse::<Id<Entity>>().is_err());
+        // 26文字だがCrockford Base32 に含まれない文字 (U)
+        assert!("01F8MECHZX3TBDSZ7XRADM79XU".parse::<Id<Entity>>().is_err());
+        // 48bit のタイムスタンプを超える
+        assert!("81F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Entity>>().is_err());
+        assert!("0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+            .parse::<Id<Entity>>()
+            .is_err());
+        assert!(matches!(
+            "NOTFOUND_ID".parse::<Id<Entity>>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_id_canonical() {
+        assert_eq!(
+            "01f8mechzx3tbdsz7xradm79xe".parse::<Id<Entity>>().unwrap(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Entity>>().unwrap()
+        );
+        assert_eq!(
+            "01f8mechzx3tbdsz7xradm79xe"
+                .parse::<Id<Entity>>()
+                .unwrap()
+                .to_string(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+    }
+
+    #[test]
+    fn test_id_timestamp() {
+        assert_eq!(
+            "01F8MECHZX3TBDSZ7XRADM79XE"
+                .parse::<Id<Entity>>()
+                .unwrap()
+                .timestamp()
+                .to_string(),
+            "2021-06-20T10:10:18.237Z".to_string()
+        );
+    }
+
     #[test]
     fn test_id_to_string() {
         assert_eq!(
@@ -1,11 +1,35 @@
 #[allow(clippy::module_inception)]
 mod vo;
 
+mod checksum;
+mod color;
+mod content_type;
+mod event_id;
+mod file_name;
 mod id;
+mod name;
 mod note;
+mod owner_id;
+mod position;
+mod render_format;
+mod size;
+mod timestamp;
 mod title;
+mod z_index;
 
+pub use self::checksum::Checksum;
+pub use self::color::FusenColor;
+pub use self::content_type::ContentType;
+pub use self::event_id::EventId;
+pub use self::file_name::FileName;
 pub use self::id::Id;
+pub use self::name::BoardName;
 pub use self::note::FusenNote;
-pub use self::title::FusenTitle;
+pub use self::owner_id::OwnerId;
+pub use self::position::Position;
+pub use self::render_format::RenderFormat;
+pub use self::size::FusenSize;
+pub use self::timestamp::Timestamp;
+pub use self::title::{FusenTitle, TitleError, TitleViolation};
 pub use self::vo::ValueObject;
+pub use self::z_index::ZIndex;
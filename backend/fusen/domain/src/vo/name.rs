@@ -0,0 +1,56 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+const MAX_LENGTH: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardName(String);
+
+impl ValueObject for BoardName {}
+
+impl FromStr for BoardName {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() || s.chars().count() > MAX_LENGTH || s.chars().any(char::is_control)
+        {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid board name {}",
+                s
+            )));
+        }
+
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl ToString for BoardName {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_name() {
+        assert_eq!(
+            "チーム A".parse::<BoardName>().unwrap().to_string(),
+            "チーム A".to_string()
+        );
+        assert!("a".repeat(MAX_LENGTH).parse::<BoardName>().is_ok());
+
+        assert!("".parse::<BoardName>().is_err());
+        assert!("   ".parse::<BoardName>().is_err());
+        assert!("a".repeat(MAX_LENGTH + 1).parse::<BoardName>().is_err());
+        assert!("board\n".parse::<BoardName>().is_err());
+        assert!(matches!(
+            "".parse::<BoardName>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
@@ -1,5 +1,5 @@
+use crate::error::DomainError;
 use crate::vo::ValueObject;
-use anyhow::Error;
 use std::str::FromStr;
 use std::string::ToString;
 
@@ -9,7 +9,7 @@ pub struct FusenNote(String);
 impl ValueObject for FusenNote {}
 
 impl FromStr for FusenNote {
-    type Err = Error;
+    type Err = DomainError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self(s.to_string()))
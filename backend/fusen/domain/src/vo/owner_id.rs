@@ -0,0 +1,55 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+const MAX_LENGTH: usize = 255;
+
+// 認証済みの呼び出し元 (JWT の sub) を表す
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OwnerId(String);
+
+impl ValueObject for OwnerId {}
+
+impl FromStr for OwnerId {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.chars().count() > MAX_LENGTH || s.chars().any(char::is_control) {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid owner id {}",
+                s
+            )));
+        }
+
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl ToString for OwnerId {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_id() {
+        assert_eq!(
+            "auth0|0123456789".parse::<OwnerId>().unwrap().to_string(),
+            "auth0|0123456789".to_string()
+        );
+        assert!("a".repeat(MAX_LENGTH).parse::<OwnerId>().is_ok());
+
+        assert!("".parse::<OwnerId>().is_err());
+        assert!("a".repeat(MAX_LENGTH + 1).parse::<OwnerId>().is_err());
+        assert!("owner\n".parse::<OwnerId>().is_err());
+        assert!(matches!(
+            "".parse::<OwnerId>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+
+const MIN_COORDINATE: i32 = -100_000;
+const MAX_COORDINATE: i32 = 100_000;
+
+// board 上の左上の座標
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl ValueObject for Position {}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Result<Self, DomainError> {
+        let range = MIN_COORDINATE..=MAX_COORDINATE;
+        if !range.contains(&x) || !range.contains(&y) {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid position ({}, {})",
+                x, y
+            )));
+        }
+
+        Ok(Self { x, y })
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let position = Position::new(-10, 20).unwrap();
+        assert_eq!((position.x(), position.y()), (-10, 20));
+        assert!(Position::new(MIN_COORDINATE, MAX_COORDINATE).is_ok());
+        assert_eq!(Position::default(), Position::new(0, 0).unwrap());
+
+        assert!(Position::new(MIN_COORDINATE - 1, 0).is_err());
+        assert!(matches!(
+            Position::new(0, MAX_COORDINATE + 1),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
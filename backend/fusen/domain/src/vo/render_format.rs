@@ -0,0 +1,59 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use std::str::FromStr;
+use std::string::ToString;
+
+// note を返すときの変換形式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderFormat {
+    Html,
+    PlainText,
+}
+
+impl ValueObject for RenderFormat {}
+
+impl FromStr for RenderFormat {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "html" => Ok(RenderFormat::Html),
+            "plain_text" => Ok(RenderFormat::PlainText),
+            _ => Err(DomainError::InvalidArgument(format!(
+                "invalid render format {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToString for RenderFormat {
+    fn to_string(&self) -> String {
+        match self {
+            RenderFormat::Html => "html",
+            RenderFormat::PlainText => "plain_text",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_format() {
+        for format in &["html", "plain_text"] {
+            assert_eq!(
+                format.parse::<RenderFormat>().unwrap().to_string(),
+                format.to_string()
+            );
+        }
+
+        assert!("".parse::<RenderFormat>().is_err());
+        assert!(matches!(
+            "markdown".parse::<RenderFormat>(),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+
+const MIN_LENGTH: i32 = 40;
+const MAX_LENGTH: i32 = 2_000;
+const DEFAULT_LENGTH: i32 = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FusenSize {
+    width: i32,
+    height: i32,
+}
+
+impl ValueObject for FusenSize {}
+
+impl FusenSize {
+    pub fn new(width: i32, height: i32) -> Result<Self, DomainError> {
+        let range = MIN_LENGTH..=MAX_LENGTH;
+        if !range.contains(&width) || !range.contains(&height) {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid size {}x{}",
+                width, height
+            )));
+        }
+
+        Ok(Self { width, height })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+impl Default for FusenSize {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_LENGTH,
+            height: DEFAULT_LENGTH,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fusen_size() {
+        let size = FusenSize::new(120, 80).unwrap();
+        assert_eq!((size.width(), size.height()), (120, 80));
+        assert!(FusenSize::new(MIN_LENGTH, MAX_LENGTH).is_ok());
+        assert_eq!(
+            FusenSize::default(),
+            FusenSize::new(DEFAULT_LENGTH, DEFAULT_LENGTH).unwrap()
+        );
+
+        assert!(FusenSize::new(0, 0).is_err());
+        assert!(FusenSize::new(MIN_LENGTH - 1, 100).is_err());
+        assert!(matches!(
+            FusenSize::new(100, MAX_LENGTH + 1),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::str::FromStr;
+use std::string::ToString;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(DateTime<Utc>);
+
+impl ValueObject for Timestamp {}
+
+impl Timestamp {
+    pub fn new(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match DateTime::parse_from_rfc3339(s) {
+            Ok(value) => Ok(Self(value.with_timezone(&Utc))),
+            Err(_) => Err(DomainError::InvalidArgument(format!(
+                "invalid timestamp {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToString for Timestamp {
+    fn to_string(&self) -> String {
+        self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        assert!("2021-10-17T07:08:37Z".parse::<Timestamp>().is_ok());
+        assert!("2021-10-17T16:08:37.123456+09:00"
+            .parse::<Timestamp>()
+            .is_ok());
+        assert!("".parse::<Timestamp>().is_err());
+        assert!("2021-10-17 07:08:37".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn test_timestamp_to_string() {
+        assert_eq!(
+            "2021-10-17T16:08:37.123456+09:00"
+                .parse::<Timestamp>()
+                .unwrap()
+                .to_string(),
+            "2021-10-17T07:08:37.123456Z".to_string(),
+        );
+    }
+
+    #[test]
+    fn test_timestamp_ord() {
+        let before = "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap();
+        let after = "2021-10-17T07:08:38Z".parse::<Timestamp>().unwrap();
+
+        assert!(before < after);
+        assert_eq!(
+            before,
+            "2021-10-17T16:08:37+09:00".parse::<Timestamp>().unwrap()
+        );
+    }
+}
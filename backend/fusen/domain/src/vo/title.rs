@@ -1,26 +1,117 @@
+use crate::error::DomainError;
 use crate::vo::ValueObject;
-use anyhow::bail;
-use anyhow::Error;
-use regex::Regex;
+use std::fmt;
 use std::str::FromStr;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+// 見た目の文字数 (書記素クラスタ) の上限
+const MAX_GRAPHEMES: usize = 64;
+// 結合文字を重ねた書記素でも保存できるよう fusens.title の VARCHAR(256) と揃える
+const MAX_CHARS: usize = 256;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FusenTitle(String);
 
 impl ValueObject for FusenTitle {}
 
-impl FromStr for FusenTitle {
-    type Err = Error;
+// title を受け付けない理由
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TitleViolation {
+    Empty,
+    TooLong { max: usize, actual: usize },
+    TooManyCodePoints { max: usize, actual: usize },
+    ControlCharacter(char),
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if Regex::new(r"^\w[\w\s]*\w$")?.is_match(s) {
-            Ok(Self(s.to_string()))
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("invalid title: {}", join(.violations))]
+pub struct TitleError {
+    violations: Vec<TitleViolation>,
+}
+
+impl FusenTitle {
+    // NFC に正規化し、前後の空白を除いて連続する空白をひとつにまとめてから検証する
+    pub fn new(s: &str) -> Result<Self, TitleError> {
+        let normalized = s.nfc().collect::<String>();
+        let title = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let mut violations = Vec::new();
+        if title.is_empty() {
+            violations.push(TitleViolation::Empty);
+        }
+        let graphemes = title.graphemes(true).count();
+        if graphemes > MAX_GRAPHEMES {
+            violations.push(TitleViolation::TooLong {
+                max: MAX_GRAPHEMES,
+                actual: graphemes,
+            });
+        }
+        let chars = title.chars().count();
+        if chars > MAX_CHARS {
+            violations.push(TitleViolation::TooManyCodePoints {
+                max: MAX_CHARS,
+                actual: chars,
+            });
+        }
+        if let Some(c) = title.chars().find(|c| c.is_control()) {
+            violations.push(TitleViolation::ControlCharacter(c));
+        }
+
+        if violations.is_empty() {
+            Ok(Self(title))
         } else {
-            bail!("invalid string {}", s)
+            Err(TitleError { violations })
         }
     }
 }
 
+impl TitleError {
+    pub fn violations(&self) -> &[TitleViolation] {
+        &self.violations
+    }
+}
+
+impl fmt::Display for TitleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TitleViolation::Empty => write!(f, "must not be empty"),
+            TitleViolation::TooLong { max, actual } => {
+                write!(f, "must be at most {} characters, got {}", max, actual)
+            }
+            TitleViolation::TooManyCodePoints { max, actual } => {
+                write!(f, "must be at most {} code points, got {}", max, actual)
+            }
+            TitleViolation::ControlCharacter(c) => {
+                write!(f, "must not contain control character U+{:04X}", *c as u32)
+            }
+        }
+    }
+}
+
+fn join(violations: &[TitleViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl From<TitleError> for DomainError {
+    fn from(error: TitleError) -> Self {
+        DomainError::InvalidTitle(error)
+    }
+}
+
+impl FromStr for FusenTitle {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s)?)
+    }
+}
+
 impl ToString for FusenTitle {
     fn to_string(&self) -> String {
         self.0.clone()
@@ -34,20 +125,92 @@ mod tests {
     #[test]
     fn test_fusen_title() {
         assert!("title".parse::<FusenTitle>().is_ok());
-        assert!("ti".parse::<FusenTitle>().is_ok());
-        assert!("ti    tle".parse::<FusenTitle>().is_ok());
-        assert!("ti    t    le".parse::<FusenTitle>().is_ok());
+        assert!("t".parse::<FusenTitle>().is_ok());
         assert!("1234567890abcdefg".parse::<FusenTitle>().is_ok());
         assert!("くぁｗせｄｒｆｔｇｙふじこｌｐ"
             .parse::<FusenTitle>()
             .is_ok());
+        assert!("Q3: plan".parse::<FusenTitle>().is_ok());
+        assert!("C++ tips".parse::<FusenTitle>().is_ok());
+        assert!("日報（10/18）".parse::<FusenTitle>().is_ok());
+        assert!("🎉!".parse::<FusenTitle>().is_ok());
 
         assert!("".parse::<FusenTitle>().is_err());
-        assert!("t".parse::<FusenTitle>().is_err());
         assert!("         ".parse::<FusenTitle>().is_err());
-        assert!("  title  ".parse::<FusenTitle>().is_err());
-        assert!("title    ".parse::<FusenTitle>().is_err());
-        assert!("    title".parse::<FusenTitle>().is_err());
+        assert!("\u{3000}\n\t".parse::<FusenTitle>().is_err());
+        assert!("ti\u{0}tle".parse::<FusenTitle>().is_err());
+
+        assert!(matches!(
+            "".parse::<FusenTitle>(),
+            Err(DomainError::InvalidTitle(_))
+        ));
+    }
+
+    #[test]
+    fn test_fusen_title_normalize() {
+        assert_eq!(
+            "  ti    t\n\tle\u{3000}".parse::<FusenTitle>().unwrap(),
+            "ti t le".parse::<FusenTitle>().unwrap()
+        );
+        // 結合文字を使った表記は合成済みの文字にまとめる
+        assert_eq!(
+            "か\u{3099}き".parse::<FusenTitle>().unwrap().to_string(),
+            "がき".to_string()
+        );
+    }
+
+    #[test]
+    fn test_fusen_title_length() {
+        assert!("a".repeat(MAX_GRAPHEMES).parse::<FusenTitle>().is_ok());
+        assert!("字".repeat(MAX_GRAPHEMES).parse::<FusenTitle>().is_ok());
+        // 国旗はふたつのコードポイントでひとつの書記素になる
+        assert!("🇯🇵".repeat(MAX_GRAPHEMES).parse::<FusenTitle>().is_ok());
+        // 正規化と空白の除去の後の長さで数える
+        assert!(format!("  {}  ", "a".repeat(MAX_GRAPHEMES))
+            .parse::<FusenTitle>()
+            .is_ok());
+
+        assert_eq!(
+            FusenTitle::new(&"a".repeat(MAX_GRAPHEMES + 1))
+                .unwrap_err()
+                .violations(),
+            &[TitleViolation::TooLong {
+                max: MAX_GRAPHEMES,
+                actual: MAX_GRAPHEMES + 1
+            }]
+        );
+        assert_eq!(
+            FusenTitle::new(&format!("x{}", "\u{301}".repeat(MAX_CHARS)))
+                .unwrap_err()
+                .violations(),
+            &[TitleViolation::TooManyCodePoints {
+                max: MAX_CHARS,
+                actual: MAX_CHARS + 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_title_error() {
+        let error = FusenTitle::new(&format!("{}\u{7f}", "a".repeat(MAX_GRAPHEMES))).unwrap_err();
+        assert_eq!(
+            error.violations(),
+            &[
+                TitleViolation::TooLong {
+                    max: MAX_GRAPHEMES,
+                    actual: MAX_GRAPHEMES + 1
+                },
+                TitleViolation::ControlCharacter('\u{7f}'),
+            ]
+        );
+        assert_eq!(
+            DomainError::from(error).to_string(),
+            "invalid argument: invalid title: must be at most 64 characters, got 65; must not contain control character U+007F".to_string()
+        );
+        assert_eq!(
+            FusenTitle::new(" ").unwrap_err().to_string(),
+            "invalid title: must not be empty".to_string()
+        );
     }
 
     #[test]
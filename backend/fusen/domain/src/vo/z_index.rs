@@ -0,0 +1,45 @@
+use crate::error::DomainError;
+use crate::vo::ValueObject;
+
+const MAX_Z_INDEX: i32 = 1_000_000;
+
+// 重なり順。大きいほど手前に表示する
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ZIndex(i32);
+
+impl ValueObject for ZIndex {}
+
+impl ZIndex {
+    pub fn new(value: i32) -> Result<Self, DomainError> {
+        if !(0..=MAX_Z_INDEX).contains(&value) {
+            return Err(DomainError::InvalidArgument(format!(
+                "invalid z-index {}",
+                value
+            )));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z_index() {
+        assert_eq!(ZIndex::new(3).unwrap().value(), 3);
+        assert!(ZIndex::new(MAX_Z_INDEX).is_ok());
+        assert!(ZIndex::new(1).unwrap() > ZIndex::default());
+
+        assert!(ZIndex::new(MAX_Z_INDEX + 1).is_err());
+        assert!(matches!(
+            ZIndex::new(-1),
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
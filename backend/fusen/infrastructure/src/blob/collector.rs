@@ -0,0 +1,238 @@
+use crate::shutdown::Shutdown;
+use anyhow::{Error, Result};
+use domain::repository::{AttachmentRepository, BlobStore};
+use std::time::Duration;
+
+const BATCH_SIZE: usize = 100;
+
+// 削除された fusen の添付ファイルを blob ごと消す。
+// fusen の削除とアップロードの完了が前後しても、記録が残っていれば次の回収で消える
+pub struct Collector<A, B> {
+    attachments: A,
+    blob_store: B,
+    interval: Duration,
+}
+
+impl<A: AttachmentRepository, B: BlobStore> Collector<A, B> {
+    pub fn new(attachments: A, blob_store: B, interval: Duration) -> Self {
+        Self {
+            attachments,
+            blob_store,
+            interval,
+        }
+    }
+
+    pub async fn run(self, shutdown: Shutdown) -> Result<(), Error> {
+        loop {
+            let result = tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                result = self.collect_once() => result,
+            };
+            let wait = match result {
+                // 回収しきれていない添付ファイルがあるので待たずに続ける
+                Ok(collected) if collected == BATCH_SIZE => Duration::ZERO,
+                Ok(_) => self.interval,
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to collect attachments");
+                    self.interval
+                }
+            };
+
+            tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                _ = tokio::time::sleep(wait) => {}
+            }
+        }
+    }
+
+    // blob を消せたものだけ記録を消し、失敗したものは次の回収で再試行する
+    pub async fn collect_once(&self) -> Result<usize, Error> {
+        let attachments = self.attachments.orphaned(BATCH_SIZE).await?;
+
+        let mut collected = Vec::with_capacity(attachments.len());
+        let mut failure = None;
+        for attachment in attachments {
+            match self.blob_store.delete(&attachment.blob_key()).await {
+                Ok(()) => collected.push(attachment.id().clone()),
+                Err(e) => {
+                    failure.get_or_insert((attachment.id().to_string(), e));
+                }
+            }
+        }
+
+        let count = collected.len();
+        if count > 0 {
+            self.attachments.remove(collected).await?;
+            tracing::info!(count, "collected attachments of deleted fusens");
+        }
+        if let Some((id, e)) = failure {
+            return Err(
+                Error::from(e).context(format!("failed to delete blob of attachment {}", id))
+            );
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{AttachmentRepository, FusenRepository};
+    use async_trait::async_trait;
+    use domain::entity::{Attachment, AttachmentBuilder, Board, Fusen, FusenBuilder};
+    use domain::error::DomainError;
+    use domain::repository::{AttachmentRepository as _, BlobStream};
+    use domain::repository::{CreateRepository, DeleteRepository};
+    use domain::vo::{
+        Checksum, ContentType, FileName, FusenNote, FusenTitle, Id, OwnerId, Timestamp,
+    };
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    fn new_fusen(id: &str) -> Fusen {
+        FusenBuilder::default()
+            .id(id.parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn new_attachment(id: &str, fusen_id: &str) -> Attachment {
+        AttachmentBuilder::default()
+            .id(id.parse::<Id<Attachment>>().unwrap())
+            .fusen_id(fusen_id.parse::<Id<Fusen>>().unwrap())
+            .file_name("screenshot.png".parse::<FileName>().unwrap())
+            .content_type("image/png".parse::<ContentType>().unwrap())
+            .size(3u64)
+            .checksum(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                    .parse::<Checksum>()
+                    .unwrap(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    // failing に含まれるキーの削除だけ失敗する
+    #[derive(Clone, Default)]
+    struct MockBlobStore {
+        keys: Arc<Mutex<HashSet<String>>>,
+        failing: Arc<Mutex<HashSet<String>>>,
+    }
+
+    #[async_trait]
+    impl BlobStore for MockBlobStore {
+        async fn put(&self, _key: &str, _content: BlobStream) -> Result<u64, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn get(&self, _key: &str) -> Result<BlobStream, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), DomainError> {
+            if self.failing.lock().unwrap().contains(key) {
+                return Err(DomainError::Unavailable("unavailable".to_string()));
+            }
+            self.keys.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    async fn setup() -> (FusenRepository, AttachmentRepository, MockBlobStore) {
+        let fusens = FusenRepository::default();
+        let attachments = AttachmentRepository::new(fusens.clone());
+        let blob_store = MockBlobStore::default();
+        for id in ["01F8MECHZX3TBDSZ7XRADM79XE", "01F8MECHZX3TBDSZ7XRADM79XF"] {
+            fusens.create(new_fusen(id)).await.unwrap();
+        }
+        for (id, fusen_id) in [
+            ("01FNATTACHXKQ7B1GZ1Z0K0T8R", "01F8MECHZX3TBDSZ7XRADM79XE"),
+            ("01FNATTACHXKQ7B1GZ1Z0K0T8S", "01F8MECHZX3TBDSZ7XRADM79XE"),
+            ("01FNATTACHXKQ7B1GZ1Z0K0T8T", "01F8MECHZX3TBDSZ7XRADM79XF"),
+        ] {
+            attachments.add(new_attachment(id, fusen_id)).await.unwrap();
+            blob_store.keys.lock().unwrap().insert(id.to_string());
+        }
+
+        (fusens, attachments, blob_store)
+    }
+
+    #[tokio::test]
+    async fn test_collect_once() {
+        let (fusens, attachments, blob_store) = setup().await;
+        let sut = Collector::new(attachments.clone(), blob_store.clone(), Duration::ZERO);
+
+        assert_eq!(sut.collect_once().await.unwrap(), 0);
+
+        fusens
+            .delete(new_fusen("01F8MECHZX3TBDSZ7XRADM79XE"))
+            .await
+            .unwrap();
+        blob_store
+            .failing
+            .lock()
+            .unwrap()
+            .insert("01FNATTACHXKQ7B1GZ1Z0K0T8S".to_string());
+
+        // 消せなかった blob の記録は残す
+        assert!(sut.collect_once().await.is_err());
+        assert_eq!(
+            blob_store.keys.lock().unwrap().clone(),
+            HashSet::from([
+                "01FNATTACHXKQ7B1GZ1Z0K0T8S".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8T".to_string()
+            ])
+        );
+        assert_eq!(attachments.orphaned(10).await.unwrap().len(), 1);
+
+        blob_store.failing.lock().unwrap().clear();
+        assert_eq!(sut.collect_once().await.unwrap(), 1);
+        assert!(attachments.orphaned(10).await.unwrap().is_empty());
+        assert_eq!(
+            attachments
+                .list("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collector_run() {
+        let (fusens, attachments, blob_store) = setup().await;
+        let shutdown = Shutdown::default();
+        let collector = tokio::spawn(
+            Collector::new(
+                attachments.clone(),
+                blob_store.clone(),
+                Duration::from_millis(10),
+            )
+            .run(shutdown.clone()),
+        );
+
+        fusens
+            .delete(new_fusen("01F8MECHZX3TBDSZ7XRADM79XF"))
+            .await
+            .unwrap();
+        while !attachments.orphaned(10).await.unwrap().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!blob_store
+            .keys
+            .lock()
+            .unwrap()
+            .contains("01FNATTACHXKQ7B1GZ1Z0K0T8T"));
+
+        shutdown.drain(Duration::ZERO).await;
+        collector.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+use crate::blob::validate_key;
+use anyhow::{Context, Error, Result};
+use async_trait::async_trait;
+use domain::error::DomainError;
+use domain::repository::{BlobStore, BlobStream};
+use futures::StreamExt;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+// 書き込み中の内容は拡張子を付けて保存し、最後まで書けてから名前を変える
+const PARTIAL_EXTENSION: &str = "partial";
+
+// root の直下にキーと同じ名前のファイルとして保存する
+#[derive(Clone, Debug)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: PathBuf) -> Result<Self, Error> {
+        std::fs::create_dir_all(&root)
+            .with_context(|| format!("failed to create {}", root.display()))?;
+
+        Ok(Self { root })
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, DomainError> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+async fn write(path: &Path, content: &mut BlobStream) -> Result<u64, DomainError> {
+    let mut file = fs::File::create(path).await.map_err(Error::from)?;
+    let mut size = 0;
+    while let Some(chunk) = content.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk).await.map_err(Error::from)?;
+        size += chunk.len() as u64;
+    }
+    file.sync_all().await.map_err(Error::from)?;
+
+    Ok(size)
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, mut content: BlobStream) -> Result<u64, DomainError> {
+        let path = self.path(key)?;
+        let partial = path.with_extension(PARTIAL_EXTENSION);
+        match write(&partial, &mut content).await {
+            Ok(size) => {
+                fs::rename(&partial, &path).await.map_err(Error::from)?;
+                Ok(size)
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&partial).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<BlobStream, DomainError> {
+        let path = self.path(key)?;
+        let mut file = match fs::File::open(&path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(DomainError::NotFound(format!("blob {} not found", key)))
+            }
+            Err(e) => return Err(Error::from(e).into()),
+        };
+
+        Ok(async_stream::try_stream! {
+            let mut buffer = vec![0; READ_BUFFER_SIZE];
+            loop {
+                let n = file.read(&mut buffer).await.map_err(Error::from)?;
+                if n == 0 {
+                    break;
+                }
+                yield buffer[..n].to_vec();
+            }
+        }
+        .boxed())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DomainError> {
+        match fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(Error::from(e).into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, TryStreamExt};
+
+    fn content(chunks: Vec<Result<Vec<u8>, DomainError>>) -> BlobStream {
+        stream::iter(chunks).boxed()
+    }
+
+    #[tokio::test]
+    async fn test_local_blob_store() {
+        let root = tempfile::tempdir().unwrap();
+        let sut = LocalBlobStore::new(root.path().join("attachments")).unwrap();
+
+        let size = sut
+            .put(
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R",
+                content(vec![Ok(b"ab".to_vec()), Ok(b"c".to_vec())]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(size, 3);
+        let stored = sut
+            .get("01FNATTACHXKQ7B1GZ1Z0K0T8R")
+            .await
+            .unwrap()
+            .try_concat()
+            .await
+            .unwrap();
+        assert_eq!(stored, b"abc".to_vec());
+
+        sut.delete("01FNATTACHXKQ7B1GZ1Z0K0T8R").await.unwrap();
+        assert!(matches!(
+            sut.get("01FNATTACHXKQ7B1GZ1Z0K0T8R").await,
+            Err(DomainError::NotFound(_))
+        ));
+        assert!(sut.delete("01FNATTACHXKQ7B1GZ1Z0K0T8R").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_local_blob_store_err() {
+        let root = tempfile::tempdir().unwrap();
+        let sut = LocalBlobStore::new(root.path().to_path_buf()).unwrap();
+
+        // 途中で失敗した内容はファイルを残さない
+        assert!(matches!(
+            sut.put(
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R",
+                content(vec![
+                    Ok(b"ab".to_vec()),
+                    Err(DomainError::Unavailable("cancelled".to_string()))
+                ]),
+            )
+            .await,
+            Err(DomainError::Unavailable(_))
+        ));
+        assert_eq!(std::fs::read_dir(root.path()).unwrap().count(), 0);
+
+        assert!(matches!(
+            sut.put("../escape", content(vec![])).await,
+            Err(DomainError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.get("").await,
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
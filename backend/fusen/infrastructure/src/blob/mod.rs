@@ -0,0 +1,20 @@
+mod collector;
+mod local;
+mod s3;
+
+pub use self::collector::*;
+pub use self::local::*;
+pub use self::s3::*;
+
+use domain::error::DomainError;
+
+// キーはファイル名や URL のパスにそのまま使うので英数字に限る
+fn validate_key(key: &str) -> Result<(), DomainError> {
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(DomainError::InvalidArgument(format!(
+            "invalid blob key {}",
+            key
+        )));
+    }
+    Ok(())
+}
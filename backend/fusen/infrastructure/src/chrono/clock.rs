@@ -0,0 +1,28 @@
+use chrono::{SubsecRound, Utc};
+use domain::repository::Clock as Repository;
+use domain::vo::Timestamp;
+
+#[derive(Default)]
+pub struct Clock {}
+
+impl Repository for Clock {
+    fn now(&self) -> Timestamp {
+        // timestamptz の精度に合わせてマイクロ秒で切り捨てる
+        Timestamp::new(Utc::now().trunc_subsecs(6))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock() {
+        let sut = Clock::default();
+
+        let before = sut.now();
+        let after = sut.now();
+        assert!(before <= after);
+        assert_eq!(before.value().timestamp_subsec_nanos() % 1000, 0);
+    }
+}
@@ -0,0 +1,1001 @@
+use crate::http::AllowedOrigins;
+use crate::jwt::Authenticator;
+use crate::logger::LOG_ENV;
+use anyhow::{anyhow, bail, Context, Error, Result};
+use hyper::Uri;
+use serde::Deserialize;
+use std::env;
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tonic::transport::Identity;
+use tracing_subscriber::EnvFilter;
+
+// 設定ファイルのパス。未設定の場合は既定値と環境変数だけで起動する
+pub const CONFIG_ENV: &str = "FUSEN_CONFIG";
+
+const BACKEND_ENV: &str = "FUSEN_BACKEND";
+const GRPC_ADDR_ENV: &str = "FUSEN_GRPC_ADDR";
+const HTTP_ADDR_ENV: &str = "FUSEN_HTTP_ADDR";
+const METRICS_ADDR_ENV: &str = "FUSEN_METRICS_ADDR";
+const DRAIN_PERIOD_ENV: &str = "FUSEN_DRAIN_PERIOD_SECS";
+const TLS_CERT_PATH_ENV: &str = "FUSEN_TLS_CERT_PATH";
+const TLS_KEY_PATH_ENV: &str = "FUSEN_TLS_KEY_PATH";
+const CORS_ALLOWED_ORIGINS_ENV: &str = "FUSEN_CORS_ALLOWED_ORIGINS";
+const JWKS_PATH_ENV: &str = "FUSEN_JWKS_PATH";
+const JWT_SECRET_ENV: &str = "FUSEN_JWT_SECRET";
+const DATABASE_URL_ENV: &str = "FUSEN_DATABASE_URL";
+const DATABASE_MIN_SIZE_ENV: &str = "FUSEN_DATABASE_MIN_SIZE";
+const DATABASE_MAX_SIZE_ENV: &str = "FUSEN_DATABASE_MAX_SIZE";
+const DATABASE_CONNECTION_TIMEOUT_ENV: &str = "FUSEN_DATABASE_CONNECTION_TIMEOUT_SECS";
+const OUTBOX_ENDPOINT_ENV: &str = "FUSEN_OUTBOX_ENDPOINT";
+const OUTBOX_BATCH_SIZE_ENV: &str = "FUSEN_OUTBOX_BATCH_SIZE";
+const OUTBOX_POLL_INTERVAL_ENV: &str = "FUSEN_OUTBOX_POLL_INTERVAL_MS";
+const OUTBOX_LEASE_ENV: &str = "FUSEN_OUTBOX_LEASE_SECS";
+const EVENT_RETENTION_ENV: &str = "FUSEN_EVENT_RETENTION_SECS";
+const EVENT_PRUNE_INTERVAL_ENV: &str = "FUSEN_EVENT_PRUNE_INTERVAL_SECS";
+const ATTACHMENT_STORE_ENV: &str = "FUSEN_ATTACHMENT_STORE";
+const ATTACHMENT_PATH_ENV: &str = "FUSEN_ATTACHMENT_PATH";
+const ATTACHMENT_MAX_SIZE_ENV: &str = "FUSEN_ATTACHMENT_MAX_SIZE_BYTES";
+const ATTACHMENT_CONTENT_TYPES_ENV: &str = "FUSEN_ATTACHMENT_CONTENT_TYPES";
+const ATTACHMENT_GC_INTERVAL_ENV: &str = "FUSEN_ATTACHMENT_GC_INTERVAL_SECS";
+const S3_ENDPOINT_ENV: &str = "FUSEN_S3_ENDPOINT";
+const S3_BUCKET_ENV: &str = "FUSEN_S3_BUCKET";
+const S3_REGION_ENV: &str = "FUSEN_S3_REGION";
+const S3_ACCESS_KEY_ID_ENV: &str = "FUSEN_S3_ACCESS_KEY_ID";
+const S3_SECRET_ACCESS_KEY_ENV: &str = "FUSEN_S3_SECRET_ACCESS_KEY";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub backend: Backend,
+    pub server: ServerConfig,
+    pub auth: AuthConfig,
+    pub database: DatabaseConfig,
+    pub outbox: OutboxConfig,
+    pub events: EventConfig,
+    pub attachments: AttachmentConfig,
+    pub log: LogConfig,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Backend {
+    #[default]
+    Postgres,
+    Memory,
+    // sqlite feature を有効にしてビルドした場合のみ使える
+    Sqlite,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub grpc_addr: SocketAddr,
+    pub http_addr: SocketAddr,
+    // /metrics を認証なしで返すアドレス。未設定の場合は公開しない
+    pub metrics_addr: Option<SocketAddr>,
+    // SIGTERM を受け取ってから新しいリクエストの受け付けを止めるまでの猶予
+    pub drain_period_secs: u64,
+    // gRPC のみ TLS で待ち受ける
+    pub tls: Option<TlsConfig>,
+    // REST と gRPC-Web で許可する origin。"*" もしくはカンマ区切りで指定する
+    pub cors_allowed_origins: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+// JWKS が指定されていればそちらを優先する
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub jwks_path: Option<PathBuf>,
+    // HS256 などの共通鍵で署名した JWT を検証する
+    pub jwt_secret: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+    // 未設定の場合は max_size と同じ数のコネクションを維持する
+    pub min_size: Option<u32>,
+    pub max_size: u32,
+    pub connection_timeout_secs: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutboxConfig {
+    // ドメインイベントを POST する http:// の URL。未設定の場合は配信せずに破棄する
+    pub endpoint: Option<String>,
+    pub batch_size: usize,
+    pub poll_interval_ms: u64,
+    // 配信中のメッセージを他のレプリカの relay に取り出させない時間
+    pub lease_secs: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventConfig {
+    // Watch を再開できるようにイベントを残しておく時間
+    pub retention_secs: u64,
+    // 保持期間を過ぎたイベントを消す間隔
+    pub prune_interval_secs: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AttachmentConfig {
+    pub store: BlobStoreKind,
+    // local の場合に保存するディレクトリ。存在しなければ起動時に作成する
+    pub path: PathBuf,
+    // store が s3 の場合は必須
+    pub s3: Option<S3Config>,
+    pub max_size_bytes: u64,
+    // image/png や image/* のように指定する
+    pub content_types: Vec<String>,
+    // 削除された fusen の添付ファイルを回収する間隔
+    pub gc_interval_secs: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlobStoreKind {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct S3Config {
+    // バケットを path-style で指定する http:// の URL。MinIO などの互換サービスを想定する
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    // RUST_LOG と同じ書式
+    pub level: String,
+}
+
+impl Config {
+    // FUSEN_CONFIG の設定ファイルを読み込み、環境変数で上書きしてから検証する
+    pub fn load() -> Result<Self, Error> {
+        let config = match env::var(CONFIG_ENV) {
+            Ok(path) => Self::from_file(&path)?,
+            Err(_) => Self::default(),
+        };
+
+        config.override_with(|name| env::var(name).ok())?.validate()
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path))?;
+
+        content
+            .parse::<Config>()
+            .with_context(|| format!("failed to parse config file {}", path))
+    }
+
+    pub fn override_with<F>(mut self, var: F) -> Result<Self, Error>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(backend) = parse_var(&var, BACKEND_ENV)? {
+            self.backend = backend;
+        }
+        if let Some(addr) = parse_var(&var, GRPC_ADDR_ENV)? {
+            self.server.grpc_addr = addr;
+        }
+        if let Some(addr) = parse_var(&var, HTTP_ADDR_ENV)? {
+            self.server.http_addr = addr;
+        }
+        if let Some(addr) = parse_var(&var, METRICS_ADDR_ENV)? {
+            self.server.metrics_addr = Some(addr);
+        }
+        if let Some(secs) = parse_var(&var, DRAIN_PERIOD_ENV)? {
+            self.server.drain_period_secs = secs;
+        }
+        if let Some(path) = var(TLS_CERT_PATH_ENV) {
+            self.server
+                .tls
+                .get_or_insert_with(Default::default)
+                .cert_path = path.into();
+        }
+        if let Some(path) = var(TLS_KEY_PATH_ENV) {
+            self.server
+                .tls
+                .get_or_insert_with(Default::default)
+                .key_path = path.into();
+        }
+        if let Some(origins) = var(CORS_ALLOWED_ORIGINS_ENV) {
+            self.server.cors_allowed_origins = origins;
+        }
+        if let Some(path) = var(JWKS_PATH_ENV) {
+            self.auth.jwks_path = Some(path.into());
+        }
+        if let Some(secret) = var(JWT_SECRET_ENV) {
+            self.auth.jwt_secret = Some(secret);
+        }
+        if let Some(url) = var(DATABASE_URL_ENV) {
+            self.database.url = url;
+        }
+        if let Some(size) = parse_var(&var, DATABASE_MIN_SIZE_ENV)? {
+            self.database.min_size = Some(size);
+        }
+        if let Some(size) = parse_var(&var, DATABASE_MAX_SIZE_ENV)? {
+            self.database.max_size = size;
+        }
+        if let Some(secs) = parse_var(&var, DATABASE_CONNECTION_TIMEOUT_ENV)? {
+            self.database.connection_timeout_secs = secs;
+        }
+        if let Some(endpoint) = var(OUTBOX_ENDPOINT_ENV) {
+            self.outbox.endpoint = Some(endpoint);
+        }
+        if let Some(size) = parse_var(&var, OUTBOX_BATCH_SIZE_ENV)? {
+            self.outbox.batch_size = size;
+        }
+        if let Some(ms) = parse_var(&var, OUTBOX_POLL_INTERVAL_ENV)? {
+            self.outbox.poll_interval_ms = ms;
+        }
+        if let Some(secs) = parse_var(&var, OUTBOX_LEASE_ENV)? {
+            self.outbox.lease_secs = secs;
+        }
+        if let Some(secs) = parse_var(&var, EVENT_RETENTION_ENV)? {
+            self.events.retention_secs = secs;
+        }
+        if let Some(secs) = parse_var(&var, EVENT_PRUNE_INTERVAL_ENV)? {
+            self.events.prune_interval_secs = secs;
+        }
+        if let Some(store) = parse_var(&var, ATTACHMENT_STORE_ENV)? {
+            self.attachments.store = store;
+        }
+        if let Some(path) = var(ATTACHMENT_PATH_ENV) {
+            self.attachments.path = path.into();
+        }
+        if let Some(size) = parse_var(&var, ATTACHMENT_MAX_SIZE_ENV)? {
+            self.attachments.max_size_bytes = size;
+        }
+        if let Some(content_types) = var(ATTACHMENT_CONTENT_TYPES_ENV) {
+            self.attachments.content_types = content_types
+                .split(',')
+                .map(|pattern| pattern.trim().to_string())
+                .filter(|pattern| !pattern.is_empty())
+                .collect();
+        }
+        if let Some(secs) = parse_var(&var, ATTACHMENT_GC_INTERVAL_ENV)? {
+            self.attachments.gc_interval_secs = secs;
+        }
+        if let Some(value) = var(S3_ENDPOINT_ENV) {
+            self.attachments
+                .s3
+                .get_or_insert_with(Default::default)
+                .endpoint = value;
+        }
+        if let Some(value) = var(S3_BUCKET_ENV) {
+            self.attachments
+                .s3
+                .get_or_insert_with(Default::default)
+                .bucket = value;
+        }
+        if let Some(value) = var(S3_REGION_ENV) {
+            self.attachments
+                .s3
+                .get_or_insert_with(Default::default)
+                .region = value;
+        }
+        if let Some(value) = var(S3_ACCESS_KEY_ID_ENV) {
+            self.attachments
+                .s3
+                .get_or_insert_with(Default::default)
+                .access_key_id = value;
+        }
+        if let Some(value) = var(S3_SECRET_ACCESS_KEY_ENV) {
+            self.attachments
+                .s3
+                .get_or_insert_with(Default::default)
+                .secret_access_key = value;
+        }
+        if let Some(level) = var(LOG_ENV) {
+            self.log.level = level;
+        }
+
+        Ok(self)
+    }
+
+    // 誤りをまとめて報告する
+    pub fn validate(self) -> Result<Self, Error> {
+        let mut errors = Vec::new();
+
+        if self.server.grpc_addr == self.server.http_addr {
+            errors.push(format!(
+                "server.grpc_addr and server.http_addr must differ: {}",
+                self.server.grpc_addr
+            ));
+        }
+        if let Some(addr) = self.server.metrics_addr {
+            if addr == self.server.grpc_addr || addr == self.server.http_addr {
+                errors.push(format!(
+                    "server.metrics_addr must differ from server.grpc_addr and server.http_addr: {}",
+                    addr
+                ));
+            }
+        }
+        if let Some(tls) = &self.server.tls {
+            for (key, path) in [("cert_path", &tls.cert_path), ("key_path", &tls.key_path)] {
+                if path.as_os_str().is_empty() {
+                    errors.push(format!("server.tls.{} must be set", key));
+                } else if !path.is_file() {
+                    errors.push(format!(
+                        "server.tls.{} does not exist: {}",
+                        key,
+                        path.display()
+                    ));
+                }
+            }
+        }
+        if let Err(e) = self.server.allowed_origins() {
+            errors.push(format!("server.cors_allowed_origins is invalid: {}", e));
+        }
+
+        match (&self.auth.jwks_path, &self.auth.jwt_secret) {
+            (Some(path), _) if !path.is_file() => errors.push(format!(
+                "auth.jwks_path does not exist: {}",
+                path.display()
+            )),
+            (Some(_), _) => {}
+            (None, Some(secret)) if !secret.is_empty() => {}
+            (None, _) => errors.push(format!(
+                "auth.jwt_secret ({}) or auth.jwks_path ({}) must be set",
+                JWT_SECRET_ENV, JWKS_PATH_ENV
+            )),
+        }
+
+        if self.backend == Backend::Sqlite && !cfg!(feature = "sqlite") {
+            errors.push("backend sqlite requires building with the sqlite feature".to_string());
+        }
+        if self.backend != Backend::Memory && self.database.url.is_empty() {
+            errors.push(format!(
+                "database.url ({}) must be set when backend is postgres or sqlite",
+                DATABASE_URL_ENV
+            ));
+        }
+        if self.database.max_size == 0 {
+            errors.push("database.max_size must be greater than 0".to_string());
+        }
+        if let Some(min_size) = self.database.min_size {
+            if min_size > self.database.max_size {
+                errors.push(format!(
+                    "database.min_size ({}) must not exceed database.max_size ({})",
+                    min_size, self.database.max_size
+                ));
+            }
+        }
+        if self.database.connection_timeout_secs == 0 {
+            errors.push("database.connection_timeout_secs must be greater than 0".to_string());
+        }
+
+        if let Some(endpoint) = &self.outbox.endpoint {
+            match endpoint.parse::<Uri>() {
+                Ok(uri) if uri.scheme_str() == Some("http") && uri.host().is_some() => {}
+                _ => errors.push(format!(
+                    "outbox.endpoint must be an http:// URL: {}",
+                    endpoint
+                )),
+            }
+        }
+        if self.outbox.batch_size == 0 {
+            errors.push("outbox.batch_size must be greater than 0".to_string());
+        }
+        if self.outbox.poll_interval_ms == 0 {
+            errors.push("outbox.poll_interval_ms must be greater than 0".to_string());
+        }
+        if self.outbox.lease_secs == 0 {
+            errors.push("outbox.lease_secs must be greater than 0".to_string());
+        }
+
+        if self.events.retention_secs == 0 {
+            errors.push("events.retention_secs must be greater than 0".to_string());
+        }
+        if self.events.prune_interval_secs == 0 {
+            errors.push("events.prune_interval_secs must be greater than 0".to_string());
+        }
+
+        let attachments = &self.attachments;
+        if attachments.store == BlobStoreKind::Local && attachments.path.as_os_str().is_empty() {
+            errors.push("attachments.path must be set when store is local".to_string());
+        }
+        match (&attachments.s3, attachments.store) {
+            (None, BlobStoreKind::S3) => {
+                errors.push("attachments.s3 must be set when store is s3".to_string())
+            }
+            (Some(s3), _) => {
+                match s3.endpoint.parse::<Uri>() {
+                    Ok(uri) if uri.scheme_str() == Some("http") && uri.host().is_some() => {}
+                    _ => errors.push(format!(
+                        "attachments.s3.endpoint must be an http:// URL: {}",
+                        s3.endpoint
+                    )),
+                }
+                for (key, value) in [
+                    ("bucket", &s3.bucket),
+                    ("region", &s3.region),
+                    ("access_key_id", &s3.access_key_id),
+                    ("secret_access_key", &s3.secret_access_key),
+                ] {
+                    if value.is_empty() {
+                        errors.push(format!("attachments.s3.{} must be set", key));
+                    }
+                }
+            }
+            (None, _) => {}
+        }
+        if attachments.max_size_bytes == 0 {
+            errors.push("attachments.max_size_bytes must be greater than 0".to_string());
+        }
+        for pattern in &attachments.content_types {
+            if pattern.split('/').filter(|part| !part.is_empty()).count() != 2 {
+                errors.push(format!(
+                    "attachments.content_types must be type/subtype: {}",
+                    pattern
+                ));
+            }
+        }
+        if attachments.gc_interval_secs == 0 {
+            errors.push("attachments.gc_interval_secs must be greater than 0".to_string());
+        }
+
+        if let Err(e) = EnvFilter::try_new(&self.log.level) {
+            errors.push(format!("log.level is invalid: {}", e));
+        }
+
+        if !errors.is_empty() {
+            bail!("invalid configuration: {}", errors.join("; "))
+        }
+
+        Ok(self)
+    }
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+impl FromStr for Backend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "postgres" => Ok(Backend::Postgres),
+            "memory" => Ok(Backend::Memory),
+            "sqlite" => Ok(Backend::Sqlite),
+            _ => bail!(
+                "unknown backend: {} (expected postgres, memory or sqlite)",
+                s
+            ),
+        }
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            grpc_addr: ([0, 0, 0, 0], 50051).into(),
+            http_addr: ([0, 0, 0, 0], 8080).into(),
+            metrics_addr: None,
+            drain_period_secs: 5,
+            tls: None,
+            cors_allowed_origins: "*".to_string(),
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn drain_period(&self) -> Duration {
+        Duration::from_secs(self.drain_period_secs)
+    }
+
+    pub fn allowed_origins(&self) -> Result<AllowedOrigins, Error> {
+        self.cors_allowed_origins.parse::<AllowedOrigins>()
+    }
+}
+
+impl TlsConfig {
+    pub fn identity(&self) -> Result<Identity, Error> {
+        let cert = std::fs::read(&self.cert_path)
+            .with_context(|| format!("failed to read {}", self.cert_path.display()))?;
+        let key = std::fs::read(&self.key_path)
+            .with_context(|| format!("failed to read {}", self.key_path.display()))?;
+
+        Ok(Identity::from_pem(cert, key))
+    }
+}
+
+impl AuthConfig {
+    pub fn authenticator(&self) -> Result<Authenticator, Error> {
+        match (&self.jwks_path, &self.jwt_secret) {
+            (Some(path), _) => Authenticator::from_jwks_file(path)
+                .with_context(|| format!("failed to load jwks {}", path.display())),
+            (None, Some(secret)) => Ok(Authenticator::from_secret(secret.as_bytes())),
+            (None, None) => bail!("auth.jwt_secret or auth.jwks_path must be set"),
+        }
+    }
+}
+
+impl DatabaseConfig {
+    pub fn connection_timeout(&self) -> Duration {
+        Duration::from_secs(self.connection_timeout_secs)
+    }
+}
+
+// r2d2 の既定値に合わせる
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            min_size: None,
+            max_size: 10,
+            connection_timeout_secs: 30,
+        }
+    }
+}
+
+impl OutboxConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn lease(&self) -> Duration {
+        Duration::from_secs(self.lease_secs)
+    }
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            batch_size: 100,
+            poll_interval_ms: 1000,
+            lease_secs: 30,
+        }
+    }
+}
+
+impl EventConfig {
+    pub fn retention(&self) -> Duration {
+        Duration::from_secs(self.retention_secs)
+    }
+
+    pub fn prune_interval(&self) -> Duration {
+        Duration::from_secs(self.prune_interval_secs)
+    }
+}
+
+// 切断したクライアントが翌日までに再開できるようにする
+impl Default for EventConfig {
+    fn default() -> Self {
+        Self {
+            retention_secs: 24 * 60 * 60,
+            prune_interval_secs: 600,
+        }
+    }
+}
+
+impl FromStr for BlobStoreKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(BlobStoreKind::Local),
+            "s3" => Ok(BlobStoreKind::S3),
+            _ => bail!("unknown attachment store: {} (expected local or s3)", s),
+        }
+    }
+}
+
+impl AttachmentConfig {
+    pub fn gc_interval(&self) -> Duration {
+        Duration::from_secs(self.gc_interval_secs)
+    }
+}
+
+// スクリーンショットと PDF を 10 MiB まで受け付ける
+impl Default for AttachmentConfig {
+    fn default() -> Self {
+        Self {
+            store: BlobStoreKind::Local,
+            path: "attachments".into(),
+            s3: None,
+            max_size_bytes: 10 * 1024 * 1024,
+            content_types: vec!["image/*".to_string(), "application/pdf".to_string()],
+            gc_interval_secs: 60,
+        }
+    }
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
+        }
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+        }
+    }
+}
+
+fn parse_var<F, T>(var: &F, name: &str) -> Result<Option<T>, Error>
+where
+    F: Fn(&str) -> Option<String>,
+    T: FromStr,
+    T::Err: Display,
+{
+    var(name)
+        .map(|value| {
+            value
+                .parse::<T>()
+                .map_err(|e| anyhow!("{} is invalid: {}", name, e))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = r#"
+            backend = "memory"
+
+            [server]
+            grpc_addr = "127.0.0.1:50052"
+            metrics_addr = "127.0.0.1:9090"
+            cors_allowed_origins = "http://localhost:3000"
+
+            [auth]
+            jwt_secret = "secret"
+
+            [database]
+            max_size = 4
+            connection_timeout_secs = 5
+
+            [outbox]
+            endpoint = "http://localhost:9000/events"
+
+            [events]
+            retention_secs = 3600
+
+            [attachments]
+            store = "s3"
+            max_size_bytes = 1024
+
+            [attachments.s3]
+            endpoint = "http://localhost:9000"
+            bucket = "fusen"
+
+            [log]
+            level = "debug"
+        "#
+        .parse::<Config>()
+        .unwrap();
+
+        assert_eq!(config.backend, Backend::Memory);
+        assert_eq!(
+            config.server.grpc_addr,
+            "127.0.0.1:50052".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(config.server.http_addr, ServerConfig::default().http_addr);
+        assert_eq!(
+            config.server.metrics_addr,
+            Some("127.0.0.1:9090".parse::<SocketAddr>().unwrap())
+        );
+        assert_eq!(
+            config.server.allowed_origins().unwrap(),
+            "http://localhost:3000".parse::<AllowedOrigins>().unwrap()
+        );
+        assert_eq!(config.auth.jwt_secret, Some("secret".to_string()));
+        assert_eq!(config.auth.jwks_path, None);
+        assert_eq!(config.database.max_size, 4);
+        assert_eq!(config.database.min_size, None);
+        assert_eq!(config.database.connection_timeout(), Duration::from_secs(5));
+        assert_eq!(
+            config.outbox.endpoint,
+            Some("http://localhost:9000/events".to_string())
+        );
+        assert_eq!(config.outbox.batch_size, 100);
+        assert_eq!(config.events.retention(), Duration::from_secs(3600));
+        assert_eq!(config.events.prune_interval(), Duration::from_secs(600));
+        assert_eq!(config.attachments.store, BlobStoreKind::S3);
+        assert_eq!(config.attachments.max_size_bytes, 1024);
+        assert_eq!(
+            config.attachments.content_types,
+            AttachmentConfig::default().content_types
+        );
+        assert_eq!(
+            config.attachments.s3.as_ref().map(|s3| s3.region.as_str()),
+            Some("us-east-1")
+        );
+        assert_eq!(config.log.level, "debug".to_string());
+
+        assert_eq!("".parse::<Config>().unwrap(), Config::default());
+        assert_eq!(
+            "backend = \"sqlite\"".parse::<Config>().unwrap().backend,
+            Backend::Sqlite
+        );
+        assert!("backend = \"mysql\"".parse::<Config>().is_err());
+        assert!("[database]\nmax = 1".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_override_with() {
+        let config = Config::default()
+            .override_with(vars(&[
+                ("FUSEN_BACKEND", "memory"),
+                ("FUSEN_HTTP_ADDR", "127.0.0.1:8081"),
+                ("FUSEN_METRICS_ADDR", "127.0.0.1:9091"),
+                ("FUSEN_DRAIN_PERIOD_SECS", "0"),
+                ("FUSEN_DATABASE_URL", "postgres://localhost/fusen"),
+                ("FUSEN_DATABASE_MIN_SIZE", "1"),
+                ("FUSEN_TLS_CERT_PATH", "cert.pem"),
+                ("FUSEN_CORS_ALLOWED_ORIGINS", "https://peta.example.com"),
+                ("FUSEN_JWKS_PATH", "jwks.json"),
+                ("FUSEN_OUTBOX_POLL_INTERVAL_MS", "250"),
+                ("FUSEN_EVENT_PRUNE_INTERVAL_SECS", "30"),
+                ("FUSEN_ATTACHMENT_STORE", "s3"),
+                (
+                    "FUSEN_ATTACHMENT_CONTENT_TYPES",
+                    "image/png, application/pdf,",
+                ),
+                ("FUSEN_ATTACHMENT_GC_INTERVAL_SECS", "5"),
+                ("FUSEN_S3_BUCKET", "fusen"),
+                ("FUSEN_LOG", "warn"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.backend, Backend::Memory);
+        assert_eq!(
+            config.server.http_addr,
+            "127.0.0.1:8081".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            config.server.metrics_addr,
+            Some("127.0.0.1:9091".parse::<SocketAddr>().unwrap())
+        );
+        assert_eq!(config.server.drain_period(), Duration::ZERO);
+        assert_eq!(
+            config.database.url,
+            "postgres://localhost/fusen".to_string()
+        );
+        assert_eq!(config.database.min_size, Some(1));
+        assert_eq!(
+            config.server.tls,
+            Some(TlsConfig {
+                cert_path: "cert.pem".into(),
+                key_path: PathBuf::new(),
+            })
+        );
+        assert_eq!(
+            config.server.cors_allowed_origins,
+            "https://peta.example.com".to_string()
+        );
+        assert_eq!(config.auth.jwks_path, Some("jwks.json".into()));
+        assert_eq!(config.outbox.poll_interval(), Duration::from_millis(250));
+        assert_eq!(config.events.prune_interval(), Duration::from_secs(30));
+        assert_eq!(config.attachments.store, BlobStoreKind::S3);
+        assert_eq!(
+            config.attachments.content_types,
+            vec!["image/png".to_string(), "application/pdf".to_string()]
+        );
+        assert_eq!(config.attachments.gc_interval(), Duration::from_secs(5));
+        assert_eq!(
+            config.attachments.s3,
+            Some(S3Config {
+                bucket: "fusen".to_string(),
+                ..Default::default()
+            })
+        );
+        assert_eq!(config.log.level, "warn".to_string());
+
+        let err = Config::default()
+            .override_with(vars(&[("FUSEN_DATABASE_MAX_SIZE", "many")]))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("FUSEN_DATABASE_MAX_SIZE is invalid"));
+    }
+
+    #[test]
+    fn test_validate() {
+        let auth = AuthConfig {
+            jwt_secret: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let config = Config {
+            auth: auth.clone(),
+            database: DatabaseConfig {
+                url: "postgres://localhost/fusen".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.clone().validate().is_ok());
+
+        // memory では database.url を使わない
+        assert!(Config {
+            backend: Backend::Memory,
+            auth: auth.clone(),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+
+        let sqlite = Config {
+            backend: Backend::Sqlite,
+            auth: auth.clone(),
+            database: DatabaseConfig {
+                url: ":memory:".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .validate();
+        assert_eq!(sqlite.is_ok(), cfg!(feature = "sqlite"));
+
+        let err = Config {
+            server: ServerConfig {
+                metrics_addr: Some(ServerConfig::default().http_addr),
+                cors_allowed_origins: " , ".to_string(),
+                ..Default::default()
+            },
+            database: DatabaseConfig {
+                min_size: Some(20),
+                max_size: 10,
+                connection_timeout_secs: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("server.metrics_addr must differ"));
+        assert!(err.contains("server.cors_allowed_origins is invalid"));
+        assert!(err.contains(
+            "auth.jwt_secret (FUSEN_JWT_SECRET) or auth.jwks_path (FUSEN_JWKS_PATH) must be set"
+        ));
+        assert!(err.contains("database.url (FUSEN_DATABASE_URL) must be set"));
+        assert!(err.contains("database.min_size (20) must not exceed database.max_size (10)"));
+        assert!(err.contains("database.connection_timeout_secs must be greater than 0"));
+
+        let err = Config {
+            outbox: OutboxConfig {
+                endpoint: Some("https://localhost/events".to_string()),
+                batch_size: 0,
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("outbox.endpoint must be an http:// URL"));
+        assert!(err.contains("outbox.batch_size must be greater than 0"));
+
+        let err = Config {
+            events: EventConfig {
+                retention_secs: 0,
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("events.retention_secs must be greater than 0"));
+
+        let err = Config {
+            attachments: AttachmentConfig {
+                store: BlobStoreKind::S3,
+                max_size_bytes: 0,
+                content_types: vec!["image".to_string()],
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("attachments.s3 must be set when store is s3"));
+        assert!(err.contains("attachments.max_size_bytes must be greater than 0"));
+        assert!(err.contains("attachments.content_types must be type/subtype: image"));
+
+        let err = Config {
+            attachments: AttachmentConfig {
+                store: BlobStoreKind::S3,
+                s3: Some(S3Config {
+                    endpoint: "https://s3.amazonaws.com".to_string(),
+                    bucket: "fusen".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("attachments.s3.endpoint must be an http:// URL"));
+        assert!(err.contains("attachments.s3.access_key_id must be set"));
+
+        assert!(Config {
+            server: ServerConfig {
+                tls: Some(TlsConfig {
+                    cert_path: "not/found.pem".into(),
+                    key_path: PathBuf::new(),
+                }),
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(Config {
+            auth: AuthConfig {
+                jwks_path: Some("not/found.json".into()),
+                ..Default::default()
+            },
+            ..config.clone()
+        }
+        .validate()
+        .unwrap_err()
+        .to_string()
+        .contains("auth.jwks_path does not exist: not/found.json"));
+        assert!(Config {
+            log: LogConfig {
+                level: "info,[".to_string(),
+            },
+            ..config
+        }
+        .validate()
+        .is_err());
+    }
+}
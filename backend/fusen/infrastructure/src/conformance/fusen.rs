@@ -0,0 +1,199 @@
+use domain::entity::{Board, Fusen, FusenBuilder};
+use domain::error::DomainError;
+use domain::repository::{CreateRepository, DeleteRepository, GetRepository};
+use domain::vo::*;
+use ulid::Ulid;
+
+// FusenRepository の実装が満たすべき振る舞い。
+// setup は (リポジトリ, 作成先の board の id) を返す Future で、テストごとに評価される
+macro_rules! fusen_repository_conformance {
+    ($setup:expr) => {
+        mod conformance {
+            use super::*;
+            use crate::conformance;
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_create_and_get() {
+                let (sut, board_id) = $setup.await;
+                conformance::create_and_get(&sut, &board_id).await;
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_duplicate_id() {
+                let (sut, board_id) = $setup.await;
+                conformance::duplicate_id(&sut, &board_id).await;
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_not_found() {
+                let (sut, board_id) = $setup.await;
+                conformance::not_found(&sut, &board_id).await;
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_delete() {
+                let (sut, board_id) = $setup.await;
+                conformance::delete(&sut, &board_id).await;
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_unicode() {
+                let (sut, board_id) = $setup.await;
+                conformance::unicode(&sut, &board_id).await;
+            }
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn test_conformance_large_note() {
+                let (sut, board_id) = $setup.await;
+                conformance::large_note(&sut, &board_id).await;
+            }
+        }
+    };
+}
+
+pub(crate) use fusen_repository_conformance;
+
+// 各バックエンドのテストで共有する board。外部キーを満たすために事前に作成しておく
+pub(crate) const BOARD_ID: &str = "01FMB0ARDXKQ7B1GZ1Z0K0T8R0";
+
+// データベースに残らないよう、作成した fusen は各ケースの最後に削除する
+fn new_fusen(board_id: &Id<Board>, title: &str, note: &str) -> Fusen {
+    FusenBuilder::default()
+        .id(Ulid::new().to_string().parse::<Id<Fusen>>().unwrap())
+        .owner_id("owner".parse::<OwnerId>().unwrap())
+        .board_id(board_id.clone())
+        .title(title.parse::<FusenTitle>().unwrap())
+        .note(note.parse::<FusenNote>().unwrap())
+        .position(Position::new(-100, 200).unwrap())
+        .size(FusenSize::new(120, 80).unwrap())
+        .color(FusenColor::Pink)
+        .z_index(ZIndex::new(7).unwrap())
+        // PostgreSQL の timestamptz はマイクロ秒までしか保持しない
+        .create_at("2021-10-17T07:08:37.123456Z".parse::<Timestamp>().unwrap())
+        .update_at("2021-10-18T07:08:37.654321Z".parse::<Timestamp>().unwrap())
+        .build()
+        .unwrap()
+}
+
+// 保存したすべての属性がそのまま読み出せる
+pub(crate) async fn create_and_get<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let entity = new_fusen(board_id, "title", "note");
+
+    sut.create(entity.clone()).await.unwrap();
+    assert_eq!(sut.get(entity.id().clone()).await.unwrap(), entity);
+
+    sut.delete(entity).await.unwrap();
+}
+
+// 同じ id の fusen は上書きせずに Conflict を返す
+pub(crate) async fn duplicate_id<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let entity = new_fusen(board_id, "title", "note");
+    let mut duplicate = entity.clone();
+    duplicate.set_title("duplicate".parse::<FusenTitle>().unwrap());
+    duplicate.set_note("duplicate".parse::<FusenNote>().unwrap());
+
+    sut.create(entity.clone()).await.unwrap();
+    assert!(matches!(
+        sut.create(duplicate).await,
+        Err(DomainError::Conflict(_))
+    ));
+    assert_eq!(sut.get(entity.id().clone()).await.unwrap(), entity);
+
+    sut.delete(entity).await.unwrap();
+}
+
+// 存在しない fusen の取得と削除は NotFound を返す
+pub(crate) async fn not_found<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let entity = new_fusen(board_id, "title", "note");
+
+    assert!(matches!(
+        sut.get(entity.id().clone()).await,
+        Err(DomainError::NotFound(_))
+    ));
+    assert!(matches!(
+        sut.delete(entity).await,
+        Err(DomainError::NotFound(_))
+    ));
+}
+
+// 削除した fusen は取得できず、二度目の削除は NotFound を返す
+pub(crate) async fn delete<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let entity = new_fusen(board_id, "title", "note");
+    let other = new_fusen(board_id, "title", "note");
+    sut.create(entity.clone()).await.unwrap();
+    sut.create(other.clone()).await.unwrap();
+
+    sut.delete(entity.clone()).await.unwrap();
+    assert!(matches!(
+        sut.get(entity.id().clone()).await,
+        Err(DomainError::NotFound(_))
+    ));
+    assert!(matches!(
+        sut.delete(entity).await,
+        Err(DomainError::NotFound(_))
+    ));
+    assert_eq!(sut.get(other.id().clone()).await.unwrap(), other);
+
+    sut.delete(other).await.unwrap();
+}
+
+// マルチバイト文字や結合文字、サロゲートペアになる文字も変換せずに保存する。
+// title の長さはバイト数ではなく文字数で数える
+pub(crate) async fn unicode<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let entities = vec![
+        new_fusen(
+            board_id,
+            "付箋 ｆｕｓｅｎ 한국어 Cafe\u{301}",
+            "🎉👨‍👩‍👧 مرحبا\r\n\tくぁｗせｄｒｆｔｇｙふじこｌｐ\u{feff}",
+        ),
+        new_fusen(board_id, &"𠮷".repeat(64), &"あ".repeat(64)),
+        // title の上限は書記素で数えるので、コードポイントでは 64 を超える
+        new_fusen(board_id, &"🇯🇵".repeat(64), "日報（10/18）: C++ tips"),
+    ];
+
+    for entity in entities {
+        sut.create(entity.clone()).await.unwrap();
+        let fusen = sut.get(entity.id().clone()).await.unwrap();
+        assert_eq!(fusen.title(), entity.title());
+        assert_eq!(fusen.note(), entity.note());
+
+        sut.delete(entity).await.unwrap();
+    }
+}
+
+// note の長さには制限がない
+pub(crate) async fn large_note<R>(sut: &R, board_id: &Id<Board>)
+where
+    R: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+{
+    let note = "## 見出し\n\n本文です。🎉\n".repeat(32 * 1024);
+    assert!(note.len() > 1024 * 1024);
+    let entity = new_fusen(board_id, "title", &note);
+
+    sut.create(entity.clone()).await.unwrap();
+    assert_eq!(
+        sut.get(entity.id().clone())
+            .await
+            .unwrap()
+            .note()
+            .to_string(),
+        note
+    );
+
+    sut.delete(entity).await.unwrap();
+}
@@ -0,0 +1,3 @@
+mod fusen;
+
+pub(crate) use self::fusen::*;
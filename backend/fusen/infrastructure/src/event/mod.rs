@@ -0,0 +1,3 @@
+mod pruner;
+
+pub use self::pruner::*;
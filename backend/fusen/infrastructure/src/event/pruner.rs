@@ -0,0 +1,141 @@
+use crate::shutdown::Shutdown;
+use anyhow::{Error, Result};
+use chrono::Utc;
+use domain::repository::PruneRepository;
+use domain::vo::Timestamp;
+use std::time::Duration;
+
+const BATCH_SIZE: usize = 1000;
+
+// Watch の再開に使うイベントのうち、保持期間を過ぎたものを消す。
+// 消したイベントの後から再開しようとしたクライアントには最初から読み直させる
+pub struct Pruner<P> {
+    events: P,
+    retention: Duration,
+    interval: Duration,
+}
+
+impl<P: PruneRepository> Pruner<P> {
+    pub fn new(events: P, retention: Duration, interval: Duration) -> Self {
+        Self {
+            events,
+            retention,
+            interval,
+        }
+    }
+
+    pub async fn run(self, shutdown: Shutdown) -> Result<(), Error> {
+        loop {
+            let result = tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                result = self.prune_once() => result,
+            };
+            let wait = match result {
+                // 消しきれていないイベントがあるので待たずに続ける
+                Ok(pruned) if pruned == BATCH_SIZE => Duration::ZERO,
+                Ok(_) => self.interval,
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to prune fusen events");
+                    self.interval
+                }
+            };
+
+            tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                _ = tokio::time::sleep(wait) => {}
+            }
+        }
+    }
+
+    pub async fn prune_once(&self) -> Result<usize, Error> {
+        let before = Utc::now() - chrono::Duration::from_std(self.retention)?;
+        let count = self
+            .events
+            .prune(Timestamp::new(before), BATCH_SIZE)
+            .await?;
+        if count > 0 {
+            tracing::info!(count, "pruned expired fusen events");
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{FusenEventRepository, FusenRepository, OutboxRepository};
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::error::DomainError;
+    use domain::repository::{CreateRepository, SubscribeRepository};
+    use domain::vo::{EventId, FusenNote, FusenTitle, Id, OwnerId};
+    use futures::StreamExt;
+
+    async fn create(fusens: &FusenRepository, id: &str, occurred_at: Timestamp) {
+        let mut fusen = FusenBuilder::default()
+            .id(id.parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at(occurred_at)
+            .update_at(occurred_at)
+            .build()
+            .unwrap();
+        fusen.record_created(occurred_at);
+        fusens.create(fusen).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_prune_once() {
+        let events = FusenEventRepository::default();
+        let fusens = FusenRepository::new(OutboxRepository::default(), events.clone());
+        let expired = "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap();
+        create(&fusens, "01F8MECHZX3TBDSZ7XRADM79XE", expired).await;
+        create(
+            &fusens,
+            "01F8MECHZX3TBDSZ7XRADM79XF",
+            Timestamp::new(Utc::now()),
+        )
+        .await;
+
+        let sut = Pruner::new(events.clone(), Duration::from_secs(60), Duration::ZERO);
+        assert_eq!(sut.prune_once().await.unwrap(), 1);
+        assert_eq!(sut.prune_once().await.unwrap(), 0);
+
+        // 残っているイベントからは再開でき、消したイベントからは再開できない
+        let mut resumed = events.subscribe(Some(EventId::new(1))).await.unwrap();
+        assert_eq!(
+            resumed.next().await.unwrap().unwrap().1.fusen().id(),
+            &"01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap()
+        );
+        assert!(matches!(
+            events.subscribe(Some(EventId::new(0))).await,
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pruner_run() {
+        let events = FusenEventRepository::default();
+        let fusens = FusenRepository::new(OutboxRepository::default(), events.clone());
+        let shutdown = Shutdown::default();
+        let pruner = tokio::spawn(
+            Pruner::new(
+                events.clone(),
+                Duration::from_secs(60),
+                Duration::from_millis(10),
+            )
+            .run(shutdown.clone()),
+        );
+
+        let expired = "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap();
+        create(&fusens, "01F8MECHZX3TBDSZ7XRADM79XE", expired).await;
+        while events.subscribe(Some(EventId::new(0))).await.is_ok() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        shutdown.drain(Duration::ZERO).await;
+        pruner.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::grpc::service::observe;
+use derive_new::new;
+use interface::controller::{AttachmentController, DownloadStream, UploadStream};
+use interface::peta_fusen_v1::attachment_service_server::AttachmentService;
+use interface::peta_fusen_v1::UploadAttachmentResponse;
+use interface::peta_fusen_v1::{DownloadAttachmentRequest, UploadAttachmentRequest};
+use interface::peta_fusen_v1::{ListAttachmentsRequest, ListAttachmentsResponse};
+use std::sync::Arc;
+use tonic::{Request, Response, Status, Streaming};
+use tracing::field::Empty;
+use tracing::{info_span, Span};
+
+#[derive(new)]
+pub(crate) struct Attachments<A>
+where
+    A: AttachmentController + std::marker::Sync + std::marker::Send,
+{
+    controller: Arc<A>,
+}
+
+#[tonic::async_trait]
+impl<A> AttachmentService for Attachments<A>
+where
+    A: AttachmentController + std::marker::Sync + std::marker::Send + 'static,
+{
+    // 内容を受け取り終えるまでを計測する
+    async fn upload(
+        &self,
+        request: Request<Streaming<UploadAttachmentRequest>>,
+    ) -> Result<Response<UploadAttachmentResponse>, Status> {
+        let span = rpc_span("Upload");
+        let request = request.map(|stream| Box::pin(stream) as UploadStream);
+
+        observe(&span, async {
+            let result = self.controller.upload(request).await;
+            if let Ok(Some(attachment)) = result.as_ref().map(|r| &r.get_ref().attachment) {
+                span.record("attachment_id", attachment.id.as_str());
+                span.record("fusen_id", attachment.fusen_id.as_str());
+            }
+            result
+        })
+        .await
+    }
+
+    type DownloadStream = DownloadStream;
+
+    // ストリームの開始までを計測する
+    async fn download(
+        &self,
+        request: Request<DownloadAttachmentRequest>,
+    ) -> Result<Response<Self::DownloadStream>, Status> {
+        let span = rpc_span("Download");
+        span.record("attachment_id", request.get_ref().id.as_str());
+
+        observe(&span, self.controller.download(request)).await
+    }
+
+    async fn list(
+        &self,
+        request: Request<ListAttachmentsRequest>,
+    ) -> Result<Response<ListAttachmentsResponse>, Status> {
+        let span = rpc_span("List");
+        span.record("fusen_id", request.get_ref().fusen_id.as_str());
+
+        observe(&span, self.controller.list(request)).await
+    }
+}
+
+fn rpc_span(method: &'static str) -> Span {
+    info_span!(
+        "rpc",
+        service = "peta.fusen.v1.AttachmentService",
+        method,
+        attachment_id = Empty,
+        fusen_id = Empty,
+        code = Empty,
+        latency_ms = Empty,
+    )
+}
@@ -0,0 +1,82 @@
+use crate::grpc::service::observe;
+use derive_new::new;
+use interface::controller::BoardController;
+use interface::peta_fusen_v1::board_service_server::BoardService;
+use interface::peta_fusen_v1::{CreateBoardRequest, CreateBoardResponse};
+use interface::peta_fusen_v1::{DeleteBoardRequest, DeleteBoardResponse};
+use interface::peta_fusen_v1::{ListBoardsRequest, ListBoardsResponse};
+use interface::peta_fusen_v1::{RenameBoardRequest, RenameBoardResponse};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+use tracing::field::Empty;
+use tracing::{info_span, Span};
+
+#[derive(new)]
+pub(crate) struct Boards<B>
+where
+    B: BoardController + std::marker::Sync + std::marker::Send,
+{
+    controller: Arc<B>,
+}
+
+#[tonic::async_trait]
+impl<B> BoardService for Boards<B>
+where
+    B: BoardController + std::marker::Sync + std::marker::Send + 'static,
+{
+    async fn create(
+        &self,
+        request: Request<CreateBoardRequest>,
+    ) -> Result<Response<CreateBoardResponse>, Status> {
+        let span = rpc_span("Create");
+
+        observe(&span, async {
+            let result = self.controller.create(request).await;
+            if let Ok(Some(board)) = result.as_ref().map(|r| &r.get_ref().board) {
+                span.record("board_id", board.id.as_str());
+            }
+            result
+        })
+        .await
+    }
+
+    async fn list(
+        &self,
+        request: Request<ListBoardsRequest>,
+    ) -> Result<Response<ListBoardsResponse>, Status> {
+        let span = rpc_span("List");
+
+        observe(&span, self.controller.list(request)).await
+    }
+
+    async fn rename(
+        &self,
+        request: Request<RenameBoardRequest>,
+    ) -> Result<Response<RenameBoardResponse>, Status> {
+        let span = rpc_span("Rename");
+        span.record("board_id", request.get_ref().id.as_str());
+
+        observe(&span, self.controller.rename(request)).await
+    }
+
+    async fn delete(
+        &self,
+        request: Request<DeleteBoardRequest>,
+    ) -> Result<Response<DeleteBoardResponse>, Status> {
+        let span = rpc_span("Delete");
+        span.record("board_id", request.get_ref().id.as_str());
+
+        observe(&span, self.controller.delete(request)).await
+    }
+}
+
+fn rpc_span(method: &'static str) -> Span {
+    info_span!(
+        "rpc",
+        service = "peta.fusen.v1.BoardService",
+        method,
+        board_id = Empty,
+        code = Empty,
+        latency_ms = Empty,
+    )
+}
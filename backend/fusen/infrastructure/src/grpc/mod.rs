@@ -1,3 +1,7 @@
+mod attachment;
+mod board;
+mod health;
 mod service;
 
+pub use self::health::HealthCheck;
 pub use self::service::Service;
@@ -1,66 +1,332 @@
+use crate::config::TlsConfig;
+use crate::grpc::attachment::Attachments;
+use crate::grpc::board::Boards;
+use crate::grpc::health::{self, HealthCheck};
+use crate::http::AllowedOrigins;
+use crate::jwt::Authenticator;
+use crate::metrics::GrpcMetricsLayer;
+use crate::shutdown::Shutdown;
 use anyhow::Result;
 use derive_new::new;
-use interface::controller::Controller;
+use futures::StreamExt;
+use interface::controller::{AttachmentController, BoardController, Controller};
+use interface::controller::{ExportStream, ImportStream, WatchStream};
+use interface::peta_fusen_v1::attachment_service_server::AttachmentServiceServer;
+use interface::peta_fusen_v1::board_service_server::BoardServiceServer;
 use interface::peta_fusen_v1::fusen_service_server::{FusenService, FusenServiceServer};
+use interface::peta_fusen_v1::ExportRequest;
+use interface::peta_fusen_v1::WatchRequest;
+use interface::peta_fusen_v1::FILE_DESCRIPTOR_SET;
+use interface::peta_fusen_v1::{BatchCreateRequest, BatchCreateResponse};
+use interface::peta_fusen_v1::{BatchDeleteRequest, BatchDeleteResponse};
+use interface::peta_fusen_v1::{BatchGetRequest, BatchGetResponse};
 use interface::peta_fusen_v1::{CreateRequest, CreateResponse};
 use interface::peta_fusen_v1::{DeleteRequest, DeleteResponse};
 use interface::peta_fusen_v1::{GetRequest, GetResponse};
+use interface::peta_fusen_v1::{ImportRequest, ImportResponse};
 use interface::peta_fusen_v1::{ListRequest, ListResponse};
+use interface::peta_fusen_v1::{MoveRequest, MoveResponse};
+use interface::peta_fusen_v1::{UpdateRequest, UpdateResponse};
+use std::future::Future;
 use std::net::SocketAddr;
-use tonic::{transport::Server, Request, Response, Status};
+use std::sync::Arc;
+use std::time::Instant;
+use tonic::transport::{NamedService, Server, ServerTlsConfig};
+use tonic::{Code, Request, Response, Status, Streaming};
+use tower_http::trace::TraceLayer;
+use tracing::field::Empty;
+use tracing::{info_span, Instrument, Span};
 
 #[derive(new)]
-pub struct Service<C>
+pub struct Service<C, B, A>
 where
     C: Controller + std::marker::Sync + std::marker::Send,
+    B: BoardController + std::marker::Sync + std::marker::Send,
+    A: AttachmentController + std::marker::Sync + std::marker::Send,
 {
-    controller: C,
+    controller: Arc<C>,
+    board_controller: Arc<B>,
+    attachment_controller: Arc<A>,
+    authenticator: Authenticator,
+    shutdown: Shutdown,
 }
 
 #[tonic::async_trait]
-impl<C> FusenService for Service<C>
+impl<C, B, A> FusenService for Service<C, B, A>
 where
     C: Controller + std::marker::Sync + std::marker::Send + 'static,
+    B: BoardController + std::marker::Sync + std::marker::Send + 'static,
+    A: AttachmentController + std::marker::Sync + std::marker::Send + 'static,
 {
     async fn create(
         &self,
         request: Request<CreateRequest>,
     ) -> Result<Response<CreateResponse>, Status> {
-        println!("{:?}", request); // TODO: logger を実装して println! を削除する
+        let span = rpc_span("Create");
 
-        self.controller.create(request)
+        observe(&span, async {
+            let result = self.controller.create(request).await;
+            if let Ok(Some(fusen)) = result.as_ref().map(|r| &r.get_ref().fusen) {
+                span.record("fusen_id", fusen.id.as_str());
+            }
+            result
+        })
+        .await
     }
 
-    async fn list(&self, _: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
-        Err(Status::unimplemented("unimplemented"))
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
+        let span = rpc_span("List");
+
+        observe(&span, self.controller.list(request)).await
     }
 
     async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
-        println!("{:?}", request); // TODO: logger を実装して println! を削除する
+        let span = rpc_span("Get");
+        span.record("fusen_id", request.get_ref().id.as_str());
+
+        observe(&span, self.controller.get(request)).await
+    }
+
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        let span = rpc_span("Update");
+        span.record("fusen_id", request.get_ref().id.as_str());
 
-        self.controller.get(request)
+        observe(&span, self.controller.update(request)).await
     }
 
     async fn delete(
         &self,
         request: Request<DeleteRequest>,
     ) -> Result<Response<DeleteResponse>, Status> {
-        println!("{:?}", request); // TODO: logger を実装して println! を削除する
+        let span = rpc_span("Delete");
+        span.record("fusen_id", request.get_ref().id.as_str());
+
+        observe(&span, self.controller.delete(request)).await
+    }
+
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateRequest>,
+    ) -> Result<Response<BatchCreateResponse>, Status> {
+        let span = rpc_span("BatchCreate");
+
+        observe(&span, self.controller.batch_create(request)).await
+    }
+
+    async fn batch_get(
+        &self,
+        request: Request<BatchGetRequest>,
+    ) -> Result<Response<BatchGetResponse>, Status> {
+        let span = rpc_span("BatchGet");
+
+        observe(&span, self.controller.batch_get(request)).await
+    }
+
+    async fn batch_delete(
+        &self,
+        request: Request<BatchDeleteRequest>,
+    ) -> Result<Response<BatchDeleteResponse>, Status> {
+        let span = rpc_span("BatchDelete");
+
+        observe(&span, self.controller.batch_delete(request)).await
+    }
+
+    async fn r#move(
+        &self,
+        request: Request<MoveRequest>,
+    ) -> Result<Response<MoveResponse>, Status> {
+        let span = rpc_span("Move");
+
+        observe(&span, self.controller.r#move(request)).await
+    }
+
+    type ExportStream = ExportStream;
+
+    // ストリームの開始までを計測する
+    async fn export(
+        &self,
+        request: Request<ExportRequest>,
+    ) -> Result<Response<Self::ExportStream>, Status> {
+        let span = rpc_span("Export");
+
+        observe(&span, self.controller.export(request)).await
+    }
+
+    async fn import(
+        &self,
+        request: Request<Streaming<ImportRequest>>,
+    ) -> Result<Response<ImportResponse>, Status> {
+        let span = rpc_span("Import");
+        let request = request.map(|stream| Box::pin(stream) as ImportStream);
 
-        self.controller.delete(request)
+        observe(&span, self.controller.import(request)).await
+    }
+
+    type WatchStream = WatchStream;
+
+    // ストリームの開始までを計測する。停止時はストリームを閉じて再接続を促す
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let span = rpc_span("Watch");
+
+        let response = observe(&span, self.controller.watch(request)).await?;
+        let shutdown = self.shutdown.clone();
+        Ok(response.map(|stream| {
+            Box::pin(stream.take_until(async move { shutdown.stopping().await })) as WatchStream
+        }))
     }
 }
 
-impl<C> Service<C>
+impl<C, B, A> Service<C, B, A>
 where
     C: Controller + std::marker::Sync + std::marker::Send + 'static,
+    B: BoardController + std::marker::Sync + std::marker::Send + 'static,
+    A: AttachmentController + std::marker::Sync + std::marker::Send + 'static,
 {
-    pub async fn serve(self, addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
-        Server::builder()
-            .add_service(FusenServiceServer::new(self))
-            .serve(addr)
+    pub async fn serve<H: HealthCheck>(
+        self,
+        addr: SocketAddr,
+        tls: Option<TlsConfig>,
+        connections: H,
+        allowed_origins: AllowedOrigins,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (reporter, health_service) = tonic_health::server::health_reporter();
+        // "" はサーバー全体の状態を表す
+        tokio::spawn(health::report(
+            reporter,
+            connections,
+            &[
+                "",
+                FusenServiceServer::<Self>::NAME,
+                BoardServiceServer::<Boards<B>>::NAME,
+                AttachmentServiceServer::<Attachments<A>>::NAME,
+            ],
+            self.shutdown.clone(),
+        ));
+
+        let authenticator = self.authenticator.clone();
+        let board_service = BoardServiceServer::with_interceptor(
+            Boards::new(self.board_controller.clone()),
+            move |request| authenticator.intercept(request),
+        );
+
+        let authenticator = self.authenticator.clone();
+        let attachment_service = AttachmentServiceServer::with_interceptor(
+            Attachments::new(self.attachment_controller.clone()),
+            move |request| authenticator.intercept(request),
+        );
+
+        let shutdown = self.shutdown.clone();
+        let authenticator = self.authenticator.clone();
+        let fusen_service = FusenServiceServer::with_interceptor(self, move |request| {
+            authenticator.intercept(request)
+        });
+
+        let reflection_service = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(
+                tonic_health::proto::GRPC_HEALTH_V1_FILE_DESCRIPTOR_SET,
+            )
+            .build()?;
+
+        // reflection 自身の descriptor は公開されていないのでメソッドを直接登録する
+        let metrics_layer = GrpcMetricsLayer::default()
+            .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)?
+            .register_encoded_file_descriptor_set(
+                tonic_health::proto::GRPC_HEALTH_V1_FILE_DESCRIPTOR_SET,
+            )?
+            .register_method(
+                "grpc.reflection.v1alpha.ServerReflection",
+                "ServerReflectionInfo",
+            );
+
+        let mut server = Server::builder();
+        if let Some(tls) = tls {
+            server = server.tls_config(ServerTlsConfig::new().identity(tls.identity()?))?;
+        }
+
+        // gRPC-Web は HTTP/1.1 でも届く
+        server
+            .accept_http1(true)
+            .layer(TraceLayer::new_for_grpc())
+            .layer(metrics_layer)
+            .add_service(health_service)
+            .add_service(reflection_service)
+            .add_service(allowed_origins.grpc_web_config().enable(fusen_service))
+            .add_service(allowed_origins.grpc_web_config().enable(board_service))
+            .add_service(allowed_origins.grpc_web_config().enable(attachment_service))
+            .serve_with_shutdown(addr, shutdown.stopping())
             .await?;
 
         Ok(())
     }
 }
+
+fn rpc_span(method: &'static str) -> Span {
+    info_span!(
+        "rpc",
+        service = "peta.fusen.v1.FusenService",
+        method,
+        fusen_id = Empty,
+        code = Empty,
+        latency_ms = Empty,
+    )
+}
+
+pub(super) async fn observe<T, F>(span: &Span, f: F) -> Result<Response<T>, Status>
+where
+    F: Future<Output = Result<Response<T>, Status>>,
+{
+    let started = Instant::now();
+
+    let result = f.instrument(span.clone()).await;
+
+    let _entered = span.enter();
+
+    let code = match &result {
+        Ok(_) => Code::Ok,
+        Err(status) => status.code(),
+    };
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    span.record("code", format!("{:?}", code).as_str());
+
+    match &result {
+        Ok(_) => tracing::info!("rpc finished"),
+        Err(status) if code == Code::Internal || code == Code::Unavailable => {
+            tracing::error!(error = status.message(), "rpc failed")
+        }
+        Err(status) => tracing::warn!(error = status.message(), "rpc failed"),
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_observe() {
+        let span = rpc_span("Get");
+
+        assert_eq!(
+            observe(&span, async { Ok(Response::new(())) })
+                .await
+                .unwrap()
+                .get_ref(),
+            &()
+        );
+        assert_eq!(
+            observe::<(), _>(&span, async { Err(Status::not_found("not found")) })
+                .await
+                .unwrap_err()
+                .code(),
+            Code::NotFound
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::{bail, Error};
+use axum::http::{header, HeaderValue, Method};
+use std::str::FromStr;
+use tower_http::cors::{self, CorsLayer, Origin};
+
+// REST と gRPC-Web で共通の CORS 設定
+#[derive(Clone, Debug, PartialEq)]
+pub enum AllowedOrigins {
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+impl AllowedOrigins {
+    pub(crate) fn cors_layer(&self) -> CorsLayer {
+        let layer = CorsLayer::new()
+            .allow_methods(vec![Method::GET, Method::POST, Method::DELETE])
+            .allow_headers(vec![header::AUTHORIZATION, header::CONTENT_TYPE]);
+
+        match self {
+            AllowedOrigins::Any => layer.allow_origin(cors::Any),
+            AllowedOrigins::List(origins) => layer.allow_origin(Origin::list(origins.clone())),
+        }
+    }
+
+    pub(crate) fn grpc_web_config(&self) -> tonic_web::Config {
+        match self {
+            AllowedOrigins::Any => tonic_web::config().allow_all_origins(),
+            AllowedOrigins::List(origins) => tonic_web::config().allow_origins(origins.clone()),
+        }
+    }
+}
+
+// "*" もしくはカンマ区切りの origin を受け付ける
+impl FromStr for AllowedOrigins {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == "*" {
+            return Ok(AllowedOrigins::Any);
+        }
+
+        let origins = s
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(HeaderValue::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if origins.is_empty() {
+            bail!("allowed origins must not be empty")
+        }
+
+        Ok(AllowedOrigins::List(origins))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_origins_from_str() {
+        assert_eq!("*".parse::<AllowedOrigins>().unwrap(), AllowedOrigins::Any);
+        assert_eq!(
+            "http://localhost:3000, https://peta.example.com"
+                .parse::<AllowedOrigins>()
+                .unwrap(),
+            AllowedOrigins::List(vec![
+                HeaderValue::from_static("http://localhost:3000"),
+                HeaderValue::from_static("https://peta.example.com"),
+            ])
+        );
+        assert!("".parse::<AllowedOrigins>().is_err());
+        assert!(" , ".parse::<AllowedOrigins>().is_err());
+        assert!("http://local\nhost".parse::<AllowedOrigins>().is_err());
+    }
+}
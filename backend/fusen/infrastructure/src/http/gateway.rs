@@ -0,0 +1,466 @@
+use crate::http::error::ErrorResponse;
+use crate::http::json::{CreateFusenBody, FusenJson, ListFusenJson, ListFusenQuery};
+use crate::http::AllowedOrigins;
+use crate::jwt::Authenticator;
+use crate::shutdown::Shutdown;
+use async_trait::async_trait;
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::extract::{Extension, FromRequest, Path, Query, RequestParts};
+use axum::http::header;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use derive_new::new;
+use interface::controller::{Caller, Controller};
+use interface::peta_fusen_v1::{DeleteRequest, GetRequest, ListRequest, RenderFormat};
+use std::convert::TryFrom;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tonic::{Request, Status};
+use tower_http::trace::TraceLayer;
+
+// gRPC を話せないクライアント向けに Controller を JSON/HTTP で公開する
+#[derive(new)]
+pub struct Gateway<C>
+where
+    C: Controller + 'static,
+{
+    controller: Arc<C>,
+    authenticator: Authenticator,
+}
+
+impl<C> Gateway<C>
+where
+    C: Controller + 'static,
+{
+    pub async fn serve(
+        self,
+        addr: SocketAddr,
+        allowed_origins: AllowedOrigins,
+        shutdown: Shutdown,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        axum::Server::bind(&addr)
+            .serve(self.router(&allowed_origins).into_make_service())
+            .with_graceful_shutdown(async move { shutdown.stopping().await })
+            .await?;
+
+        Ok(())
+    }
+
+    fn router(self, allowed_origins: &AllowedOrigins) -> Router {
+        Router::new()
+            .route("/v1/fusens", get(list::<C>).post(create::<C>))
+            .route("/v1/fusens/:id", get(get_fusen::<C>).delete(delete::<C>))
+            .layer(Extension(self.controller))
+            .layer(Extension(self.authenticator))
+            .layer(allowed_origins.cors_layer())
+            .layer(TraceLayer::new_for_http())
+    }
+}
+
+// gRPC の interceptor と同じく Authorization ヘッダーから呼び出し元を特定する
+struct Authenticated(Caller);
+
+#[async_trait]
+impl<B: Send> FromRequest<B> for Authenticated {
+    type Rejection = ErrorResponse;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let Extension(authenticator) = Extension::<Authenticator>::from_request(request)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let authorization = request
+            .headers()
+            .and_then(|headers| headers.get(header::AUTHORIZATION))
+            .and_then(|value| value.to_str().ok());
+
+        Ok(Authenticated(authenticator.authenticate(authorization)?))
+    }
+}
+
+fn authorized<T>(message: T, caller: Caller) -> Request<T> {
+    let mut request = Request::new(message);
+    request.extensions_mut().insert(caller);
+    request
+}
+
+async fn create<C: Controller>(
+    Extension(controller): Extension<Arc<C>>,
+    Authenticated(caller): Authenticated,
+    body: Result<Json<CreateFusenBody>, JsonRejection>,
+) -> Result<(StatusCode, Json<FusenJson>), ErrorResponse> {
+    let Json(body) = body?;
+    let response = controller.create(authorized(body.into(), caller)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(FusenJson::try_from(response.into_inner().fusen)?),
+    ))
+}
+
+async fn list<C: Controller>(
+    Extension(controller): Extension<Arc<C>>,
+    Authenticated(caller): Authenticated,
+    query: Result<Query<ListFusenQuery>, QueryRejection>,
+) -> Result<Json<ListFusenJson>, ErrorResponse> {
+    let Query(query) = query?;
+    let response = controller
+        .list(authorized(ListRequest::try_from(query)?, caller))
+        .await?;
+
+    Ok(Json(response.into_inner().into()))
+}
+
+async fn get_fusen<C: Controller>(
+    Extension(controller): Extension<Arc<C>>,
+    Authenticated(caller): Authenticated,
+    Path(id): Path<String>,
+) -> Result<Json<FusenJson>, ErrorResponse> {
+    let response = controller
+        .get(authorized(
+            GetRequest {
+                id,
+                render_format: RenderFormat::Unspecified as i32,
+            },
+            caller,
+        ))
+        .await?;
+
+    Ok(Json(FusenJson::try_from(response.into_inner().fusen)?))
+}
+
+async fn delete<C: Controller>(
+    Extension(controller): Extension<Arc<C>>,
+    Authenticated(caller): Authenticated,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ErrorResponse> {
+    controller
+        .delete(authorized(DeleteRequest { id }, caller))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Prometheus が scrape する。認証は不要
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Method;
+    use interface::controller::{ExportStream, ImportStream, WatchStream};
+    use interface::peta_fusen_v1::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::{json, Value};
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tonic::Response;
+    use tower::ServiceExt;
+
+    const ID: &str = "01FJ4Q0AXMXKQ7B1GZ1Z0K0T8R";
+    const BOARD_ID: &str = "01FMB0ARDXKQ7B1GZ1Z0K0T8R0";
+
+    struct StubController;
+
+    fn fusen(title: &str, note: &str) -> Fusen {
+        Fusen {
+            id: ID.to_string(),
+            title: title.to_string(),
+            note: note.to_string(),
+            board_id: BOARD_ID.to_string(),
+            x: 10,
+            y: 20,
+            size: Some(Size {
+                width: 200,
+                height: 200,
+            }),
+            color: Color::Pink as i32,
+            z_index: 1,
+            create_time: Some(prost_types::Timestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+            update_time: Some(prost_types::Timestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+        }
+    }
+
+    #[async_trait]
+    impl Controller for StubController {
+        async fn create(
+            &self,
+            request: Request<CreateRequest>,
+        ) -> Result<Response<CreateResponse>, Status> {
+            let request = request.into_inner();
+            if request.title.is_empty() {
+                return Err(Status::invalid_argument("title is empty"));
+            }
+
+            Ok(Response::new(CreateResponse {
+                fusen: Some(fusen(&request.title, &request.note)),
+            }))
+        }
+
+        async fn list(
+            &self,
+            request: Request<ListRequest>,
+        ) -> Result<Response<ListResponse>, Status> {
+            if request.get_ref().board_id != BOARD_ID {
+                return Err(Status::permission_denied("not member"));
+            }
+
+            Ok(Response::new(ListResponse {
+                fusens: vec![fusen("title", "note")],
+                next_page_token: "next".to_string(),
+            }))
+        }
+
+        async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+            let caller = request.extensions().get::<Caller>().unwrap();
+            match request.get_ref().id.as_str() {
+                ID if caller.subject != "owner" => Err(Status::permission_denied("not owner")),
+                ID => Ok(Response::new(GetResponse {
+                    fusen: Some(fusen("title", "note")),
+                    rendered_note: "".to_string(),
+                })),
+                _ => Err(Status::not_found("not found entity")),
+            }
+        }
+
+        async fn update(
+            &self,
+            _request: Request<UpdateRequest>,
+        ) -> Result<Response<UpdateResponse>, Status> {
+            Err(Status::unimplemented("update"))
+        }
+
+        async fn delete(
+            &self,
+            _request: Request<DeleteRequest>,
+        ) -> Result<Response<DeleteResponse>, Status> {
+            Ok(Response::new(DeleteResponse {}))
+        }
+
+        async fn batch_create(
+            &self,
+            _request: Request<BatchCreateRequest>,
+        ) -> Result<Response<BatchCreateResponse>, Status> {
+            Err(Status::unimplemented("batch_create"))
+        }
+
+        async fn batch_get(
+            &self,
+            _request: Request<BatchGetRequest>,
+        ) -> Result<Response<BatchGetResponse>, Status> {
+            Err(Status::unimplemented("batch_get"))
+        }
+
+        async fn batch_delete(
+            &self,
+            _request: Request<BatchDeleteRequest>,
+        ) -> Result<Response<BatchDeleteResponse>, Status> {
+            Err(Status::unimplemented("batch_delete"))
+        }
+
+        async fn r#move(
+            &self,
+            _request: Request<MoveRequest>,
+        ) -> Result<Response<MoveResponse>, Status> {
+            Err(Status::unimplemented("move"))
+        }
+
+        async fn export(
+            &self,
+            _request: Request<ExportRequest>,
+        ) -> Result<Response<ExportStream>, Status> {
+            Err(Status::unimplemented("export"))
+        }
+
+        async fn import(
+            &self,
+            _request: Request<ImportStream>,
+        ) -> Result<Response<ImportResponse>, Status> {
+            Err(Status::unimplemented("import"))
+        }
+
+        async fn watch(
+            &self,
+            _request: Request<WatchRequest>,
+        ) -> Result<Response<WatchStream>, Status> {
+            Err(Status::unimplemented("watch"))
+        }
+    }
+
+    fn token(subject: &str) -> String {
+        #[derive(serde::Serialize)]
+        struct Claims<'a> {
+            sub: &'a str,
+            exp: u64,
+        }
+
+        let exp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60;
+        encode(
+            &Header::default(),
+            &Claims { sub: subject, exp },
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap()
+    }
+
+    async fn call(method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        call_as(Some("owner"), method, uri, body).await
+    }
+
+    async fn call_as(
+        subject: Option<&str>,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let router = Gateway::new(
+            Arc::new(StubController),
+            Authenticator::from_secret(b"secret"),
+        )
+        .router(&AllowedOrigins::Any);
+        let mut request = axum::http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(subject) = subject {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token(subject)));
+        }
+        let request = request
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn test_create() {
+        assert_eq!(
+            call(
+                Method::POST,
+                "/v1/fusens",
+                Some(json!({"board_id": BOARD_ID, "title": "title", "note": "note"}))
+            )
+            .await,
+            (
+                StatusCode::CREATED,
+                json!({
+                    "id": ID,
+                    "title": "title",
+                    "note": "note",
+                    "board_id": BOARD_ID,
+                    "x": 10,
+                    "y": 20,
+                    "width": 200,
+                    "height": 200,
+                    "color": "pink",
+                    "z_index": 1,
+                    "create_time": "2021-10-17T07:08:37Z",
+                    "update_time": "2021-10-17T07:08:37Z",
+                })
+            )
+        );
+        assert_eq!(
+            call(Method::POST, "/v1/fusens", Some(json!({"note": "note"}))).await,
+            (
+                StatusCode::BAD_REQUEST,
+                json!({"code": "INVALID_ARGUMENT", "message": "title is empty"})
+            )
+        );
+
+        let (status, body) = call(Method::POST, "/v1/fusens", Some(json!([1]))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_ARGUMENT");
+    }
+
+    #[tokio::test]
+    async fn test_list() {
+        let (status, body) = call(
+            Method::GET,
+            &format!("/v1/fusens?board_id={}&page_size=1&order=desc", BOARD_ID),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["fusens"][0]["id"], ID);
+        assert_eq!(body["next_page_token"], "next");
+
+        let (status, body) = call(
+            Method::GET,
+            &format!("/v1/fusens?board_id={}&order=newest", BOARD_ID),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_ARGUMENT");
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        let (status, body) = call(Method::GET, &format!("/v1/fusens/{}", ID), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["id"], ID);
+
+        assert_eq!(
+            call(Method::GET, "/v1/fusens/01FJ4Q0AXMXKQ7B1GZ1Z0K0T8S", None).await,
+            (
+                StatusCode::NOT_FOUND,
+                json!({"code": "NOT_FOUND", "message": "not found entity"})
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authentication() {
+        let (status, body) = call_as(None, Method::GET, "/v1/fusens", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "UNAUTHENTICATED");
+
+        assert_eq!(
+            call_as(
+                Some("another"),
+                Method::GET,
+                &format!("/v1/fusens/{}", ID),
+                None
+            )
+            .await,
+            (
+                StatusCode::FORBIDDEN,
+                json!({"code": "PERMISSION_DENIED", "message": "not owner"})
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        assert_eq!(
+            call(Method::DELETE, &format!("/v1/fusens/{}", ID), None).await,
+            (StatusCode::NO_CONTENT, Value::Null)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metrics_not_exposed() {
+        // /metrics は MetricsExporter で別のアドレスから返す
+        assert_eq!(
+            call(Method::GET, "/metrics", None).await.0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
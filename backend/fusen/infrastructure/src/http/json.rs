@@ -0,0 +1,202 @@
+use chrono::{SecondsFormat, TimeZone, Utc};
+use interface::peta_fusen_v1::{Color, CreateRequest, Fusen, ListRequest, ListResponse, SortOrder};
+use prost_types::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use tonic::Status;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreateFusenBody {
+    #[serde(default)]
+    board_id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    note: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct ListFusenQuery {
+    board_id: Option<String>,
+    page_size: Option<i32>,
+    page_token: Option<String>,
+    order: Option<String>,
+    keyword: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct FusenJson {
+    id: String,
+    title: String,
+    note: String,
+    board_id: String,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    color: &'static str,
+    z_index: i32,
+    create_time: Option<String>,
+    update_time: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct ListFusenJson {
+    fusens: Vec<FusenJson>,
+    next_page_token: String,
+}
+
+impl From<CreateFusenBody> for CreateRequest {
+    fn from(body: CreateFusenBody) -> Self {
+        CreateRequest {
+            title: body.title,
+            note: body.note,
+            board_id: body.board_id,
+        }
+    }
+}
+
+impl TryFrom<ListFusenQuery> for ListRequest {
+    type Error = Status;
+
+    fn try_from(query: ListFusenQuery) -> Result<Self, Self::Error> {
+        let order = match query.order.as_deref() {
+            None => SortOrder::Unspecified,
+            Some("asc") => SortOrder::Asc,
+            Some("desc") => SortOrder::Desc,
+            Some(order) => {
+                return Err(Status::invalid_argument(format!("invalid order {}", order)))
+            }
+        };
+
+        Ok(ListRequest {
+            page_size: query.page_size.unwrap_or_default(),
+            page_token: query.page_token.unwrap_or_default(),
+            order: order as i32,
+            keyword: query.keyword.unwrap_or_default(),
+            board_id: query.board_id.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<Option<Fusen>> for FusenJson {
+    type Error = Status;
+
+    fn try_from(fusen: Option<Fusen>) -> Result<Self, Self::Error> {
+        fusen
+            .map(FusenJson::from)
+            .ok_or_else(|| Status::internal("fusen is missing"))
+    }
+}
+
+impl From<Fusen> for FusenJson {
+    fn from(fusen: Fusen) -> Self {
+        FusenJson {
+            id: fusen.id,
+            title: fusen.title,
+            note: fusen.note,
+            board_id: fusen.board_id,
+            x: fusen.x,
+            y: fusen.y,
+            width: fusen
+                .size
+                .as_ref()
+                .map(|size| size.width)
+                .unwrap_or_default(),
+            height: fusen
+                .size
+                .as_ref()
+                .map(|size| size.height)
+                .unwrap_or_default(),
+            color: color_name(fusen.color),
+            z_index: fusen.z_index,
+            create_time: fusen.create_time.and_then(to_rfc3339),
+            update_time: fusen.update_time.and_then(to_rfc3339),
+        }
+    }
+}
+
+impl From<ListResponse> for ListFusenJson {
+    fn from(response: ListResponse) -> Self {
+        ListFusenJson {
+            fusens: response.fusens.into_iter().map(FusenJson::from).collect(),
+            next_page_token: response.next_page_token,
+        }
+    }
+}
+
+fn color_name(color: i32) -> &'static str {
+    match Color::from_i32(color) {
+        Some(Color::Yellow) => "yellow",
+        Some(Color::Pink) => "pink",
+        Some(Color::Blue) => "blue",
+        Some(Color::Green) => "green",
+        Some(Color::Orange) => "orange",
+        Some(Color::Purple) => "purple",
+        _ => "unspecified",
+    }
+}
+
+fn to_rfc3339(timestamp: Timestamp) -> Option<String> {
+    Utc.timestamp_opt(timestamp.seconds, timestamp.nanos as u32)
+        .single()
+        .map(|timestamp| timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_request_try_from() {
+        let request = ListRequest::try_from(ListFusenQuery {
+            board_id: Some("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string()),
+            page_size: Some(10),
+            order: Some("desc".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(request.board_id, "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string());
+        assert_eq!(request.page_size, 10);
+        assert_eq!(request.order, SortOrder::Desc as i32);
+
+        let request = ListRequest::try_from(ListFusenQuery::default()).unwrap();
+        assert_eq!(request.order, SortOrder::Unspecified as i32);
+
+        assert!(ListRequest::try_from(ListFusenQuery {
+            order: Some("newest".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_fusen_json_from() {
+        let fusen = FusenJson::from(Fusen {
+            id: "01FJ4Q0AXMXKQ7B1GZ1Z0K0T8R".to_string(),
+            title: "title".to_string(),
+            note: "note".to_string(),
+            create_time: Some(Timestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+            update_time: None,
+            board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            x: -10,
+            y: 20,
+            size: Some(interface::peta_fusen_v1::Size {
+                width: 120,
+                height: 80,
+            }),
+            color: Color::Blue as i32,
+            z_index: 3,
+        });
+
+        assert_eq!(fusen.create_time.as_deref(), Some("2021-10-17T07:08:37Z"));
+        assert_eq!(fusen.update_time, None);
+        assert_eq!((fusen.x, fusen.y), (-10, 20));
+        assert_eq!((fusen.width, fusen.height), (120, 80));
+        assert_eq!(fusen.color, "blue");
+        assert_eq!(fusen.z_index, 3);
+    }
+}
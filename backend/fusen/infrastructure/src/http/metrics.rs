@@ -0,0 +1,81 @@
+use crate::http::error::ErrorResponse;
+use crate::metrics;
+use crate::shutdown::Shutdown;
+use axum::http::header::{self, HeaderName};
+use axum::response::Headers;
+use axum::routing::get;
+use axum::Router;
+use std::net::SocketAddr;
+use tonic::Status;
+use tower_http::trace::TraceLayer;
+
+// Prometheus 向けに /metrics を返す。
+// 認証も CORS もかけないので、Gateway とは別のアドレスで内部にだけ公開する
+pub struct MetricsExporter;
+
+impl MetricsExporter {
+    pub async fn serve(
+        self,
+        addr: SocketAddr,
+        shutdown: Shutdown,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        axum::Server::bind(&addr)
+            .serve(self.router().into_make_service())
+            .with_graceful_shutdown(async move { shutdown.stopping().await })
+            .await?;
+
+        Ok(())
+    }
+
+    fn router(self) -> Router {
+        Router::new()
+            .route("/metrics", get(export_metrics))
+            .layer(TraceLayer::new_for_http())
+    }
+}
+
+async fn export_metrics(
+) -> Result<(Headers<[(HeaderName, &'static str); 1]>, String), ErrorResponse> {
+    let body = metrics::encode().map_err(|e| Status::internal(e.to_string()))?;
+
+    Ok((
+        Headers([(header::CONTENT_TYPE, metrics::CONTENT_TYPE)]),
+        body,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let router = MetricsExporter.router();
+        drop(metrics::query_timer("test", "exporter"));
+
+        // 認証なしで取得でき、CORS のヘッダーは付けない
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri("/metrics")
+                    .header(header::ORIGIN, "https://example.com")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            metrics::CONTENT_TYPE
+        );
+        assert!(!response
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN));
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("fusen_db_query_duration_seconds"));
+    }
+}
@@ -0,0 +1,9 @@
+mod cors;
+mod error;
+mod gateway;
+mod json;
+mod metrics;
+
+pub use self::cors::AllowedOrigins;
+pub use self::gateway::Gateway;
+pub use self::metrics::MetricsExporter;
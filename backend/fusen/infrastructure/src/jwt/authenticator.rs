@@ -0,0 +1,273 @@
+use anyhow::{bail, Error};
+use interface::controller::Caller;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tonic::{Request, Status};
+
+const HMAC_ALGORITHMS: [Algorithm; 3] = [Algorithm::HS256, Algorithm::HS384, Algorithm::HS512];
+const RSA_ALGORITHMS: [Algorithm; 6] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+];
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+}
+
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+#[derive(Deserialize)]
+struct Jwk {
+    kid: String,
+    kty: String,
+    n: Option<String>,
+    e: Option<String>,
+    k: Option<String>,
+}
+
+struct Key {
+    key: DecodingKey<'static>,
+    algorithms: Vec<Algorithm>,
+}
+
+enum Keys {
+    Secret(Key),
+    Jwks(HashMap<String, Key>),
+}
+
+// Bearer トークン (JWT) を検証して呼び出し元を特定する
+#[derive(Clone)]
+pub struct Authenticator {
+    keys: Arc<Keys>,
+}
+
+impl Authenticator {
+    pub fn from_secret(secret: &[u8]) -> Self {
+        Self {
+            keys: Arc::new(Keys::Secret(Key {
+                key: DecodingKey::from_secret(secret).into_static(),
+                algorithms: HMAC_ALGORITHMS.to_vec(),
+            })),
+        }
+    }
+
+    pub fn from_jwks(jwks: &str) -> Result<Self, Error> {
+        let jwks = serde_json::from_str::<JwkSet>(jwks)?;
+
+        let mut keys = HashMap::new();
+        for jwk in jwks.keys {
+            let key = match (jwk.kty.as_str(), jwk.n, jwk.e, jwk.k) {
+                ("RSA", Some(n), Some(e), _) => Key {
+                    key: DecodingKey::from_rsa_components(&n, &e).into_static(),
+                    algorithms: RSA_ALGORITHMS.to_vec(),
+                },
+                ("oct", _, _, Some(k)) => Key {
+                    key: DecodingKey::from_base64_secret(&k)?.into_static(),
+                    algorithms: HMAC_ALGORITHMS.to_vec(),
+                },
+                (kty, _, _, _) => bail!("unsupported jwk {} ({})", jwk.kid, kty),
+            };
+            keys.insert(jwk.kid, key);
+        }
+        if keys.is_empty() {
+            bail!("jwks has no keys")
+        }
+
+        Ok(Self {
+            keys: Arc::new(Keys::Jwks(keys)),
+        })
+    }
+
+    pub fn from_jwks_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_jwks(&fs::read_to_string(path)?)
+    }
+
+    // authorization には Authorization ヘッダーの値を渡す
+    pub fn authenticate(&self, authorization: Option<&str>) -> Result<Caller, Status> {
+        let token = authorization
+            .and_then(|value| value.split_once(' '))
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+            .map(|(_, token)| token.trim())
+            .ok_or_else(|| Status::unauthenticated("missing bearer token"))?;
+
+        let key = match &*self.keys {
+            Keys::Secret(key) => key,
+            Keys::Jwks(keys) => decode_header(token)
+                .ok()
+                .and_then(|header| header.kid)
+                .and_then(|kid| keys.get(&kid))
+                .ok_or_else(|| Status::unauthenticated("unknown signing key"))?,
+        };
+        let validation = Validation {
+            algorithms: key.algorithms.clone(),
+            ..Validation::default()
+        };
+
+        let claims = decode::<Claims>(token, &key.key, &validation)
+            .map_err(|e| Status::unauthenticated(format!("invalid token: {}", e)))?
+            .claims;
+
+        Ok(Caller::new(claims.sub))
+    }
+
+    // gRPC の interceptor として呼び出し元を Request の extensions に格納する
+    pub(crate) fn intercept(&self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let caller = self.authenticate(
+            request
+                .metadata()
+                .get("authorization")
+                .and_then(|value| value.to_str().ok()),
+        )?;
+        request.extensions_mut().insert(caller);
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde::Serialize;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tonic::Code;
+
+    const RSA_MODULUS: &str = "mkJEVNbSy8eIwV0HlFJBbTAS7iPC-Fw8-nK-gCC54EECUomL2ppOELpJzRjOFrgjNicHfFATf7E_8q2p58nJ7KKMVjiB68wNnHh18S6skpWzH8ny643YtOPUKOU09B1nNj1bpptGRp7X0JbOMKsoUOMhOivJIbzBbDJpZX8SeHG6c-VeJocSjiiJjFomzE3OQRK7y3n_3MiYI4nWgeggSg0W1FzBP6_Ce9nj1Trr1auKEavPtOtUIAO068mVcF5YEGalOQp8l4uOafx-qSA-VlBaCc3AU0cqIAh-feqYJcsNmUhgPA_UdofntKBuKnxgr2Qi-hpENv35xX-rJTpk4Q";
+
+    #[derive(Serialize)]
+    struct TestClaims<'a> {
+        sub: &'a str,
+        exp: u64,
+    }
+
+    fn token(header: Header, secret: &[u8], exp: u64) -> String {
+        encode(
+            &header,
+            &TestClaims { sub: "owner", exp },
+            &EncodingKey::from_secret(secret),
+        )
+        .unwrap()
+    }
+
+    fn expires() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60
+    }
+
+    #[test]
+    fn test_authenticate_with_secret() {
+        let sut = Authenticator::from_secret(b"secret");
+
+        let valid = token(Header::default(), b"secret", expires());
+        assert_eq!(
+            sut.authenticate(Some(&format!("Bearer {}", valid)))
+                .unwrap(),
+            Caller::new("owner".to_string())
+        );
+        assert!(sut.authenticate(Some(&format!("bearer {}", valid))).is_ok());
+
+        let invalids = vec![
+            None,
+            Some(valid.clone()),
+            Some(format!("Basic {}", valid)),
+            Some(format!(
+                "Bearer {}",
+                token(Header::default(), b"another", expires())
+            )),
+            Some(format!("Bearer {}", token(Header::default(), b"secret", 1))),
+        ];
+        for authorization in invalids {
+            assert_eq!(
+                sut.authenticate(authorization.as_deref())
+                    .unwrap_err()
+                    .code(),
+                Code::Unauthenticated
+            );
+        }
+    }
+
+    #[test]
+    fn test_authenticate_with_jwks() {
+        // "secret" を base64url にしたもの
+        let sut = Authenticator::from_jwks(
+            r#"{"keys": [{"kid": "test", "kty": "oct", "k": "c2VjcmV0"}]}"#,
+        )
+        .unwrap();
+
+        let header = Header {
+            kid: Some("test".to_string()),
+            ..Header::default()
+        };
+        assert_eq!(
+            sut.authenticate(Some(&format!(
+                "Bearer {}",
+                token(header.clone(), b"secret", expires())
+            )))
+            .unwrap(),
+            Caller::new("owner".to_string())
+        );
+        assert!(sut
+            .authenticate(Some(&format!(
+                "Bearer {}",
+                token(Header::default(), b"secret", expires())
+            )))
+            .is_err());
+        assert!(sut
+            .authenticate(Some(&format!(
+                "Bearer {}",
+                token(header, b"another", expires())
+            )))
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_jwks() {
+        let rsa = format!(
+            r#"{{"keys": [{{"kid": "rsa", "kty": "RSA", "alg": "RS256", "n": "{}", "e": "AQAB"}}]}}"#,
+            RSA_MODULUS
+        );
+        assert!(Authenticator::from_jwks(&rsa).is_ok());
+
+        assert!(Authenticator::from_jwks(r#"{"keys": []}"#).is_err());
+        assert!(Authenticator::from_jwks(
+            r#"{"keys": [{"kid": "ec", "kty": "EC", "crv": "P-256", "x": "", "y": ""}]}"#,
+        )
+        .is_err());
+        assert!(Authenticator::from_jwks("not json").is_err());
+    }
+
+    #[test]
+    fn test_intercept() {
+        let sut = Authenticator::from_secret(b"secret");
+
+        let mut request = Request::new(());
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", token(Header::default(), b"secret", expires()))
+                .parse()
+                .unwrap(),
+        );
+        let request = sut.intercept(request).unwrap();
+        assert_eq!(
+            request.extensions().get::<Caller>(),
+            Some(&Caller::new("owner".to_string()))
+        );
+
+        assert!(sut.intercept(Request::new(())).is_err());
+    }
+}
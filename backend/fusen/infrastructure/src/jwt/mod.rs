@@ -0,0 +1,3 @@
+mod authenticator;
+
+pub use self::authenticator::*;
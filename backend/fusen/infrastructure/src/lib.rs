@@ -3,7 +3,22 @@ extern crate diesel;
 #[macro_use]
 extern crate diesel_migrations;
 
+pub mod blob;
+pub mod chrono;
+pub mod config;
+#[cfg(test)]
+mod conformance;
+pub mod event;
 pub mod grpc;
+pub mod http;
+pub mod jwt;
+pub mod logger;
+pub mod markdown;
 pub mod memory;
+pub mod metrics;
+pub mod outbox;
 pub mod postgres;
+pub mod shutdown;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod ulid;
@@ -0,0 +1,15 @@
+use anyhow::{anyhow, Error, Result};
+use tracing_subscriber::EnvFilter;
+
+// RUST_LOG と同じ書式 (例: "info", "infrastructure=debug,tower_http=info")
+pub const LOG_ENV: &str = "FUSEN_LOG";
+
+pub fn init(directives: &str) -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_env_filter(EnvFilter::try_new(directives)?)
+        .try_init()
+        .map_err(|e| anyhow!(e))
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod logger;
+
+pub use self::logger::*;
@@ -0,0 +1,127 @@
+use domain::repository::NoteRenderer as Repository;
+use domain::vo::{FusenNote, RenderFormat};
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+
+// plain text で返す抜粋の最大文字数
+const EXCERPT_LENGTH: usize = 140;
+
+#[derive(Default)]
+pub struct NoteRenderer {}
+
+impl NoteRenderer {
+    fn to_html(&self, markdown: &str) -> String {
+        let mut unsafe_html = String::new();
+        html::push_html(&mut unsafe_html, Parser::new_ext(markdown, options()));
+
+        // note に直接書かれた HTML も通るので、script やイベントハンドラ属性はここで取り除く
+        ammonia::clean(&unsafe_html)
+    }
+
+    fn to_plain_text(&self, markdown: &str) -> String {
+        let mut text = String::new();
+        for event in Parser::new_ext(markdown, options()) {
+            match event {
+                Event::Text(s) | Event::Code(s) => text.push_str(&s),
+                Event::SoftBreak | Event::HardBreak => text.push(' '),
+                Event::End(tag) if is_block(&tag) => text.push(' '),
+                _ => {}
+            }
+        }
+
+        // 改行や連続する空白はひとつにまとめる
+        excerpt(&text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Repository for NoteRenderer {
+    fn render(&self, note: &FusenNote, format: RenderFormat) -> String {
+        match format {
+            RenderFormat::Html => self.to_html(&note.to_string()),
+            RenderFormat::PlainText => self.to_plain_text(&note.to_string()),
+        }
+    }
+}
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+fn is_block(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Paragraph
+            | Tag::Heading(_)
+            | Tag::BlockQuote
+            | Tag::CodeBlock(_)
+            | Tag::Item
+            | Tag::TableCell
+    )
+}
+
+fn excerpt(text: &str) -> String {
+    match text.char_indices().nth(EXCERPT_LENGTH) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(s: &str) -> FusenNote {
+        s.parse::<FusenNote>().unwrap()
+    }
+
+    #[test]
+    fn test_render_html() {
+        let sut = NoteRenderer::default();
+
+        assert_eq!(
+            sut.render(
+                &note("## はじめに\n\nこの記事は **2日目** の記事です。"),
+                RenderFormat::Html
+            ),
+            "<h2>はじめに</h2>\n<p>この記事は <strong>2日目</strong> の記事です。</p>\n".to_string()
+        );
+
+        let html = sut.render(
+            &note("[link](https://example.com)\n\n<script>alert(1)</script>\n\n<img src=\"a.png\" onerror=\"alert(1)\">"),
+            RenderFormat::Html,
+        );
+        assert!(html.contains("href=\"https://example.com\""));
+        assert!(html.contains("<img src=\"a.png\">"));
+        assert!(!html.contains("script"));
+        assert!(!html.contains("alert"));
+
+        let html = sut.render(&note("[click](javascript:alert(1))"), RenderFormat::Html);
+        assert!(!html.contains("javascript"));
+    }
+
+    #[test]
+    fn test_render_plain_text() {
+        let sut = NoteRenderer::default();
+
+        assert_eq!(
+            sut.render(
+                &note("## はじめに\n\nこの記事は、 [Advent Calendar](https://example.com) の**2日目**の記事です。\n\n- `a`\n- b"),
+                RenderFormat::PlainText
+            ),
+            "はじめに この記事は、 Advent Calendar の2日目の記事です。 a b".to_string()
+        );
+        assert_eq!(
+            sut.render(&note("<script>alert(1)</script>"), RenderFormat::PlainText),
+            "".to_string()
+        );
+
+        let long = "あ".repeat(EXCERPT_LENGTH + 1);
+        assert_eq!(
+            sut.render(&note(&long), RenderFormat::PlainText),
+            format!("{}…", "あ".repeat(EXCERPT_LENGTH))
+        );
+        assert_eq!(
+            sut.render(&note(&long[3..]), RenderFormat::PlainText),
+            "あ".repeat(EXCERPT_LENGTH)
+        );
+    }
+}
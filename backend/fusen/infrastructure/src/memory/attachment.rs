@@ -0,0 +1,148 @@
+use crate::memory::FusenRepository;
+use async_trait::async_trait;
+use domain::entity::{Attachment, Fusen};
+use domain::error::DomainError;
+use domain::repository::AttachmentRepository as Repository;
+use domain::vo::Id;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Clone)]
+pub struct AttachmentRepository {
+    // id 順に並べて返すので BTreeMap に保存する
+    store: Arc<Mutex<BTreeMap<String, Attachment>>>,
+    // 添付先の fusen が削除されたかどうかを調べる
+    fusens: FusenRepository,
+}
+
+impl AttachmentRepository {
+    pub fn new(fusens: FusenRepository) -> Self {
+        Self {
+            store: Arc::new(Mutex::new(BTreeMap::new())),
+            fusens,
+        }
+    }
+}
+
+#[async_trait]
+impl Repository for AttachmentRepository {
+    async fn add(&self, attachment: Attachment) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        let key = attachment.id().to_string();
+        if m.contains_key(&key) {
+            return Err(DomainError::Conflict(format!(
+                "attachment {} already exists",
+                key
+            )));
+        }
+        m.insert(key, attachment);
+        Ok(())
+    }
+
+    async fn get(&self, id: Id<Attachment>) -> Result<Attachment, DomainError> {
+        let m = self.store.lock().unwrap();
+        match m.get(&id.to_string()) {
+            Some(attachment) => Ok(attachment.clone()),
+            None => Err(DomainError::NotFound(format!(
+                "attachment {} not found",
+                id.to_string()
+            ))),
+        }
+    }
+
+    async fn list(&self, fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError> {
+        let m = self.store.lock().unwrap();
+        Ok(m.values()
+            .filter(|attachment| attachment.fusen_id() == &fusen_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn orphaned(&self, limit: usize) -> Result<Vec<Attachment>, DomainError> {
+        let m = self.store.lock().unwrap();
+        Ok(m.values()
+            .filter(|attachment| !self.fusens.contains(attachment.fusen_id()))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn remove(&self, ids: Vec<Id<Attachment>>) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        for id in ids {
+            m.remove(&id.to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{AttachmentBuilder, Board, FusenBuilder};
+    use domain::repository::CreateRepository;
+    use domain::vo::{Checksum, ContentType, FileName, FusenNote, FusenTitle, OwnerId, Timestamp};
+
+    fn new_attachment(id: &str, fusen_id: &str) -> Attachment {
+        AttachmentBuilder::default()
+            .id(id.parse::<Id<Attachment>>().unwrap())
+            .fusen_id(fusen_id.parse::<Id<Fusen>>().unwrap())
+            .file_name("screenshot.png".parse::<FileName>().unwrap())
+            .content_type("image/png".parse::<ContentType>().unwrap())
+            .size(3u64)
+            .checksum(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                    .parse::<Checksum>()
+                    .unwrap(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_attachment_repository() {
+        let fusens = FusenRepository::default();
+        fusens
+            .create(
+                FusenBuilder::default()
+                    .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let sut = AttachmentRepository::new(fusens);
+        let second = new_attachment("01FNATTACHXKQ7B1GZ1Z0K0T8S", "01F8MECHZX3TBDSZ7XRADM79XE");
+        let first = new_attachment("01FNATTACHXKQ7B1GZ1Z0K0T8R", "01F8MECHZX3TBDSZ7XRADM79XE");
+        let orphaned = new_attachment("01FNATTACHXKQ7B1GZ1Z0K0T8T", "01F8MECHZX3TBDSZ7XRADM79XF");
+        sut.add(second.clone()).await.unwrap();
+        sut.add(first.clone()).await.unwrap();
+        sut.add(orphaned.clone()).await.unwrap();
+
+        assert!(matches!(
+            sut.add(first.clone()).await,
+            Err(DomainError::Conflict(_))
+        ));
+        assert_eq!(sut.get(first.id().clone()).await.unwrap(), first);
+        assert_eq!(
+            sut.list(first.fusen_id().clone()).await.unwrap(),
+            vec![first, second]
+        );
+        assert_eq!(sut.orphaned(10).await.unwrap(), vec![orphaned.clone()]);
+
+        sut.remove(vec![orphaned.id().clone()]).await.unwrap();
+        assert!(sut.orphaned(10).await.unwrap().is_empty());
+        assert!(matches!(
+            sut.get(orphaned.id().clone()).await,
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
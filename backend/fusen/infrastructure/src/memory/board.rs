@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use domain::entity::Board;
+use domain::error::DomainError;
+use domain::repository::{CreateRepository, DeleteRepository, GetRepository, ListRepository};
+use domain::repository::{ListQuery, SortOrder, UpdateRepository};
+use domain::vo::Id;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Clone, Default)]
+pub struct BoardRepository {
+    store: Arc<Mutex<HashMap<Id<Board>, Board>>>,
+}
+
+#[async_trait]
+impl CreateRepository<Board> for BoardRepository {
+    async fn create(&self, aggregate: Board) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        m.insert(aggregate.id().clone(), aggregate);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GetRepository<Board> for BoardRepository {
+    async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+        let m = self.store.lock().unwrap();
+        match m.get(&id) {
+            Some(aggregate) => Ok(aggregate.clone()),
+            None => Err(DomainError::NotFound("not found entity".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl ListRepository<Board> for BoardRepository {
+    async fn list(&self, query: ListQuery<Board>) -> Result<Vec<Board>, DomainError> {
+        let m = self.store.lock().unwrap();
+        let cursor = query.cursor.map(|cursor| cursor.to_string());
+
+        let mut aggregates = m
+            .values()
+            .filter(|aggregate| match &query.owner_id {
+                Some(owner_id) => aggregate.is_member(owner_id),
+                None => true,
+            })
+            .filter(|aggregate| match (&cursor, query.order) {
+                (Some(cursor), SortOrder::Asc) => aggregate.id().to_string() > *cursor,
+                (Some(cursor), SortOrder::Desc) => aggregate.id().to_string() < *cursor,
+                (None, _) => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        aggregates.sort_by_key(|aggregate| aggregate.id().to_string());
+        if query.order == SortOrder::Desc {
+            aggregates.reverse();
+        }
+        aggregates.truncate(query.limit);
+
+        Ok(aggregates)
+    }
+}
+
+#[async_trait]
+impl UpdateRepository<Board> for BoardRepository {
+    async fn update(&self, aggregate: Board) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        match m.get_mut(aggregate.id()) {
+            Some(current) => {
+                *current = aggregate;
+                Ok(())
+            }
+            None => Err(DomainError::NotFound("not found entity".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteRepository<Board> for BoardRepository {
+    async fn delete(&self, aggregate: Board) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        match m.remove(aggregate.id()) {
+            Some(_) => Ok(()),
+            None => Err(DomainError::NotFound("not found entity".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::BoardBuilder;
+    use domain::vo::{BoardName, OwnerId, Timestamp};
+
+    fn new_board(id: &str, owner_id: &str, member_ids: &[&str]) -> Board {
+        BoardBuilder::default()
+            .id(id.parse::<Id<Board>>().unwrap())
+            .owner_id(owner_id.parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(
+                member_ids
+                    .iter()
+                    .map(|m| m.parse::<OwnerId>().unwrap())
+                    .collect::<Vec<_>>(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_board_repository() {
+        let sut = BoardRepository::default();
+        let board = new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "owner", &[]);
+        sut.create(board.clone()).await.unwrap();
+        sut.create(new_board(
+            "01FMB0ARDXKQ7B1GZ1Z0K0T8R1",
+            "another",
+            &["owner"],
+        ))
+        .await
+        .unwrap();
+        sut.create(new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R2", "another", &[]))
+            .await
+            .unwrap();
+
+        let mut renamed = sut.get(board.id().clone()).await.unwrap();
+        renamed.set_name("renamed".parse::<BoardName>().unwrap());
+        sut.update(renamed).await.unwrap();
+        assert_eq!(
+            sut.get(board.id().clone())
+                .await
+                .unwrap()
+                .name()
+                .to_string(),
+            "renamed".to_string()
+        );
+
+        let boards = sut
+            .list(ListQuery::new(
+                None,
+                10,
+                SortOrder::Asc,
+                None,
+                Some("owner".parse::<OwnerId>().unwrap()),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            boards
+                .iter()
+                .map(|b| b.id().to_string())
+                .collect::<Vec<_>>(),
+            vec!["01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "01FMB0ARDXKQ7B1GZ1Z0K0T8R1"]
+        );
+
+        assert!(sut.delete(board.clone()).await.is_ok());
+        assert!(matches!(
+            sut.get(board.id().clone()).await,
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
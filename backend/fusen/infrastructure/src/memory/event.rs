@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use domain::error::DomainError;
+use domain::event::{FusenDomainEvent, FusenEvent};
+use domain::repository::{EventStream, PruneRepository, SubscribeRepository};
+use domain::vo::{EventId, Timestamp};
+use futures::stream::{self, StreamExt};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+
+// 再開用に保持するイベント数
+const HISTORY_SIZE: usize = 1024;
+
+#[derive(Default)]
+struct History {
+    // 消したイベントの ID を使い回さないように、最後に振った ID を別に持つ
+    last: u64,
+    events: VecDeque<(EventId, FusenEvent)>,
+}
+
+impl History {
+    // 再開できる最も古いイベントの ID
+    fn oldest(&self) -> u64 {
+        self.events
+            .front()
+            .map(|(id, _)| id.value())
+            .unwrap_or(self.last + 1)
+    }
+}
+
+#[derive(Clone)]
+pub struct FusenEventRepository {
+    history: Arc<Mutex<History>>,
+    sender: broadcast::Sender<(EventId, FusenEvent)>,
+}
+
+impl Default for FusenEventRepository {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(HISTORY_SIZE);
+
+        Self {
+            history: Arc::new(Mutex::new(History::default())),
+            sender,
+        }
+    }
+}
+
+impl FusenEventRepository {
+    // 集約を保存した FusenRepository から呼び出す
+    pub(crate) fn append(&self, events: &[FusenDomainEvent]) {
+        let mut history = self.history.lock().unwrap();
+        for event in events.iter().map(FusenEvent::from) {
+            history.last += 1;
+            let id = EventId::new(history.last);
+
+            history.events.push_back((id, event.clone()));
+            if history.events.len() > HISTORY_SIZE {
+                history.events.pop_front();
+            }
+            // 購読者がいない場合はエラーになるが無視してよい
+            let _ = self.sender.send((id, event));
+        }
+    }
+}
+
+#[async_trait]
+impl SubscribeRepository<FusenEvent> for FusenEventRepository {
+    async fn subscribe(
+        &self,
+        after: Option<EventId>,
+    ) -> Result<EventStream<FusenEvent>, DomainError> {
+        // history のロック中に購読を開始して、取りこぼしと重複が起きないようにする
+        let history = self.history.lock().unwrap();
+        let receiver = self.sender.subscribe();
+
+        let backlog = match after {
+            Some(after) => {
+                if after.value() + 1 < history.oldest() {
+                    return Err(DomainError::InvalidArgument(format!(
+                        "event {} is no longer available",
+                        after.to_string()
+                    )));
+                }
+                history
+                    .events
+                    .iter()
+                    .filter(|(id, _)| *id > after)
+                    .cloned()
+                    .map(Ok)
+                    .collect::<Vec<_>>()
+            }
+            None => Vec::new(),
+        };
+
+        let live = BroadcastStream::new(receiver).map(|event| match event {
+            Ok(event) => Ok(event),
+            Err(BroadcastStreamRecvError::Lagged(n)) => Err(DomainError::Unavailable(format!(
+                "subscriber lagged behind by {} events",
+                n
+            ))),
+        });
+
+        Ok(stream::iter(backlog).chain(live).boxed())
+    }
+}
+
+#[async_trait]
+impl PruneRepository for FusenEventRepository {
+    async fn prune(&self, before: Timestamp, limit: usize) -> Result<usize, DomainError> {
+        let mut history = self.history.lock().unwrap();
+        let mut count = 0;
+        while count < limit {
+            match history.events.front() {
+                Some((_, event)) if event.occurred_at() < &before => {
+                    history.events.pop_front();
+                    count += 1;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::event::FusenEventKind;
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId, Timestamp};
+
+    fn new_event(kind: FusenEventKind) -> FusenDomainEvent {
+        let mut fusen = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let now = "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap();
+        match kind {
+            FusenEventKind::Created => fusen.record_created(now),
+            FusenEventKind::Updated => fusen.record_updated(now),
+            FusenEventKind::Deleted => fusen.record_deleted(now),
+        }
+        fusen.take_events().remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_event_repository() {
+        let sut = FusenEventRepository::default();
+        let created = new_event(FusenEventKind::Created);
+        let updated = new_event(FusenEventKind::Updated);
+
+        sut.append(std::slice::from_ref(&created));
+        let mut live = sut.subscribe(None).await.unwrap();
+        let mut resumed = sut.subscribe(Some(EventId::new(1))).await.unwrap();
+        let mut replayed = sut.subscribe(Some(EventId::new(0))).await.unwrap();
+
+        sut.append(std::slice::from_ref(&updated));
+
+        assert_eq!(
+            live.next().await.unwrap().unwrap(),
+            (EventId::new(2), FusenEvent::from(&updated))
+        );
+        assert_eq!(
+            resumed.next().await.unwrap().unwrap(),
+            (EventId::new(2), FusenEvent::from(&updated))
+        );
+        assert_eq!(
+            replayed.next().await.unwrap().unwrap(),
+            (EventId::new(1), FusenEvent::from(&created))
+        );
+        assert_eq!(
+            replayed.next().await.unwrap().unwrap(),
+            (EventId::new(2), FusenEvent::from(&updated))
+        );
+    }
+}
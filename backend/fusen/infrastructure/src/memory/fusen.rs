@@ -1,62 +1,310 @@
-use anyhow::bail;
-use anyhow::{Error, Result};
+use crate::memory::{FusenEventRepository, OutboxRepository, OutboxState};
+use async_trait::async_trait;
 use domain::entity::Fusen;
-use domain::repository::{CreateRepository, DeleteRepository, GetRepository};
+use domain::error::DomainError;
+use domain::event::FusenDomainEvent;
+use domain::repository::{BatchMode, CreateAllRepository, DeleteAllRepository, GetAllRepository};
+use domain::repository::{CreateRepository, DeleteRepository, GetRepository, ListRepository};
+use domain::repository::{ListQuery, SortOrder};
+use domain::repository::{MoveAllRepository, UpdateRepository};
 use domain::vo::Id;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+#[derive(Clone)]
 pub struct FusenRepository {
     store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+    // 保存に成功した集約のイベントだけを書き込む
+    outbox: OutboxRepository,
+    events: FusenEventRepository,
+    // UnitOfWork の実行中は Watch に流すイベントを成功するまで溜めておく
+    pending: Arc<Mutex<Option<Vec<FusenDomainEvent>>>>,
+}
+
+// UnitOfWork が失敗した場合に戻す内容
+pub(crate) struct Snapshot {
+    store: HashMap<Id<Fusen>, Fusen>,
+    outbox: OutboxState,
 }
 
 impl FusenRepository {
     #[allow(dead_code)]
     pub fn default() -> Self {
+        Self::with_outbox(OutboxRepository::default())
+    }
+
+    pub fn with_outbox(outbox: OutboxRepository) -> Self {
+        Self::new(outbox, FusenEventRepository::default())
+    }
+
+    pub fn new(outbox: OutboxRepository, events: FusenEventRepository) -> Self {
         Self {
             store: Arc::new(Mutex::new(HashMap::new())),
+            outbox,
+            events,
+            pending: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // UnitOfWork の開始時に呼び出す
+    pub(crate) fn snapshot(&self) -> Snapshot {
+        *self.pending.lock().unwrap() = Some(Vec::new());
+        Snapshot {
+            store: self.store.lock().unwrap().clone(),
+            outbox: self.outbox.snapshot(),
         }
     }
+
+    pub(crate) fn restore(&self, snapshot: Snapshot) {
+        *self.store.lock().unwrap() = snapshot.store;
+        self.outbox.restore(snapshot.outbox);
+        *self.pending.lock().unwrap() = None;
+    }
+
+    // UnitOfWork が成功した場合に、溜めておいたイベントを Watch に流す
+    pub(crate) fn commit(&self) {
+        if let Some(events) = self.pending.lock().unwrap().take() {
+            self.events.append(&events);
+        }
+    }
+
+    // store のロック中に呼び出し、保存した順にイベントを書き出す
+    fn append(&self, events: Vec<FusenDomainEvent>) -> Result<(), DomainError> {
+        self.outbox.append(events.clone())?;
+        match self.pending.lock().unwrap().as_mut() {
+            Some(pending) => pending.extend(events),
+            None => self.events.append(&events),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn contains(&self, id: &Id<Fusen>) -> bool {
+        self.store.lock().unwrap().contains_key(id)
+    }
 }
 
+#[async_trait]
 impl CreateRepository<Fusen> for FusenRepository {
-    fn create(&self, aggregate: Fusen) -> Result<(), Error> {
+    async fn create(&self, mut aggregate: Fusen) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
         let mut m = self.store.lock().unwrap();
-        m.insert(aggregate.id().clone(), aggregate.clone());
-        Ok(())
+        insert(&mut m, aggregate)?;
+        self.append(events)
+    }
+}
+
+// AllOrNothing の場合は失敗した時点で作成前の状態に戻す
+#[async_trait]
+impl CreateAllRepository<Fusen> for FusenRepository {
+    async fn create_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let mut m = self.store.lock().unwrap();
+        let snapshot = m.clone();
+        let mut results = Vec::with_capacity(aggregates.len());
+        let mut events = Vec::new();
+        for mut aggregate in aggregates {
+            let aggregate_events = aggregate.take_events();
+            match (insert(&mut m, aggregate), mode) {
+                (Err(e), BatchMode::AllOrNothing) => {
+                    *m = snapshot;
+                    return Err(e);
+                }
+                (Ok(()), _) => {
+                    events.extend(aggregate_events);
+                    results.push(Ok(()));
+                }
+                (result, _) => results.push(result),
+            }
+        }
+        self.append(events)?;
+        Ok(results)
+    }
+}
+
+// 永続化するバックエンドと同じく、既存の id は上書きせずに Conflict を返す
+fn insert(m: &mut HashMap<Id<Fusen>, Fusen>, aggregate: Fusen) -> Result<(), DomainError> {
+    if m.contains_key(aggregate.id()) {
+        return Err(DomainError::Conflict(format!(
+            "fusen {} already exists",
+            aggregate.id().to_string()
+        )));
     }
+    m.insert(aggregate.id().clone(), aggregate);
+    Ok(())
 }
 
+#[async_trait]
 impl GetRepository<Fusen> for FusenRepository {
-    fn get(&self, id: Id<Fusen>) -> Result<Fusen, Error> {
+    async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
         let m = self.store.lock().unwrap();
         match m.get(&id) {
             Some(aggregate) => Ok(aggregate.clone()),
-            None => bail!("not found entity"),
+            None => Err(DomainError::NotFound("not found entity".to_string())),
         }
     }
 }
 
+#[async_trait]
+impl GetAllRepository<Fusen> for FusenRepository {
+    async fn get_all(&self, ids: Vec<Id<Fusen>>) -> Result<Vec<Fusen>, DomainError> {
+        let m = self.store.lock().unwrap();
+        Ok(ids.iter().filter_map(|id| m.get(id).cloned()).collect())
+    }
+}
+
+#[async_trait]
+impl ListRepository<Fusen> for FusenRepository {
+    async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+        let m = self.store.lock().unwrap();
+        let keyword = query.keyword.map(|keyword| keyword.to_lowercase());
+        let cursor = query.cursor.map(|cursor| cursor.to_string());
+
+        let mut aggregates = m
+            .values()
+            .filter(|aggregate| match &query.owner_id {
+                Some(owner_id) => aggregate.owner_id() == owner_id,
+                None => true,
+            })
+            .filter(|aggregate| match &query.board_id {
+                Some(board_id) => aggregate.board_id() == board_id,
+                None => true,
+            })
+            .filter(|aggregate| match &keyword {
+                Some(keyword) => {
+                    aggregate
+                        .title()
+                        .to_string()
+                        .to_lowercase()
+                        .contains(keyword)
+                        || aggregate
+                            .note()
+                            .to_string()
+                            .to_lowercase()
+                            .contains(keyword)
+                }
+                None => true,
+            })
+            .filter(|aggregate| match (&cursor, query.order) {
+                (Some(cursor), SortOrder::Asc) => aggregate.id().to_string() > *cursor,
+                (Some(cursor), SortOrder::Desc) => aggregate.id().to_string() < *cursor,
+                (None, _) => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        aggregates.sort_by_key(|aggregate| aggregate.id().to_string());
+        if query.order == SortOrder::Desc {
+            aggregates.reverse();
+        }
+        aggregates.truncate(query.limit);
+
+        Ok(aggregates)
+    }
+}
+
+#[async_trait]
+impl UpdateRepository<Fusen> for FusenRepository {
+    async fn update(&self, mut aggregate: Fusen) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
+        let mut m = self.store.lock().unwrap();
+        match m.get_mut(aggregate.id()) {
+            Some(current) => {
+                *current = aggregate;
+                self.append(events)
+            }
+            None => Err(DomainError::NotFound("not found entity".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl MoveAllRepository<Fusen> for FusenRepository {
+    async fn move_all(&self, aggregates: Vec<Fusen>) -> Result<(), DomainError> {
+        let mut m = self.store.lock().unwrap();
+        if aggregates
+            .iter()
+            .any(|aggregate| !m.contains_key(aggregate.id()))
+        {
+            return Err(DomainError::NotFound("not found entity".to_string()));
+        }
+        // 永続化するバックエンドと同じく、配置に関する項目だけを書き換える
+        let mut events = Vec::new();
+        for mut aggregate in aggregates {
+            events.extend(aggregate.take_events());
+            if let Some(current) = m.get_mut(aggregate.id()) {
+                current.set_position(*aggregate.position());
+                current.set_size(*aggregate.size());
+                current.set_z_index(*aggregate.z_index());
+                current.set_update_at(*aggregate.update_at());
+            }
+        }
+        self.append(events)
+    }
+}
+
+#[async_trait]
 impl DeleteRepository<Fusen> for FusenRepository {
-    fn delete(&self, aggregate: Fusen) -> Result<(), Error> {
+    async fn delete(&self, mut aggregate: Fusen) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
         let mut m = self.store.lock().unwrap();
         match m.remove(aggregate.id()) {
-            Some(_) => Ok(()),
-            None => bail!("not found entity"),
+            Some(_) => self.append(events),
+            None => Err(DomainError::NotFound("not found entity".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteAllRepository<Fusen> for FusenRepository {
+    async fn delete_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let mut m = self.store.lock().unwrap();
+        if mode == BatchMode::AllOrNothing
+            && aggregates
+                .iter()
+                .any(|aggregate| !m.contains_key(aggregate.id()))
+        {
+            return Err(DomainError::NotFound("not found entity".to_string()));
         }
+        let mut events = Vec::new();
+        let results = aggregates
+            .into_iter()
+            .map(|mut aggregate| match m.remove(aggregate.id()) {
+                Some(_) => {
+                    events.extend(aggregate.take_events());
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            })
+            .collect();
+        self.append(events)?;
+        Ok(results)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::conformance::{fusen_repository_conformance, BOARD_ID};
     use domain::aggregate::AggregateRoot;
+    use domain::entity::Board;
     use domain::entity::Entity;
     use domain::entity::FusenBuilder;
+    use domain::repository::OutboxRepository as _;
     use domain::vo::FusenNote;
     use domain::vo::FusenTitle;
     use domain::vo::Id;
+    use domain::vo::OwnerId;
+    use domain::vo::Position;
+    use domain::vo::Timestamp;
+    use std::time::Duration;
 
     #[derive(Default, Clone, Debug, PartialEq, Eq)]
     struct DummyEntity {
@@ -65,52 +313,385 @@ mod tests {
     impl AggregateRoot for DummyEntity {}
     impl Entity for DummyEntity {}
 
-    #[test]
-    fn test_create_repository_for_entity() {
+    #[tokio::test]
+    async fn test_create_repository_for_entity() {
         let entity = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
         let sut = FusenRepository::default();
 
-        assert!(sut.create(entity).is_ok());
+        assert!(sut.create(entity).await.is_ok());
     }
 
-    #[test]
-    fn test_get_repository_for_entity() {
+    #[tokio::test]
+    async fn test_get_repository_for_entity() {
         let entity = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
         let sut = FusenRepository::default();
-        sut.create(entity).unwrap();
+        sut.create(entity).await.unwrap();
 
         assert!(sut
             .get("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .await
             .is_ok());
     }
 
-    #[test]
-    fn test_delete_repository_for_entity() {
+    #[tokio::test]
+    async fn test_list_repository_for_entity() {
+        let sut = FusenRepository::default();
+        for (id, title) in [
+            ("01F8MECHZX3TBDSZ7XRADM79XE", "apple"),
+            ("01F8MECHZX3TBDSZ7XRADM79XF", "Banana"),
+            ("01F8MECHZX3TBDSZ7XRADM79XG", "cherry"),
+        ] {
+            sut.create(
+                FusenBuilder::default()
+                    .id(id.parse::<Id<Fusen>>().unwrap())
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title(title.parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let ids = |fusens: Vec<Fusen>| {
+            fusens
+                .iter()
+                .map(|f| f.id().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(None, 2, SortOrder::Asc, None, None, None))
+                .await
+                .unwrap()),
+            vec!["01F8MECHZX3TBDSZ7XRADM79XE", "01F8MECHZX3TBDSZ7XRADM79XF"]
+        );
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(
+                    Some("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap()),
+                    2,
+                    SortOrder::Asc,
+                    None,
+                    None,
+                    None
+                ))
+                .await
+                .unwrap()),
+            vec!["01F8MECHZX3TBDSZ7XRADM79XG"]
+        );
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(
+                    Some("01F8MECHZX3TBDSZ7XRADM79XG".parse::<Id<Fusen>>().unwrap()),
+                    2,
+                    SortOrder::Desc,
+                    None,
+                    None,
+                    None
+                ))
+                .await
+                .unwrap()),
+            vec!["01F8MECHZX3TBDSZ7XRADM79XF", "01F8MECHZX3TBDSZ7XRADM79XE"]
+        );
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(
+                    None,
+                    10,
+                    SortOrder::Asc,
+                    Some("BAN".to_string()),
+                    None,
+                    None
+                ))
+                .await
+                .unwrap()),
+            vec!["01F8MECHZX3TBDSZ7XRADM79XF"]
+        );
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(
+                    None,
+                    10,
+                    SortOrder::Asc,
+                    None,
+                    Some("another".parse::<OwnerId>().unwrap()),
+                    None
+                ))
+                .await
+                .unwrap()),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            ids(sut
+                .list(ListQuery::new(
+                    None,
+                    10,
+                    SortOrder::Asc,
+                    None,
+                    None,
+                    Some("01FMB0ARDXKQ7B1GZ1Z0K0T8R1".parse::<Id<Board>>().unwrap())
+                ))
+                .await
+                .unwrap()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_repository_for_entity() {
+        let entity = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        let sut = FusenRepository::default();
+        assert!(sut.update(entity.clone()).await.is_err());
+
+        sut.create(entity.clone()).await.unwrap();
+        let mut target = entity;
+        target.set_note("updated".parse::<FusenNote>().unwrap());
+
+        assert!(sut.update(target).await.is_ok());
+        assert_eq!(
+            sut.get("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                .await
+                .unwrap()
+                .note()
+                .to_string(),
+            "updated".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_move_all_repository_for_entity() {
         let entity = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let missing = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("title".parse::<FusenTitle>().unwrap())
             .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
         let sut = FusenRepository::default();
-        sut.create(entity).unwrap();
+        sut.create(entity.clone()).await.unwrap();
+
+        let mut target = entity.clone();
+        target.set_position(Position::new(10, 20).unwrap());
+        // 読んだ後に別の更新で書き換えられた項目は元に戻さない
+        let mut renamed = entity.clone();
+        renamed.set_title("renamed".parse::<FusenTitle>().unwrap());
+        sut.update(renamed).await.unwrap();
+        assert!(sut.move_all(vec![target.clone()]).await.is_ok());
+        assert_eq!(
+            sut.get(entity.id().clone())
+                .await
+                .unwrap()
+                .title()
+                .to_string(),
+            "renamed".to_string()
+        );
+
+        // 存在しない fusen が含まれる場合は何も更新しない
+        target.set_position(Position::new(30, 40).unwrap());
+        assert!(sut.move_all(vec![target, missing]).await.is_err());
+        assert_eq!(
+            sut.get(entity.id().clone()).await.unwrap().position(),
+            &Position::new(10, 20).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_repository_for_entity() {
+        let entity = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        let sut = FusenRepository::default();
+        sut.create(entity).await.unwrap();
         let target = sut
             .get("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .await
             .unwrap();
 
-        assert!(sut.delete(target).is_ok());
+        assert!(sut.delete(target).await.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_create_all_repository_for_entity() {
+        let new_fusen = |id: &str| {
+            FusenBuilder::default()
+                .id(id.parse::<Id<Fusen>>().unwrap())
+                .owner_id("owner".parse::<OwnerId>().unwrap())
+                .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                .title("title".parse::<FusenTitle>().unwrap())
+                .note("note".parse::<FusenNote>().unwrap())
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap()
+        };
+        let first = new_fusen("01F8MECHZX3TBDSZ7XRADM79XE");
+        let second = new_fusen("01F8MECHZX3TBDSZ7XRADM79XF");
+
+        let sut = FusenRepository::default();
+        sut.create(first.clone()).await.unwrap();
+
+        // 重複する fusen が含まれる場合は何も作成しない
+        assert!(matches!(
+            sut.create_all(vec![second.clone(), first.clone()], BatchMode::AllOrNothing)
+                .await,
+            Err(DomainError::Conflict(_))
+        ));
+        assert_eq!(
+            sut.get_all(vec![first.id().clone(), second.id().clone()])
+                .await
+                .unwrap(),
+            vec![first.clone()]
+        );
+
+        let results = sut
+            .create_all(vec![second, first], BatchMode::PartialSuccess)
+            .await
+            .unwrap();
+        assert!(matches!(
+            &results[..],
+            [Ok(()), Err(DomainError::Conflict(_))]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_repository_for_entity() {
+        let entity = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let missing = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        let sut = FusenRepository::default();
+        sut.create_all(vec![entity.clone()], BatchMode::AllOrNothing)
+            .await
+            .unwrap();
+
+        // 存在しない fusen が含まれる場合は何も削除しない
+        assert!(sut
+            .delete_all(
+                vec![entity.clone(), missing.clone()],
+                BatchMode::AllOrNothing
+            )
+            .await
+            .is_err());
+        assert_eq!(
+            sut.get_all(vec![entity.id().clone(), missing.id().clone()])
+                .await
+                .unwrap(),
+            vec![entity.clone()]
+        );
+
+        let results = sut
+            .delete_all(vec![entity, missing], BatchMode::PartialSuccess)
+            .await
+            .unwrap();
+        assert!(results[0].is_ok() && results[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_outbox_for_entity() {
+        let mut entity = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        entity.record_created("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap());
+
+        let outbox = OutboxRepository::default();
+        let sut = FusenRepository::with_outbox(outbox.clone());
+        sut.create(entity.clone()).await.unwrap();
+        // 保存に失敗した場合はイベントを書き込まない
+        assert!(sut.create(entity).await.is_err());
+
+        let claimed = outbox.claim(10, Duration::from_secs(30)).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].event_type(), "FusenCreated");
+    }
+
+    async fn setup() -> (FusenRepository, Id<Board>) {
+        (
+            FusenRepository::default(),
+            BOARD_ID.parse::<Id<Board>>().unwrap(),
+        )
+    }
+
+    fusen_repository_conformance!(setup());
 }
@@ -1,3 +1,13 @@
+mod attachment;
+mod board;
+mod event;
 mod fusen;
+mod outbox;
+mod unit_of_work;
 
+pub use self::attachment::*;
+pub use self::board::*;
+pub use self::event::*;
 pub use self::fusen::*;
+pub use self::outbox::*;
+pub use self::unit_of_work::*;
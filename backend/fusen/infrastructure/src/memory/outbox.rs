@@ -0,0 +1,152 @@
+use crate::outbox::encode;
+use async_trait::async_trait;
+use domain::error::DomainError;
+use domain::event::FusenDomainEvent;
+use domain::repository::OutboxMessage;
+use domain::repository::OutboxRepository as Repository;
+use domain::vo::{EventId, Timestamp};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Default)]
+pub struct OutboxRepository {
+    state: Arc<Mutex<OutboxState>>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct OutboxState {
+    last_id: u64,
+    entries: Vec<Entry>,
+}
+
+#[derive(Clone)]
+struct Entry {
+    message: OutboxMessage,
+    locked_until: Option<Instant>,
+}
+
+impl OutboxRepository {
+    // 集約を保存した FusenRepository から呼び出す
+    pub(crate) fn append(&self, events: Vec<FusenDomainEvent>) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        for event in events {
+            let encoded = encode(&event)?;
+            state.last_id += 1;
+            let message = OutboxMessage::new(
+                EventId::new(state.last_id),
+                encoded.event_type.to_string(),
+                encoded.aggregate_id,
+                encoded.payload,
+                Timestamp::new(encoded.occurred_at),
+            );
+            state.entries.push(Entry {
+                message,
+                locked_until: None,
+            });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn snapshot(&self) -> OutboxState {
+        self.state.lock().unwrap().clone()
+    }
+
+    pub(crate) fn restore(&self, snapshot: OutboxState) {
+        *self.state.lock().unwrap() = snapshot;
+    }
+}
+
+#[async_trait]
+impl Repository for OutboxRepository {
+    async fn claim(
+        &self,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<OutboxMessage>, DomainError> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        Ok(state
+            .entries
+            .iter_mut()
+            .filter(|entry| match entry.locked_until {
+                Some(locked_until) => locked_until < now,
+                None => true,
+            })
+            .take(limit)
+            .map(|entry| {
+                entry.locked_until = Some(now + lease);
+                entry.message.clone()
+            })
+            .collect())
+    }
+
+    async fn acknowledge(&self, ids: Vec<EventId>) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        state
+            .entries
+            .retain(|entry| !ids.contains(entry.message.id()));
+        Ok(())
+    }
+
+    async fn release(&self, ids: Vec<EventId>) -> Result<(), DomainError> {
+        let mut state = self.state.lock().unwrap();
+        for entry in state.entries.iter_mut() {
+            if ids.contains(entry.message.id()) {
+                entry.locked_until = None;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId};
+
+    fn events() -> Vec<FusenDomainEvent> {
+        let mut fusen = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        fusen.record_created(now);
+        fusen.record_deleted(now);
+        fusen.take_events()
+    }
+
+    #[tokio::test]
+    async fn test_outbox_repository() {
+        let sut = OutboxRepository::default();
+        sut.append(events()).unwrap();
+        let lease = Duration::from_secs(30);
+
+        let claimed = sut.claim(10, lease).await.unwrap();
+        assert_eq!(
+            claimed
+                .iter()
+                .map(|message| message.event_type().as_str())
+                .collect::<Vec<_>>(),
+            vec!["FusenCreated", "FusenDeleted"]
+        );
+        assert!(sut.claim(10, lease).await.unwrap().is_empty());
+
+        let ids = claimed.iter().map(|m| *m.id()).collect::<Vec<_>>();
+        sut.release(ids.clone()).await.unwrap();
+        assert_eq!(sut.claim(1, lease).await.unwrap(), claimed[..1]);
+
+        sut.acknowledge(ids[..1].to_vec()).await.unwrap();
+        assert_eq!(sut.claim(10, Duration::ZERO).await.unwrap(), claimed[1..]);
+
+        // lease が切れたものは再び取り出せる
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert_eq!(sut.claim(10, lease).await.unwrap(), claimed[1..]);
+    }
+}
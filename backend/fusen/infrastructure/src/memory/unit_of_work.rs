@@ -0,0 +1,136 @@
+use crate::memory::{FusenRepository, Snapshot};
+use async_trait::async_trait;
+use domain::error::DomainError;
+use domain::repository::UnitOfWork as Repository;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+// work の実行中は他の UnitOfWork を待たせ、失敗した場合は開始前の内容に戻す。
+// UnitOfWork を経由しない書き込みとは排他しない
+#[derive(Clone)]
+pub struct UnitOfWork {
+    fusen_repository: FusenRepository,
+    lock: Arc<Mutex<()>>,
+}
+
+impl UnitOfWork {
+    #[allow(dead_code)]
+    pub fn new(fusen_repository: FusenRepository) -> Self {
+        Self {
+            fusen_repository,
+            lock: Arc::new(Mutex::new(())),
+        }
+    }
+}
+
+// work が失敗した場合に加えて、完了せずに破棄された場合も開始前の内容に戻す
+struct Rollback<'a> {
+    fusen_repository: &'a FusenRepository,
+    snapshot: Option<Snapshot>,
+}
+
+impl Drop for Rollback<'_> {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            self.fusen_repository.restore(snapshot);
+        }
+    }
+}
+
+#[async_trait]
+impl Repository for UnitOfWork {
+    type Repositories = FusenRepository;
+
+    async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+            + Send
+            + 'static,
+    {
+        let _guard = self.lock.lock().await;
+        let mut rollback = Rollback {
+            fusen_repository: &self.fusen_repository,
+            snapshot: Some(self.fusen_repository.snapshot()),
+        };
+
+        let result = work(&self.fusen_repository).await;
+        if result.is_ok() {
+            rollback.snapshot = None;
+            self.fusen_repository.commit();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{FusenEventRepository, OutboxRepository};
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::event::FusenEventKind;
+    use domain::repository::{CreateRepository, DeleteRepository, GetRepository};
+    use domain::repository::{SubscribeRepository, UpdateRepository};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId, Timestamp};
+    use futures::{FutureExt, StreamExt};
+
+    #[tokio::test]
+    async fn test_unit_of_work() {
+        let entity = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+
+        let events = FusenEventRepository::default();
+        let fusen_repository = FusenRepository::new(OutboxRepository::default(), events.clone());
+        let sut = UnitOfWork::new(fusen_repository.clone());
+        let mut watched = events.subscribe(None).await.unwrap();
+
+        let mut target = entity.clone();
+        target.record_created(*entity.create_at());
+        assert!(sut
+            .run(move |repositories| { async move { repositories.create(target).await }.boxed() })
+            .await
+            .is_ok());
+
+        // 削除した後に失敗した場合は削除を取り消し、Watch にも流さない
+        let mut target = entity.clone();
+        target.record_deleted(*entity.create_at());
+        assert!(sut
+            .run(move |repositories| {
+                async move {
+                    repositories.delete(target.clone()).await?;
+                    repositories.delete(target).await
+                }
+                .boxed()
+            })
+            .await
+            .is_err());
+        assert_eq!(
+            fusen_repository.get(entity.id().clone()).await.unwrap(),
+            entity
+        );
+
+        let mut target = entity.clone();
+        target.record_updated(*entity.create_at());
+        fusen_repository.update(target).await.unwrap();
+        let kinds = [
+            watched.next().await.unwrap().unwrap(),
+            watched.next().await.unwrap().unwrap(),
+        ]
+        .iter()
+        .map(|(_, event)| *event.kind())
+        .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![FusenEventKind::Created, FusenEventKind::Updated]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::metrics::{GRPC_SERVER_HANDLED, GRPC_SERVER_HANDLING_SECONDS};
+use axum::http::{HeaderMap, Request, Response};
+use futures::future::BoxFuture;
+use prost::{DecodeError, Message};
+use prost_types::FileDescriptorSet;
+use std::collections::HashSet;
+use std::error::Error;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tonic::{Code, Status};
+use tower::{Layer, Service};
+
+const UNKNOWN: &str = "unknown";
+
+// gRPC のパス (/package.Service/Method) ごとに件数とレイテンシを記録する。
+// 任意のパスでラベルが増え続けないよう、登録したサービスとメソッド以外は unknown にまとめる
+#[derive(Clone, Default)]
+pub struct GrpcMetricsLayer {
+    methods: Arc<HashSet<(String, String)>>,
+}
+
+impl GrpcMetricsLayer {
+    pub fn register_encoded_file_descriptor_set(self, encoded: &[u8]) -> Result<Self, DecodeError> {
+        let set = FileDescriptorSet::decode(encoded)?;
+        let mut layer = self;
+        for file in set.file {
+            for service in file.service {
+                let name = match file.package.as_deref() {
+                    Some(package) if !package.is_empty() => {
+                        format!("{}.{}", package, service.name())
+                    }
+                    _ => service.name().to_string(),
+                };
+                for method in service.method {
+                    layer = layer.register_method(&name, method.name());
+                }
+            }
+        }
+
+        Ok(layer)
+    }
+
+    pub fn register_method(mut self, service: &str, method: &str) -> Self {
+        Arc::make_mut(&mut self.methods).insert((service.to_string(), method.to_string()));
+        self
+    }
+
+    fn labels(&self, path: &str) -> (String, String) {
+        let (service, method) = match path.trim_start_matches('/').split_once('/') {
+            Some((service, method)) => (service, method),
+            None => return (UNKNOWN.to_string(), UNKNOWN.to_string()),
+        };
+        if self
+            .methods
+            .contains(&(service.to_string(), method.to_string()))
+        {
+            return (service.to_string(), method.to_string());
+        }
+        if self.methods.iter().any(|(known, _)| known == service) {
+            return (service.to_string(), UNKNOWN.to_string());
+        }
+
+        (UNKNOWN.to_string(), UNKNOWN.to_string())
+    }
+}
+
+impl<S> Layer<S> for GrpcMetricsLayer {
+    type Service = GrpcMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcMetrics {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GrpcMetrics<S> {
+    inner: S,
+    layer: GrpcMetricsLayer,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for GrpcMetrics<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    S::Error: AsRef<dyn Error + Send + Sync> + Send,
+    ResBody: Send,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let (service, method) = self.layer.labels(request.uri().path());
+        let started = Instant::now();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let result = future.await;
+
+            // interceptor で拒否した場合などは Status がエラーとして返り、外側でレスポンスになる
+            let code = match &result {
+                Ok(response) => code(response.headers()),
+                Err(e) => e
+                    .as_ref()
+                    .downcast_ref::<Status>()
+                    .map_or(Code::Unknown, Status::code),
+            };
+            let code = format!("{:?}", code);
+            let labels = [service.as_str(), method.as_str(), code.as_str()];
+            GRPC_SERVER_HANDLED.with_label_values(&labels).inc();
+            GRPC_SERVER_HANDLING_SECONDS
+                .with_label_values(&labels)
+                .observe(started.elapsed().as_secs_f64());
+
+            result
+        })
+    }
+}
+
+// エラーはヘッダーだけのレスポンスで返るので、grpc-status がなければ成功とみなす
+fn code(headers: &HeaderMap) -> Code {
+    headers
+        .get("grpc-status")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i32>().ok())
+        .map_or(Code::Ok, Code::from_i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    type BoxError = Box<dyn Error + Send + Sync>;
+
+    async fn call(path: &str, result: Result<Option<&str>, Status>) {
+        let mut result = Some(result.map(|status| status.map(str::to_string)));
+        let service = GrpcMetricsLayer::default()
+            .register_method("peta.fusen.v1.TestService", "Get")
+            .layer(tower::service_fn(move |_: Request<()>| {
+                let result = match result.take().unwrap() {
+                    Ok(status) => {
+                        let mut response = Response::builder();
+                        if let Some(status) = status {
+                            response = response.header("grpc-status", status);
+                        }
+                        Ok(response.body(()).unwrap())
+                    }
+                    Err(status) => Err(Box::new(status) as BoxError),
+                };
+                async move { result }
+            }));
+
+        let _ = service
+            .oneshot(Request::builder().uri(path).body(()).unwrap())
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_grpc_metrics() {
+        let labels = ["peta.fusen.v1.TestService", "Get", "Ok"];
+        let handled = GRPC_SERVER_HANDLED.with_label_values(&labels).get();
+        call("/peta.fusen.v1.TestService/Get", Ok(None)).await;
+        assert_eq!(
+            GRPC_SERVER_HANDLED.with_label_values(&labels).get(),
+            handled + 1
+        );
+
+        let labels = ["peta.fusen.v1.TestService", "Get", "NotFound"];
+        let handled = GRPC_SERVER_HANDLED.with_label_values(&labels).get();
+        call("/peta.fusen.v1.TestService/Get", Ok(Some("5"))).await;
+        assert_eq!(
+            GRPC_SERVER_HANDLED.with_label_values(&labels).get(),
+            handled + 1
+        );
+        assert_eq!(
+            GRPC_SERVER_HANDLING_SECONDS
+                .with_label_values(&labels)
+                .get_sample_count(),
+            handled + 1
+        );
+
+        let labels = ["peta.fusen.v1.TestService", "Get", "Unauthenticated"];
+        let handled = GRPC_SERVER_HANDLED.with_label_values(&labels).get();
+        call(
+            "/peta.fusen.v1.TestService/Get",
+            Err(Status::unauthenticated("missing bearer token")),
+        )
+        .await;
+        assert_eq!(
+            GRPC_SERVER_HANDLED.with_label_values(&labels).get(),
+            handled + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grpc_metrics_unknown() {
+        let labels = ["unknown", "unknown", "Ok"];
+        let handled = GRPC_SERVER_HANDLED.with_label_values(&labels).get();
+        call("/peta.fusen.v1.Unregistered/Get", Ok(None)).await;
+        call("/", Ok(None)).await;
+        assert_eq!(
+            GRPC_SERVER_HANDLED.with_label_values(&labels).get(),
+            handled + 2
+        );
+
+        let labels = ["peta.fusen.v1.TestService", "unknown", "Ok"];
+        let handled = GRPC_SERVER_HANDLED.with_label_values(&labels).get();
+        call("/peta.fusen.v1.TestService/Unregistered", Ok(None)).await;
+        assert_eq!(
+            GRPC_SERVER_HANDLED.with_label_values(&labels).get(),
+            handled + 1
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let sut = GrpcMetricsLayer::default()
+            .register_encoded_file_descriptor_set(interface::peta_fusen_v1::FILE_DESCRIPTOR_SET)
+            .unwrap();
+        assert_eq!(
+            sut.labels("/peta.fusen.v1.FusenService/Get"),
+            ("peta.fusen.v1.FusenService".to_string(), "Get".to_string())
+        );
+        assert_eq!(
+            sut.labels("/peta.fusen.v1.FusenService/Nope"),
+            (
+                "peta.fusen.v1.FusenService".to_string(),
+                "unknown".to_string()
+            )
+        );
+        assert_eq!(
+            sut.labels("/evil.Service/Get"),
+            ("unknown".to_string(), "unknown".to_string())
+        );
+        assert_eq!(
+            sut.labels("/"),
+            ("unknown".to_string(), "unknown".to_string())
+        );
+    }
+}
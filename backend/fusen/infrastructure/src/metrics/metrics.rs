@@ -0,0 +1,101 @@
+use anyhow::{Error, Result};
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram, register_histogram_vec, register_int_counter, register_int_counter_vec,
+};
+use prometheus::{
+    Encoder, Histogram, HistogramTimer, HistogramVec, IntCounter, IntCounterVec, TextEncoder,
+};
+use std::time::Duration;
+
+// go-grpc-prometheus と同じ名前とラベルにそろえる
+lazy_static! {
+    pub(crate) static ref GRPC_SERVER_HANDLED: IntCounterVec = register_int_counter_vec!(
+        "grpc_server_handled_total",
+        "Total number of RPCs completed on the server, regardless of success or failure.",
+        &["grpc_service", "grpc_method", "grpc_code"]
+    )
+    .unwrap();
+    pub(crate) static ref GRPC_SERVER_HANDLING_SECONDS: HistogramVec = register_histogram_vec!(
+        "grpc_server_handling_seconds",
+        "Histogram of response latency (seconds) of gRPC until the response headers are sent.",
+        &["grpc_service", "grpc_method", "grpc_code"]
+    )
+    .unwrap();
+    static ref DB_POOL_WAIT_SECONDS: Histogram = register_histogram!(
+        "fusen_db_pool_wait_seconds",
+        "Histogram of time (seconds) spent waiting for a database connection."
+    )
+    .unwrap();
+    static ref DB_QUERY_SECONDS: HistogramVec = register_histogram_vec!(
+        "fusen_db_query_duration_seconds",
+        "Histogram of latency (seconds) of repository methods.",
+        &["repository", "method"]
+    )
+    .unwrap();
+    static ref OUTBOX_DELIVERED: IntCounter = register_int_counter!(
+        "fusen_outbox_delivered_total",
+        "Total number of outbox messages delivered to the sink."
+    )
+    .unwrap();
+    static ref OUTBOX_DELIVERY_FAILURES: IntCounter = register_int_counter!(
+        "fusen_outbox_delivery_failures_total",
+        "Total number of failed attempts to deliver an outbox message."
+    )
+    .unwrap();
+}
+
+// drop されるまでの時間を記録する
+pub(crate) fn query_timer(repository: &str, method: &str) -> HistogramTimer {
+    DB_QUERY_SECONDS
+        .with_label_values(&[repository, method])
+        .start_timer()
+}
+
+#[cfg(test)]
+pub(crate) fn query_count(repository: &str, method: &str) -> u64 {
+    DB_QUERY_SECONDS
+        .with_label_values(&[repository, method])
+        .get_sample_count()
+}
+
+pub(crate) fn observe_pool_wait(duration: Duration) {
+    DB_POOL_WAIT_SECONDS.observe(duration.as_secs_f64());
+}
+
+pub(crate) fn record_outbox_delivery(delivered: bool) {
+    if delivered {
+        OUTBOX_DELIVERED.inc();
+    } else {
+        OUTBOX_DELIVERY_FAILURES.inc();
+    }
+}
+
+// Prometheus のテキスト形式
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+pub fn encode() -> Result<String, Error> {
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&prometheus::gather(), &mut buffer)?;
+
+    Ok(String::from_utf8(buffer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        drop(query_timer("test", "encode"));
+        observe_pool_wait(Duration::from_millis(10));
+        record_outbox_delivery(false);
+
+        let text = encode().unwrap();
+        assert!(text.contains(
+            "fusen_db_query_duration_seconds_count{method=\"encode\",repository=\"test\"} 1"
+        ));
+        assert!(text.contains("# TYPE fusen_db_pool_wait_seconds histogram"));
+        assert!(text.contains("# TYPE fusen_outbox_delivery_failures_total counter"));
+    }
+}
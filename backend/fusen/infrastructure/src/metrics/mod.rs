@@ -0,0 +1,8 @@
+mod grpc;
+#[allow(clippy::module_inception)]
+mod metrics;
+mod pool;
+
+pub use self::grpc::*;
+pub use self::metrics::*;
+pub use self::pool::*;
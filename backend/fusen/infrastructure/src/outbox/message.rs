@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use domain::entity::Fusen;
+use domain::error::DomainError;
+use domain::event::FusenDomainEvent;
+use serde::Serialize;
+
+// outbox の各バックエンドに保存する形式
+pub(crate) struct EncodedEvent {
+    pub event_type: &'static str,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+// 配信先のサービスが読む JSON。項目の削除や名前の変更は互換性を壊す
+#[derive(Serialize)]
+struct Payload {
+    #[serde(rename = "type")]
+    event_type: &'static str,
+    fusen_id: String,
+    owner_id: String,
+    board_id: String,
+    occurred_at: String,
+    // FusenDeleted では省略する
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fusen: Option<FusenPayload>,
+}
+
+#[derive(Serialize)]
+struct FusenPayload {
+    title: String,
+    note: String,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    color: String,
+    z_index: i32,
+    create_at: String,
+    update_at: String,
+}
+
+impl From<&Fusen> for FusenPayload {
+    fn from(fusen: &Fusen) -> Self {
+        Self {
+            title: fusen.title().to_string(),
+            note: fusen.note().to_string(),
+            x: fusen.position().x(),
+            y: fusen.position().y(),
+            width: fusen.size().width(),
+            height: fusen.size().height(),
+            color: fusen.color().to_string(),
+            z_index: fusen.z_index().value(),
+            create_at: fusen.create_at().to_string(),
+            update_at: fusen.update_at().to_string(),
+        }
+    }
+}
+
+pub(crate) fn encode(event: &FusenDomainEvent) -> Result<EncodedEvent, DomainError> {
+    let (owner_id, board_id, fusen) = match event {
+        FusenDomainEvent::Created(e) => (
+            e.fusen().owner_id(),
+            e.fusen().board_id(),
+            Some(FusenPayload::from(e.fusen())),
+        ),
+        FusenDomainEvent::Updated(e) => (
+            e.fusen().owner_id(),
+            e.fusen().board_id(),
+            Some(FusenPayload::from(e.fusen())),
+        ),
+        FusenDomainEvent::Deleted(e) => (e.owner_id(), e.board_id(), None),
+    };
+    let payload = Payload {
+        event_type: event.name(),
+        fusen_id: event.fusen_id().to_string(),
+        owner_id: owner_id.to_string(),
+        board_id: board_id.to_string(),
+        occurred_at: event.occurred_at().to_string(),
+        fusen,
+    };
+
+    Ok(EncodedEvent {
+        event_type: event.name(),
+        aggregate_id: event.fusen_id().to_string(),
+        payload: serde_json::to_string(&payload).map_err(anyhow::Error::from)?,
+        occurred_at: *event.occurred_at().value(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{Board, FusenBuilder};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId, Timestamp};
+    use serde_json::json;
+
+    #[test]
+    fn test_encode() {
+        let mut fusen = FusenBuilder::default()
+            .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        fusen.record_created(now);
+        fusen.record_deleted(now);
+        let events = fusen.take_events();
+
+        let created = encode(&events[0]).unwrap();
+        assert_eq!(created.event_type, "FusenCreated");
+        assert_eq!(
+            created.aggregate_id,
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+        assert_eq!(created.occurred_at, *now.value());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&created.payload).unwrap(),
+            json!({
+                "type": "FusenCreated",
+                "fusen_id": "01F8MECHZX3TBDSZ7XRADM79XE",
+                "owner_id": "owner",
+                "board_id": "01FMB0ARDXKQ7B1GZ1Z0K0T8R0",
+                "occurred_at": "2021-10-18T07:08:37Z",
+                "fusen": {
+                    "title": "title",
+                    "note": "note",
+                    "x": 0,
+                    "y": 0,
+                    "width": 200,
+                    "height": 200,
+                    "color": "yellow",
+                    "z_index": 0,
+                    "create_at": "2021-10-17T07:08:37Z",
+                    "update_at": "2021-10-17T07:08:37Z"
+                }
+            })
+        );
+
+        let deleted = encode(&events[1]).unwrap();
+        assert_eq!(deleted.event_type, "FusenDeleted");
+        assert!(!deleted.payload.contains("\"fusen\""));
+    }
+}
@@ -0,0 +1,7 @@
+mod message;
+mod relay;
+mod sink;
+
+pub(crate) use self::message::*;
+pub use self::relay::*;
+pub use self::sink::*;
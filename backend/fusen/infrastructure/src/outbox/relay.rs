@@ -0,0 +1,218 @@
+use crate::config::OutboxConfig;
+use crate::metrics::record_outbox_delivery;
+use crate::outbox::Sink;
+use crate::shutdown::Shutdown;
+use anyhow::{Error, Result};
+use domain::repository::OutboxRepository;
+use std::cmp;
+use std::time::Duration;
+
+// 配信先が復旧するまでの再試行の間隔の上限
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+// outbox のメッセージを取り出した順に sink に配信する。
+// 配信した後に acknowledge する前に停止した場合は lease が切れてから再送するので、配信先は ce-id で重複を除く
+pub struct Relay<O> {
+    outbox: O,
+    sink: Box<dyn Sink>,
+    config: OutboxConfig,
+}
+
+impl<O: OutboxRepository> Relay<O> {
+    pub fn new(outbox: O, sink: Box<dyn Sink>, config: OutboxConfig) -> Self {
+        Self {
+            outbox,
+            sink,
+            config,
+        }
+    }
+
+    // 失敗した場合は poll_interval から倍々に待つ時間を延ばす
+    pub async fn run(self, shutdown: Shutdown) -> Result<(), Error> {
+        let mut backoff = self.config.poll_interval();
+        loop {
+            let result = tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                result = self.relay_once() => result,
+            };
+            let wait = match result {
+                // 取り出しきれていないメッセージがあるので待たずに続ける
+                Ok(relayed) if relayed == self.config.batch_size => Duration::ZERO,
+                Ok(_) => {
+                    backoff = self.config.poll_interval();
+                    backoff
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, retry_after_ms = backoff.as_millis() as u64, "failed to relay outbox");
+                    let wait = backoff;
+                    backoff = cmp::min(backoff * 2, MAX_BACKOFF);
+                    wait
+                }
+            };
+
+            tokio::select! {
+                _ = shutdown.stopping() => return Ok(()),
+                _ = tokio::time::sleep(wait) => {}
+            }
+        }
+    }
+
+    // 配信に失敗した時点で残りを戻し、次の呼び出しで同じ順に再送する
+    pub async fn relay_once(&self) -> Result<usize, Error> {
+        let messages = self
+            .outbox
+            .claim(self.config.batch_size, self.config.lease())
+            .await?;
+
+        let mut delivered = Vec::with_capacity(messages.len());
+        let mut failure = None;
+        for (i, message) in messages.iter().enumerate() {
+            match self.sink.send(message).await {
+                Ok(()) => {
+                    record_outbox_delivery(true);
+                    delivered.push(*message.id());
+                }
+                Err(e) => {
+                    record_outbox_delivery(false);
+                    failure = Some((i, e));
+                    break;
+                }
+            }
+        }
+
+        if !delivered.is_empty() {
+            self.outbox.acknowledge(delivered).await?;
+        }
+        if let Some((i, e)) = failure {
+            let pending = messages[i..].iter().map(|message| *message.id()).collect();
+            self.outbox.release(pending).await?;
+
+            return Err(e.context(format!(
+                "failed to deliver outbox message {}",
+                messages[i].id().to_string()
+            )));
+        }
+
+        Ok(messages.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{FusenRepository, OutboxRepository};
+    use crate::outbox::ChannelSink;
+    use async_trait::async_trait;
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::repository::{CreateRepository, DeleteRepository, OutboxMessage};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId, Timestamp};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn new_fusen(id: &str) -> Fusen {
+        FusenBuilder::default()
+            .id(id.parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn config(batch_size: usize) -> OutboxConfig {
+        OutboxConfig {
+            batch_size,
+            poll_interval_ms: 10,
+            ..Default::default()
+        }
+    }
+
+    // fail_at 番目の送信だけ失敗する
+    struct FlakySink {
+        inner: ChannelSink,
+        calls: Arc<AtomicUsize>,
+        fail_at: usize,
+    }
+
+    #[async_trait]
+    impl Sink for FlakySink {
+        async fn send(&self, message: &OutboxMessage) -> Result<(), Error> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == self.fail_at {
+                anyhow::bail!("unavailable");
+            }
+            self.inner.send(message).await
+        }
+    }
+
+    async fn save(repository: &FusenRepository) {
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        for id in ["01F8MECHZX3TBDSZ7XRADM79XE", "01F8MECHZX3TBDSZ7XRADM79XF"] {
+            let mut fusen = new_fusen(id);
+            fusen.record_created(now);
+            repository.create(fusen).await.unwrap();
+        }
+        let mut fusen = new_fusen("01F8MECHZX3TBDSZ7XRADM79XE");
+        fusen.record_deleted(now);
+        repository.delete(fusen).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_relay_once() {
+        let outbox = OutboxRepository::default();
+        save(&FusenRepository::with_outbox(outbox.clone())).await;
+
+        let (inner, mut receiver) = ChannelSink::new();
+        let sink = FlakySink {
+            inner,
+            calls: Arc::new(AtomicUsize::new(0)),
+            fail_at: 1,
+        };
+        let sut = Relay::new(outbox.clone(), Box::new(sink), config(10));
+
+        // 2 件目で失敗した場合は 1 件目だけを acknowledge する
+        assert!(sut.relay_once().await.is_err());
+        assert_eq!(
+            receiver.recv().await.unwrap().event_type(),
+            &"FusenCreated".to_string()
+        );
+        assert!(receiver.try_recv().is_err());
+
+        // 戻したメッセージを同じ順に再送する
+        assert_eq!(sut.relay_once().await.unwrap(), 2);
+        let messages = [
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+        ];
+        assert_eq!(
+            messages[0].aggregate_id(),
+            &"01F8MECHZX3TBDSZ7XRADM79XF".to_string()
+        );
+        assert_eq!(messages[1].event_type(), &"FusenDeleted".to_string());
+
+        assert_eq!(sut.relay_once().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_run() {
+        let outbox = OutboxRepository::default();
+        let (sink, mut receiver) = ChannelSink::new();
+        let shutdown = Shutdown::default();
+        let relay = tokio::spawn(
+            Relay::new(outbox.clone(), Box::new(sink), config(2)).run(shutdown.clone()),
+        );
+
+        save(&FusenRepository::with_outbox(outbox.clone())).await;
+        for event_type in ["FusenCreated", "FusenCreated", "FusenDeleted"] {
+            assert_eq!(
+                receiver.recv().await.unwrap().event_type(),
+                &event_type.to_string()
+            );
+        }
+
+        shutdown.drain(Duration::ZERO).await;
+        relay.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::{bail, Error, Result};
+use async_trait::async_trait;
+use domain::repository::OutboxMessage;
+use hyper::client::HttpConnector;
+use hyper::header::CONTENT_TYPE;
+use hyper::{Body, Client, Request, Uri};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+// CloudEvents の source 属性。配信先はこのサービスが発行したイベントかを見分けるのに使う
+const SOURCE: &str = "/fusen";
+
+// 応答がない配信先のためにバッチ全体が止まらないようにする
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+// 配信に失敗した場合は relay が同じメッセージを再送する
+#[async_trait]
+pub trait Sink: Send + Sync + 'static {
+    async fn send(&self, message: &OutboxMessage) -> Result<(), Error>;
+}
+
+// CloudEvents の binary mode で payload を POST する。2xx 以外の応答は配信の失敗として扱う
+pub struct HttpSink {
+    client: Client<HttpConnector>,
+    endpoint: Uri,
+}
+
+impl HttpSink {
+    pub fn new(endpoint: Uri) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint,
+        }
+    }
+}
+
+#[async_trait]
+impl Sink for HttpSink {
+    async fn send(&self, message: &OutboxMessage) -> Result<(), Error> {
+        let request = Request::post(self.endpoint.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .header("ce-specversion", "1.0")
+            .header("ce-id", message.id().to_string())
+            .header("ce-type", message.event_type().as_str())
+            .header("ce-source", SOURCE)
+            .header("ce-subject", message.aggregate_id().as_str())
+            .header("ce-time", message.occurred_at().to_string())
+            .body(Body::from(message.payload().clone()))?;
+
+        let response = tokio::time::timeout(TIMEOUT, self.client.request(request)).await??;
+        if !response.status().is_success() {
+            bail!("{} responded with {}", self.endpoint, response.status());
+        }
+
+        Ok(())
+    }
+}
+
+// 同じプロセスの receiver に送る。receiver が drop されていれば失敗する
+pub struct ChannelSink {
+    sender: mpsc::UnboundedSender<OutboxMessage>,
+}
+
+impl ChannelSink {
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<OutboxMessage>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (Self { sender }, receiver)
+    }
+}
+
+#[async_trait]
+impl Sink for ChannelSink {
+    async fn send(&self, message: &OutboxMessage) -> Result<(), Error> {
+        self.sender.send(message.clone())?;
+        Ok(())
+    }
+}
+
+// 配信先が設定されていない場合に使う。outbox が溜まり続けないよう破棄する
+pub struct DiscardSink;
+
+#[async_trait]
+impl Sink for DiscardSink {
+    async fn send(&self, message: &OutboxMessage) -> Result<(), Error> {
+        tracing::debug!(
+            id = %message.id().to_string(),
+            event_type = %message.event_type(),
+            "discarded outbox message"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use domain::vo::{EventId, Timestamp};
+    use std::net::SocketAddr;
+
+    fn message() -> OutboxMessage {
+        OutboxMessage::new(
+            EventId::new(42),
+            "FusenCreated".to_string(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            "{\"type\":\"FusenCreated\"}".to_string(),
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap(),
+        )
+    }
+
+    async fn serve(status: StatusCode) -> (Uri, mpsc::UnboundedReceiver<(HeaderMap, String)>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let app = Router::new().route(
+            "/events",
+            post(move |headers: HeaderMap, body: String| async move {
+                sender.send((headers, body)).unwrap();
+                status
+            }),
+        );
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .serve(app.into_make_service());
+        let uri = format!("http://{}/events", server.local_addr())
+            .parse::<Uri>()
+            .unwrap();
+        tokio::spawn(server);
+
+        (uri, receiver)
+    }
+
+    #[tokio::test]
+    async fn test_http_sink() {
+        let (uri, mut receiver) = serve(StatusCode::ACCEPTED).await;
+        HttpSink::new(uri).send(&message()).await.unwrap();
+
+        let (headers, body) = receiver.recv().await.unwrap();
+        assert_eq!(body, "{\"type\":\"FusenCreated\"}".to_string());
+        assert_eq!(headers["ce-id"], "42");
+        assert_eq!(headers["ce-type"], "FusenCreated");
+        assert_eq!(headers["ce-source"], "/fusen");
+        assert_eq!(headers["ce-subject"], "01F8MECHZX3TBDSZ7XRADM79XE");
+        assert_eq!(headers["ce-time"], "2021-10-17T07:08:37Z");
+        assert_eq!(headers["content-type"], "application/json");
+
+        let (uri, _receiver) = serve(StatusCode::SERVICE_UNAVAILABLE).await;
+        assert!(HttpSink::new(uri).send(&message()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_channel_sink() {
+        let (sink, mut receiver) = ChannelSink::new();
+        sink.send(&message()).await.unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), message());
+
+        drop(receiver);
+        assert!(sink.send(&message()).await.is_err());
+    }
+}
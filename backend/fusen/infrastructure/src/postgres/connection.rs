@@ -1,31 +1,156 @@
+use crate::config::DatabaseConfig;
+use crate::metrics::observe_pool_wait;
+use crate::postgres::error::{connection_error, query_error};
 use anyhow::{Error, Result};
 use diesel::r2d2::ConnectionManager;
-use diesel::PgConnection;
+use diesel::{PgConnection, RunQueryDsl};
 use diesel_migrations::embed_migrations;
-use r2d2::Pool;
+use domain::error::DomainError;
+use r2d2::{Pool, PooledConnection};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 embed_migrations!("../migrations");
 
 #[derive(Clone)]
-pub struct DbPool(Pool<ConnectionManager<PgConnection>>);
+pub struct DbPool {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    permits: Arc<Semaphore>,
+    migrated: Arc<AtomicBool>,
+}
 
 impl DbPool {
     pub fn new(database_url: &str) -> Self {
-        let manager = ConnectionManager::<PgConnection>::new(database_url);
-        let pool = Pool::builder().build_unchecked(manager);
+        Self::with_config(&DatabaseConfig {
+            url: database_url.to_string(),
+            ..Default::default()
+        })
+    }
+
+    // 起動時にデータベースへ接続できなくても health check に応答できるよう、接続は遅延させる
+    pub fn with_config(config: &DatabaseConfig) -> Self {
+        let manager = ConnectionManager::<PgConnection>::new(&config.url);
+        let pool = Pool::builder()
+            .max_size(config.max_size)
+            .min_idle(config.min_size)
+            .connection_timeout(config.connection_timeout())
+            .build_unchecked(manager);
+        // コネクションプールの上限を超えて blocking スレッドを占有しないようにする
+        let permits = Arc::new(Semaphore::new(pool.max_size() as usize));
 
-        Self(pool)
+        Self {
+            pool,
+            permits,
+            migrated: Arc::new(AtomicBool::new(false)),
+        }
     }
 
     pub fn init(&self) -> Result<(), Error> {
         let conn = self.pool().get()?;
 
-        embedded_migrations::run_with_output(&conn, &mut std::io::stdout())?;
+        let mut output = Vec::new();
+        embedded_migrations::run_with_output(&conn, &mut output)?;
+        for line in String::from_utf8_lossy(&output).lines() {
+            tracing::info!("{}", line);
+        }
+        self.migrated.store(true, Ordering::Release);
 
         Ok(())
     }
 
+    // migration が終わっていない場合やコネクションを取得できない場合はエラーを返す
+    pub async fn check(&self) -> Result<(), DomainError> {
+        if !self.migrated.load(Ordering::Acquire) {
+            return Err(DomainError::Unavailable(
+                "migrations are not completed".to_string(),
+            ));
+        }
+
+        self.run(|conn| {
+            diesel::sql_query("SELECT 1")
+                .execute(conn)
+                .map(|_| ())
+                .map_err(query_error)
+        })
+        .await
+    }
+
+    // 実行中のクエリが終わるのを待ち、以降はコネクションを貸し出さない。
+    // コネクション自体は最後の DbPool が drop されたときに切断される
+    pub async fn close(&self) {
+        if let Ok(permits) = self.permits.acquire_many(self.pool.max_size()).await {
+            permits.forget();
+        }
+        self.permits.close();
+    }
+
     pub fn pool(&self) -> Pool<ConnectionManager<PgConnection>> {
-        self.0.clone()
+        self.pool.clone()
+    }
+
+    // トランザクションのためにコネクションを permit ごと貸し出す。
+    // 呼び出し側が permit を手放すまで、他の操作はこのコネクションの分だけ待つ
+    pub(crate) async fn dedicated(
+        &self,
+    ) -> Result<
+        (
+            OwnedSemaphorePermit,
+            PooledConnection<ConnectionManager<PgConnection>>,
+        ),
+        DomainError,
+    > {
+        let started = Instant::now();
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| DomainError::Unavailable(e.to_string()))?;
+
+        let pool = self.pool();
+        let conn = tokio::task::spawn_blocking(move || pool.get().map_err(connection_error))
+            .await
+            .map_err(anyhow::Error::from)??;
+        observe_pool_wait(started.elapsed());
+
+        Ok((permit, conn))
+    }
+
+    // Diesel は同期 API なので tokio の worker を塞がないよう blocking スレッドで実行する
+    pub(crate) async fn run<R, F>(&self, f: F) -> Result<R, DomainError>
+    where
+        F: FnOnce(&PgConnection) -> Result<R, DomainError> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.run_pooled(move |conn| f(&conn)).await
+    }
+
+    // コネクションを f に渡して所有させる。複数の操作にまたがるトランザクションで使う
+    pub(crate) async fn run_pooled<R, F>(&self, f: F) -> Result<R, DomainError>
+    where
+        F: FnOnce(PooledConnection<ConnectionManager<PgConnection>>) -> Result<R, DomainError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    {
+        // permit とコネクションの両方を待った時間を記録する
+        let started = Instant::now();
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(|e| DomainError::Unavailable(e.to_string()))?;
+
+        let pool = self.pool();
+        tokio::task::spawn_blocking(move || {
+            let conn = pool.get().map_err(connection_error)?;
+            observe_pool_wait(started.elapsed());
+
+            f(conn)
+        })
+        .await
+        .map_err(anyhow::Error::from)?
     }
 }
@@ -0,0 +1,162 @@
+use diesel::result::{DatabaseErrorInformation, DatabaseErrorKind, Error};
+use diesel::Connection;
+use domain::error::DomainError;
+
+// ドライバのメッセージにはテーブルや制約の名前が含まれるため、クライアントには決まった文言だけを返し、
+// 詳細はサーバーのログに残す
+pub(crate) fn query_error(error: Error) -> DomainError {
+    match error {
+        Error::NotFound => DomainError::NotFound("not found entity".to_string()),
+        Error::DatabaseError(kind, info) => match kind {
+            DatabaseErrorKind::UniqueViolation => {
+                log_violation(&*info);
+                DomainError::Conflict(conflict_message(info.constraint_name()).to_string())
+            }
+            DatabaseErrorKind::ForeignKeyViolation => {
+                log_violation(&*info);
+                DomainError::FailedPrecondition(
+                    reference_message(info.constraint_name()).to_string(),
+                )
+            }
+            DatabaseErrorKind::SerializationFailure => {
+                log_violation(&*info);
+                DomainError::Unavailable("concurrent update conflicted, retry later".to_string())
+            }
+            kind => DomainError::Unexpected(Error::DatabaseError(kind, info).into()),
+        },
+        error => DomainError::Unexpected(error.into()),
+    }
+}
+
+fn log_violation(info: &(dyn DatabaseErrorInformation + Send + Sync)) {
+    tracing::info!(
+        table = info.table_name(),
+        constraint = info.constraint_name(),
+        detail = info.details(),
+        error = info.message(),
+        "query rejected by database"
+    );
+}
+
+// SQLite は制約の名前を返さないため既定の文言になる
+fn conflict_message(constraint: Option<&str>) -> &'static str {
+    match constraint {
+        Some("fusens_pkey") => "fusen already exists",
+        Some("boards_pkey") => "board already exists",
+        Some("attachments_pkey") => "attachment already exists",
+        _ => "entity already exists",
+    }
+}
+
+fn reference_message(constraint: Option<&str>) -> &'static str {
+    match constraint {
+        Some("fusens_board_id_fkey") => "board not found",
+        _ => "referenced entity not found",
+    }
+}
+
+// f が返した DomainError を RollbackTransaction に変換せずに返す
+pub(crate) fn transaction<C, T, F>(conn: &C, f: F) -> Result<T, DomainError>
+where
+    C: Connection,
+    F: FnOnce() -> Result<T, DomainError>,
+{
+    let mut failure = None;
+    let result = conn.transaction::<_, Error, _>(|| {
+        f().map_err(|e| {
+            failure = Some(e);
+            Error::RollbackTransaction
+        })
+    });
+
+    match failure {
+        Some(e) => Err(e),
+        None => result.map_err(query_error),
+    }
+}
+
+pub(crate) fn connection_error(error: r2d2::Error) -> DomainError {
+    tracing::warn!(error = %error, "failed to get database connection");
+    DomainError::Unavailable("database unavailable".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_error() {
+        assert!(matches!(
+            query_error(Error::NotFound),
+            DomainError::NotFound(_)
+        ));
+        assert!(matches!(
+            query_error(Error::RollbackTransaction),
+            DomainError::Unexpected(_)
+        ));
+
+        let violation = |kind, constraint: &'static str| {
+            Error::DatabaseError(
+                kind,
+                Box::new(Violation {
+                    message: format!("violates constraint \"{}\"", constraint),
+                    constraint,
+                }),
+            )
+        };
+        assert!(matches!(
+            query_error(violation(DatabaseErrorKind::UniqueViolation, "fusens_pkey")),
+            DomainError::Conflict(message) if message == "fusen already exists"
+        ));
+        assert!(matches!(
+            query_error(violation(DatabaseErrorKind::UniqueViolation, "unknown_key")),
+            DomainError::Conflict(message) if message == "entity already exists"
+        ));
+        assert!(matches!(
+            query_error(violation(
+                DatabaseErrorKind::ForeignKeyViolation,
+                "fusens_board_id_fkey"
+            )),
+            DomainError::FailedPrecondition(message) if message == "board not found"
+        ));
+        assert!(matches!(
+            query_error(violation(DatabaseErrorKind::SerializationFailure, "")),
+            DomainError::Unavailable(message) if !message.contains("constraint")
+        ));
+        assert!(matches!(
+            query_error(violation(DatabaseErrorKind::UnableToSendCommand, "")),
+            DomainError::Unexpected(_)
+        ));
+    }
+
+    struct Violation {
+        message: String,
+        constraint: &'static str,
+    }
+
+    impl DatabaseErrorInformation for Violation {
+        fn message(&self) -> &str {
+            &self.message
+        }
+
+        fn details(&self) -> Option<&str> {
+            None
+        }
+
+        fn hint(&self) -> Option<&str> {
+            None
+        }
+
+        fn table_name(&self) -> Option<&str> {
+            Some("fusens")
+        }
+
+        fn column_name(&self) -> Option<&str> {
+            None
+        }
+
+        fn constraint_name(&self) -> Option<&str> {
+            Some(self.constraint)
+        }
+    }
+}
@@ -1,10 +1,21 @@
-use crate::postgres::schema::fusens;
+use crate::postgres::schema::{attachments, boards, fusen_events, fusens, outbox_messages};
+use chrono::{DateTime, Utc};
 
 #[derive(Queryable, Debug)]
 pub struct FusenModel {
     pub id: String,
     pub title: String,
     pub note: String,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
 }
 
 #[derive(Insertable)]
@@ -13,4 +24,118 @@ pub struct NewFusenModel {
     pub id: String,
     pub title: String,
     pub note: String,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Queryable, Debug)]
+pub struct FusenEventModel {
+    pub id: i64,
+    pub kind: String,
+    pub fusen_id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+    pub occurred_at: DateTime<Utc>,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+    pub tx_id: i64,
+}
+
+#[derive(Insertable)]
+#[table_name = "fusen_events"]
+pub struct NewFusenEventModel {
+    pub kind: String,
+    pub fusen_id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+    pub occurred_at: DateTime<Utc>,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Queryable, Debug)]
+pub struct BoardModel {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub member_ids: Vec<String>,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[table_name = "boards"]
+pub struct NewBoardModel {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub member_ids: Vec<String>,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Debug)]
+pub struct OutboxModel {
+    pub id: i64,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[table_name = "outbox_messages"]
+pub struct NewOutboxModel {
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Debug)]
+pub struct AttachmentModel {
+    pub id: String,
+    pub fusen_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub create_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[table_name = "attachments"]
+pub struct NewAttachmentModel {
+    pub id: String,
+    pub fusen_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub create_at: DateTime<Utc>,
 }
@@ -1,3 +1,48 @@
+table! {
+    attachments (id) {
+        id -> Varchar,
+        fusen_id -> Varchar,
+        file_name -> Varchar,
+        content_type -> Varchar,
+        size -> Int8,
+        checksum -> Varchar,
+        create_at -> Timestamptz,
+    }
+}
+
+table! {
+    boards (id) {
+        id -> Varchar,
+        owner_id -> Varchar,
+        name -> Varchar,
+        member_ids -> Array<Text>,
+        create_at -> Timestamptz,
+        update_at -> Timestamptz,
+    }
+}
+
+table! {
+    fusen_events (id) {
+        id -> Int8,
+        kind -> Varchar,
+        fusen_id -> Varchar,
+        title -> Varchar,
+        note -> Text,
+        create_at -> Timestamptz,
+        update_at -> Timestamptz,
+        occurred_at -> Timestamptz,
+        owner_id -> Varchar,
+        board_id -> Varchar,
+        x -> Int4,
+        y -> Int4,
+        width -> Int4,
+        height -> Int4,
+        color -> Varchar,
+        z_index -> Int4,
+        tx_id -> Int8,
+    }
+}
+
 table! {
     fusens (id) {
         id -> Varchar,
@@ -5,5 +50,32 @@ table! {
         note -> Text,
         create_at -> Timestamptz,
         update_at -> Timestamptz,
+        owner_id -> Varchar,
+        board_id -> Varchar,
+        x -> Int4,
+        y -> Int4,
+        width -> Int4,
+        height -> Int4,
+        color -> Varchar,
+        z_index -> Int4,
+    }
+}
+
+table! {
+    outbox_messages (id) {
+        id -> Int8,
+        event_type -> Varchar,
+        aggregate_id -> Varchar,
+        payload -> Text,
+        occurred_at -> Timestamptz,
+        locked_until -> Nullable<Timestamptz>,
     }
 }
+
+allow_tables_to_appear_in_same_query!(
+    attachments,
+    boards,
+    fusen_events,
+    fusens,
+    outbox_messages,
+);
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod shutdown;
+
+pub use self::shutdown::*;
@@ -0,0 +1,113 @@
+use anyhow::{Error, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::watch;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+enum Phase {
+    Running,
+    // health status を NOT_SERVING にして新しいリクエストが来なくなるのを待つ
+    Draining,
+    // 処理中のリクエストが終わり次第サーバーを止める
+    Stopping,
+}
+
+// SIGTERM/SIGINT を受け取ってからの停止手順を各サーバーに伝える
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<watch::Sender<Phase>>,
+    receiver: watch::Receiver<Phase>,
+}
+
+impl Shutdown {
+    pub async fn listen(self, drain_period: Duration) -> Result<(), Error> {
+        let mut terminate = signal(SignalKind::terminate())?;
+        let mut interrupt = signal(SignalKind::interrupt())?;
+        tokio::select! {
+            _ = terminate.recv() => tracing::info!("received SIGTERM"),
+            _ = interrupt.recv() => tracing::info!("received SIGINT"),
+        }
+
+        self.drain(drain_period).await;
+
+        Ok(())
+    }
+
+    pub async fn drain(&self, period: Duration) {
+        tracing::info!(drain_period_ms = period.as_millis() as u64, "draining");
+        self.send(Phase::Draining);
+        tokio::time::sleep(period).await;
+
+        tracing::info!("stopping");
+        self.send(Phase::Stopping);
+    }
+
+    pub async fn draining(&self) {
+        self.wait_for(Phase::Draining).await
+    }
+
+    pub async fn stopping(&self) {
+        self.wait_for(Phase::Stopping).await
+    }
+
+    // receiver を self が保持しているので送信に失敗することはない
+    fn send(&self, phase: Phase) {
+        let _ = self.sender.send(phase);
+    }
+
+    async fn wait_for(&self, phase: Phase) {
+        let mut receiver = self.receiver.clone();
+        while *receiver.borrow() < phase {
+            if receiver.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        let (sender, receiver) = watch::channel(Phase::Running);
+
+        Self {
+            sender: Arc::new(sender),
+            receiver,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_drain() {
+        let shutdown = Shutdown::default();
+        let draining = shutdown.draining();
+        let stopping = shutdown.stopping();
+        tokio::pin!(draining, stopping);
+
+        assert!((&mut draining).now_or_never().is_none());
+        assert!((&mut stopping).now_or_never().is_none());
+
+        let drain = tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move { shutdown.drain(Duration::from_millis(100)).await }
+        });
+
+        tokio::time::timeout(Duration::from_millis(50), &mut draining)
+            .await
+            .unwrap();
+        assert!((&mut stopping).now_or_never().is_none());
+
+        tokio::time::timeout(Duration::from_secs(1), &mut stopping)
+            .await
+            .unwrap();
+        drain.await.unwrap();
+
+        // 停止後に待ち始めてもすぐに返る
+        assert!(shutdown.draining().now_or_never().is_some());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::metrics::query_timer;
+use crate::postgres::error::query_error;
+use crate::sqlite::models::*;
+use crate::sqlite::schema::{attachments, fusens};
+use crate::sqlite::DbPool;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use diesel::dsl::not;
+use diesel::prelude::*;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::AttachmentRepository as Repository;
+use domain::vo::*;
+
+#[derive(Clone)]
+pub struct AttachmentRepository {
+    connections: DbPool,
+}
+
+impl AttachmentRepository {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+
+    fn add_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        entity: Attachment,
+    ) -> Result<(), DomainError> {
+        diesel::insert_into(attachments::table)
+            .values(&NewAttachmentModel {
+                id: entity.id().to_string(),
+                fusen_id: entity.fusen_id().to_string(),
+                file_name: entity.file_name().to_string(),
+                content_type: entity.content_type().to_string(),
+                size: *entity.size() as i64,
+                checksum: entity.checksum().to_string(),
+                create_at: entity.create_at().value().naive_utc(),
+            })
+            .execute(conn)
+            .map_err(query_error)?;
+
+        Ok(())
+    }
+
+    fn get_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        id: Id<Attachment>,
+    ) -> Result<Attachment, DomainError> {
+        let attachment = attachments::table
+            .filter(attachments::id.eq(id.to_string()))
+            .first::<AttachmentModel>(conn)
+            .optional()
+            .map_err(query_error)?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("attachment {} not found", id.to_string()))
+            })?;
+
+        to_entity(attachment)
+    }
+
+    fn list_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        fusen_id: Id<Fusen>,
+    ) -> Result<Vec<Attachment>, DomainError> {
+        attachments::table
+            .filter(attachments::fusen_id.eq(fusen_id.to_string()))
+            .order(attachments::id.asc())
+            .load::<AttachmentModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_entity)
+            .collect()
+    }
+
+    fn orphaned_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        limit: usize,
+    ) -> Result<Vec<Attachment>, DomainError> {
+        attachments::table
+            .filter(not(
+                attachments::fusen_id.eq_any(fusens::table.select(fusens::id))
+            ))
+            .order(attachments::id.asc())
+            .limit(limit as i64)
+            .load::<AttachmentModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_entity)
+            .collect()
+    }
+
+    fn remove_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        ids: Vec<Id<Attachment>>,
+    ) -> Result<(), DomainError> {
+        let ids = ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        diesel::delete(attachments::table.filter(attachments::id.eq_any(ids)))
+            .execute(conn)
+            .map_err(query_error)?;
+
+        Ok(())
+    }
+}
+
+// 保存済みのデータが不正な場合はクライアントの入力エラーではないので Unexpected として扱う
+fn to_entity(attachment: AttachmentModel) -> Result<Attachment, DomainError> {
+    Ok(AttachmentBuilder::default()
+        .id(attachment
+            .id
+            .parse::<Id<Attachment>>()
+            .map_err(anyhow::Error::from)?)
+        .fusen_id(
+            attachment
+                .fusen_id
+                .parse::<Id<Fusen>>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .file_name(
+            attachment
+                .file_name
+                .parse::<FileName>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .content_type(
+            attachment
+                .content_type
+                .parse::<ContentType>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .size(attachment.size as u64)
+        .checksum(
+            attachment
+                .checksum
+                .parse::<Checksum>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .create_at(Utc.from_utc_datetime(&attachment.create_at))
+        .build()
+        .map_err(anyhow::Error::from)?)
+}
+
+#[async_trait]
+impl Repository for AttachmentRepository {
+    async fn add(&self, attachment: Attachment) -> Result<(), DomainError> {
+        let _timer = query_timer("attachment", "add");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.add_with_conn(conn, attachment))
+            .await
+    }
+
+    async fn get(&self, id: Id<Attachment>) -> Result<Attachment, DomainError> {
+        let _timer = query_timer("attachment", "get");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.get_with_conn(conn, id))
+            .await
+    }
+
+    async fn list(&self, fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError> {
+        let _timer = query_timer("attachment", "list");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.list_with_conn(conn, fusen_id))
+            .await
+    }
+
+    async fn orphaned(&self, limit: usize) -> Result<Vec<Attachment>, DomainError> {
+        let _timer = query_timer("attachment", "orphaned");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.orphaned_with_conn(conn, limit))
+            .await
+    }
+
+    async fn remove(&self, ids: Vec<Id<Attachment>>) -> Result<(), DomainError> {
+        let _timer = query_timer("attachment", "remove");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.remove_with_conn(conn, ids))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::schema::boards;
+    use ulid::Ulid;
+
+    // テストごとに空のデータベースを使う
+    fn connections() -> DbPool {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+        connections
+    }
+
+    // 添付先の fusen を board と一緒に作る
+    fn insert_fusen(conn: &SqliteConnection) -> Id<Fusen> {
+        let board_id = Ulid::new().to_string();
+        let fusen_id = Ulid::new().to_string();
+        diesel::insert_into(boards::table)
+            .values(&NewBoardModel {
+                id: board_id.clone(),
+                owner_id: "owner".to_string(),
+                name: "board".to_string(),
+                member_ids: "[]".to_string(),
+                create_at: "2021-10-17T07:08:37".parse().unwrap(),
+                update_at: "2021-10-17T07:08:37".parse().unwrap(),
+            })
+            .execute(conn)
+            .unwrap();
+        diesel::insert_into(fusens::table)
+            .values(&NewFusenModel {
+                id: fusen_id.clone(),
+                title: "title".to_string(),
+                note: "note".to_string(),
+                create_at: "2021-10-17T07:08:37".parse().unwrap(),
+                update_at: "2021-10-17T07:08:37".parse().unwrap(),
+                owner_id: "owner".to_string(),
+                board_id,
+                x: 0,
+                y: 0,
+                width: 200,
+                height: 200,
+                color: "yellow".to_string(),
+                z_index: 0,
+            })
+            .execute(conn)
+            .unwrap();
+
+        fusen_id.parse::<Id<Fusen>>().unwrap()
+    }
+
+    fn new_attachment(fusen_id: &Id<Fusen>) -> Attachment {
+        AttachmentBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Attachment>>().unwrap())
+            .fusen_id(fusen_id.clone())
+            .file_name("screenshot.png".parse::<FileName>().unwrap())
+            .content_type("image/png".parse::<ContentType>().unwrap())
+            .size(3u64)
+            .checksum(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                    .parse::<Checksum>()
+                    .unwrap(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_attachment_repository_add_and_get() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let fusen_id = insert_fusen(&conn);
+        let first = new_attachment(&fusen_id);
+        let second = new_attachment(&fusen_id);
+
+        let sut = AttachmentRepository::new(connections.clone());
+        sut.add_with_conn(&conn, first.clone()).unwrap();
+        sut.add_with_conn(&conn, second.clone()).unwrap();
+
+        let attachment = sut.get_with_conn(&conn, first.id().clone()).unwrap();
+        assert_eq!(attachment.content_type(), first.content_type());
+        assert_eq!(attachment.size(), first.size());
+        assert_eq!(attachment.create_at(), first.create_at());
+        assert!(matches!(
+            sut.get_with_conn(
+                &conn,
+                Ulid::new().to_string().parse::<Id<Attachment>>().unwrap()
+            ),
+            Err(DomainError::NotFound(_))
+        ));
+
+        let mut expected = vec![first, second];
+        expected.sort_by_key(|attachment| attachment.id().to_string());
+        assert_eq!(sut.list_with_conn(&conn, fusen_id).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_attachment_repository_orphaned() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let fusen_id = insert_fusen(&conn);
+        let kept = new_attachment(&fusen_id);
+        let orphaned = new_attachment(&Ulid::new().to_string().parse::<Id<Fusen>>().unwrap());
+
+        let sut = AttachmentRepository::new(connections.clone());
+        sut.add_with_conn(&conn, kept).unwrap();
+        sut.add_with_conn(&conn, orphaned.clone()).unwrap();
+
+        assert_eq!(
+            sut.orphaned_with_conn(&conn, 100).unwrap(),
+            vec![orphaned.clone()]
+        );
+
+        sut.remove_with_conn(&conn, vec![orphaned.id().clone()])
+            .unwrap();
+        assert!(sut.orphaned_with_conn(&conn, 100).unwrap().is_empty());
+    }
+}
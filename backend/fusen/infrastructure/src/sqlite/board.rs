@@ -0,0 +1,393 @@
+use crate::postgres::error::query_error;
+use crate::sqlite::models::*;
+use crate::sqlite::schema::boards;
+use crate::sqlite::DbPool;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error};
+use diesel::sql_types::{Bool, Text};
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+
+#[derive(Clone)]
+pub struct BoardRepository {
+    connections: DbPool,
+}
+
+impl BoardRepository {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+
+    fn create_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregate: Board,
+    ) -> Result<(), DomainError> {
+        diesel::insert_into(boards::table)
+            .values(&NewBoardModel {
+                id: aggregate.id().to_string(),
+                owner_id: aggregate.owner_id().to_string(),
+                name: aggregate.name().to_string(),
+                member_ids: to_member_ids(&aggregate)?,
+                create_at: aggregate.create_at().value().naive_utc(),
+                update_at: aggregate.update_at().value().naive_utc(),
+            })
+            .execute(conn)
+            .map_err(query_error)?;
+
+        Ok(())
+    }
+
+    fn get_with_conn(&self, conn: &SqliteConnection, id: Id<Board>) -> Result<Board, DomainError> {
+        let board = boards::table
+            .filter(boards::id.eq(id.to_string()))
+            .first::<BoardModel>(conn)
+            .optional()
+            .map_err(query_error)?
+            .ok_or_else(|| DomainError::NotFound(format!("board {} not found", id.to_string())))?;
+
+        to_aggregate(board)
+    }
+
+    fn list_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        query: ListQuery<Board>,
+    ) -> Result<Vec<Board>, DomainError> {
+        let mut statement = boards::table.into_boxed();
+
+        if let Some(owner_id) = query.owner_id {
+            statement = statement.filter(
+                boards::owner_id.eq(owner_id.to_string()).or(sql::<Bool>(
+                    "EXISTS (SELECT 1 FROM json_each(boards.member_ids) WHERE json_each.value = ",
+                )
+                .bind::<Text, _>(owner_id.to_string())
+                .sql(")")),
+            );
+        }
+
+        statement = match (query.cursor, query.order) {
+            (Some(cursor), SortOrder::Asc) => statement.filter(boards::id.gt(cursor.to_string())),
+            (Some(cursor), SortOrder::Desc) => statement.filter(boards::id.lt(cursor.to_string())),
+            (None, _) => statement,
+        };
+
+        statement = match query.order {
+            SortOrder::Asc => statement.order(boards::id.asc()),
+            SortOrder::Desc => statement.order(boards::id.desc()),
+        };
+
+        statement
+            .limit(query.limit as i64)
+            .load::<BoardModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_aggregate)
+            .collect()
+    }
+
+    fn update_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregate: Board,
+    ) -> Result<(), DomainError> {
+        let updated =
+            diesel::update(boards::table.filter(boards::id.eq(aggregate.id().to_string())))
+                .set((
+                    boards::name.eq(aggregate.name().to_string()),
+                    boards::member_ids.eq(to_member_ids(&aggregate)?),
+                    boards::update_at.eq(aggregate.update_at().value().naive_utc()),
+                ))
+                .execute(conn)
+                .map_err(query_error)?;
+
+        if updated == 0 {
+            return Err(DomainError::NotFound(format!(
+                "board {} not found",
+                aggregate.id().to_string()
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn delete_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregate: Board,
+    ) -> Result<(), DomainError> {
+        // fusen から参照されている場合の外部キー制約違反は fusen の追加時と区別して返す
+        let deleted =
+            diesel::delete(boards::table.filter(boards::id.eq(aggregate.id().to_string())))
+                .execute(conn)
+                .map_err(|e| match e {
+                    Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                        DomainError::FailedPrecondition(format!(
+                            "board {} still has fusens",
+                            aggregate.id().to_string()
+                        ))
+                    }
+                    e => query_error(e),
+                })?;
+
+        if deleted == 0 {
+            return Err(DomainError::NotFound(format!(
+                "board {} not found",
+                aggregate.id().to_string()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+// SQLite には配列型がないので JSON の配列として保存する
+fn to_member_ids(aggregate: &Board) -> Result<String, DomainError> {
+    let member_ids = aggregate
+        .member_ids()
+        .iter()
+        .map(|member_id| member_id.to_string())
+        .collect::<Vec<_>>();
+
+    Ok(serde_json::to_string(&member_ids).map_err(anyhow::Error::from)?)
+}
+
+// 保存済みのデータが不正な場合はクライアントの入力エラーではないので Unexpected として扱う
+fn to_aggregate(board: BoardModel) -> Result<Board, DomainError> {
+    let member_ids = serde_json::from_str::<Vec<String>>(&board.member_ids)
+        .map_err(anyhow::Error::from)?
+        .iter()
+        .map(|member_id| member_id.parse::<OwnerId>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(anyhow::Error::from)?;
+
+    Ok(BoardBuilder::default()
+        .id(board.id.parse::<Id<Board>>().map_err(anyhow::Error::from)?)
+        .owner_id(
+            board
+                .owner_id
+                .parse::<OwnerId>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .name(
+            board
+                .name
+                .parse::<BoardName>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .member_ids(member_ids)
+        .create_at(Utc.from_utc_datetime(&board.create_at))
+        .update_at(Utc.from_utc_datetime(&board.update_at))
+        .build()
+        .map_err(anyhow::Error::from)?)
+}
+
+#[async_trait]
+impl CreateRepository<Board> for BoardRepository {
+    async fn create(&self, aggregate: Board) -> Result<(), DomainError> {
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.create_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl GetRepository<Board> for BoardRepository {
+    async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.get_with_conn(conn, id))
+            .await
+    }
+}
+
+#[async_trait]
+impl ListRepository<Board> for BoardRepository {
+    async fn list(&self, query: ListQuery<Board>) -> Result<Vec<Board>, DomainError> {
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.list_with_conn(conn, query))
+            .await
+    }
+}
+
+#[async_trait]
+impl UpdateRepository<Board> for BoardRepository {
+    async fn update(&self, aggregate: Board) -> Result<(), DomainError> {
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.update_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl DeleteRepository<Board> for BoardRepository {
+    async fn delete(&self, aggregate: Board) -> Result<(), DomainError> {
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.delete_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::schema::fusens;
+    use ulid::Ulid;
+
+    fn connections() -> DbPool {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+        connections
+    }
+
+    fn new_board(owner_id: &str, member_ids: &[&str]) -> Board {
+        BoardBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Board>>().unwrap())
+            .owner_id(owner_id.parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(
+                member_ids
+                    .iter()
+                    .map(|m| m.parse::<OwnerId>().unwrap())
+                    .collect::<Vec<_>>(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_board_repository_create_and_get() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let entity = new_board("owner", &["member"]);
+        let sut = BoardRepository::new(connections.clone());
+
+        assert!(sut.create_with_conn(&conn, entity.clone()).is_ok());
+        assert_eq!(
+            sut.get_with_conn(&conn, entity.id().clone()).unwrap(),
+            entity
+        );
+        assert!(matches!(
+            sut.get_with_conn(&conn, Ulid::new().to_string().parse::<Id<Board>>().unwrap()),
+            Err(DomainError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_board_repository_list() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let sut = BoardRepository::new(connections.clone());
+
+        let owned = new_board("owner", &[]);
+        let shared = new_board("another", &["member", "owner"]);
+        sut.create_with_conn(&conn, owned.clone()).unwrap();
+        sut.create_with_conn(&conn, shared.clone()).unwrap();
+        // member_ids の JSON に部分一致するだけの board は含めない
+        sut.create_with_conn(&conn, new_board("another", &["owner2"]))
+            .unwrap();
+        let mut expected = vec![owned, shared];
+        expected.sort_by_key(|board| board.id().to_string());
+
+        let boards = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(
+                    None,
+                    10,
+                    SortOrder::Asc,
+                    None,
+                    Some("owner".parse::<OwnerId>().unwrap()),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert_eq!(boards, expected);
+
+        let next = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(
+                    Some(expected[0].id().clone()),
+                    10,
+                    SortOrder::Asc,
+                    None,
+                    Some("owner".parse::<OwnerId>().unwrap()),
+                    None,
+                ),
+            )
+            .unwrap();
+        assert_eq!(next, vec![expected[1].clone()]);
+    }
+
+    #[test]
+    fn test_board_repository_update() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let entity = new_board("owner", &[]);
+        let sut = BoardRepository::new(connections.clone());
+        assert!(sut.update_with_conn(&conn, entity.clone()).is_err());
+
+        sut.create_with_conn(&conn, entity.clone()).unwrap();
+        let mut target = entity.clone();
+        target.set_name("renamed".parse::<BoardName>().unwrap());
+        target.set_member_ids(vec!["member".parse::<OwnerId>().unwrap()]);
+
+        assert!(sut.update_with_conn(&conn, target.clone()).is_ok());
+        assert_eq!(
+            sut.get_with_conn(&conn, entity.id().clone()).unwrap(),
+            target
+        );
+    }
+
+    #[test]
+    fn test_board_repository_delete_with_fusens() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let entity = new_board("owner", &[]);
+        let sut = BoardRepository::new(connections.clone());
+        sut.create_with_conn(&conn, entity.clone()).unwrap();
+        diesel::insert_into(fusens::table)
+            .values(&NewFusenModel {
+                id: Ulid::new().to_string(),
+                title: "title".to_string(),
+                note: "note".to_string(),
+                create_at: entity.create_at().value().naive_utc(),
+                update_at: entity.update_at().value().naive_utc(),
+                owner_id: "owner".to_string(),
+                board_id: entity.id().to_string(),
+                x: 0,
+                y: 0,
+                width: 200,
+                height: 200,
+                color: "yellow".to_string(),
+                z_index: 0,
+            })
+            .execute(&conn)
+            .unwrap();
+
+        // usecase での確認をすり抜けても外部キーで削除を拒否する
+        assert!(matches!(
+            sut.delete_with_conn(&conn, entity.clone()),
+            Err(DomainError::FailedPrecondition(message)) if message.ends_with("still has fusens")
+        ));
+
+        diesel::delete(fusens::table).execute(&conn).unwrap();
+        assert!(sut.delete_with_conn(&conn, entity.clone()).is_ok());
+        assert!(matches!(
+            sut.delete_with_conn(&conn, entity),
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
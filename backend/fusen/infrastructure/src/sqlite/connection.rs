@@ -0,0 +1,204 @@
+use crate::config::DatabaseConfig;
+use crate::metrics::observe_pool_wait;
+use crate::postgres::error::{connection_error, query_error};
+use anyhow::{Error, Result};
+use diesel::connection::SimpleConnection;
+use diesel::r2d2::{ConnectionManager, CustomizeConnection};
+use diesel::{RunQueryDsl, SqliteConnection};
+use diesel_migrations::embed_migrations;
+use domain::error::DomainError;
+use r2d2::{Pool, PooledConnection};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+embed_migrations!("../migrations_sqlite");
+
+const IN_MEMORY: &str = ":memory:";
+
+#[derive(Clone)]
+pub struct DbPool {
+    pool: Pool<ConnectionManager<SqliteConnection>>,
+    permits: Arc<Semaphore>,
+    migrated: Arc<AtomicBool>,
+}
+
+impl DbPool {
+    pub fn new(database_url: &str) -> Self {
+        Self::with_config(&DatabaseConfig {
+            url: database_url.to_string(),
+            ..Default::default()
+        })
+    }
+
+    // url にはファイルのパスか :memory: を指定する
+    pub fn with_config(config: &DatabaseConfig) -> Self {
+        let manager = ConnectionManager::<SqliteConnection>::new(&config.url);
+        let builder = Pool::builder()
+            .connection_customizer(Box::new(Pragmas))
+            .connection_timeout(config.connection_timeout());
+        // :memory: はコネクションごとに別のデータベースになるため、ひとつのコネクションを使い続ける
+        let pool = if config.url == IN_MEMORY {
+            builder
+                .max_size(1)
+                .idle_timeout(None)
+                .max_lifetime(None)
+                .build_unchecked(manager)
+        } else {
+            builder
+                .max_size(config.max_size)
+                .min_idle(config.min_size)
+                .build_unchecked(manager)
+        };
+        // コネクションプールの上限を超えて blocking スレッドを占有しないようにする
+        let permits = Arc::new(Semaphore::new(pool.max_size() as usize));
+
+        Self {
+            pool,
+            permits,
+            migrated: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn init(&self) -> Result<(), Error> {
+        let conn = self.pool().get()?;
+
+        let mut output = Vec::new();
+        embedded_migrations::run_with_output(&conn, &mut output)?;
+        for line in String::from_utf8_lossy(&output).lines() {
+            tracing::info!("{}", line);
+        }
+        self.migrated.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
+    // migration が終わっていない場合やコネクションを取得できない場合はエラーを返す
+    pub async fn check(&self) -> Result<(), DomainError> {
+        if !self.migrated.load(Ordering::Acquire) {
+            return Err(DomainError::Unavailable(
+                "migrations are not completed".to_string(),
+            ));
+        }
+
+        self.run(|conn| {
+            diesel::sql_query("SELECT 1")
+                .execute(conn)
+                .map(|_| ())
+                .map_err(query_error)
+        })
+        .await
+    }
+
+    // 実行中のクエリが終わるのを待ち、以降はコネクションを貸し出さない
+    pub async fn close(&self) {
+        if let Ok(permits) = self.permits.acquire_many(self.pool.max_size()).await {
+            permits.forget();
+        }
+        self.permits.close();
+    }
+
+    pub fn pool(&self) -> Pool<ConnectionManager<SqliteConnection>> {
+        self.pool.clone()
+    }
+
+    // トランザクションのためにコネクションを permit ごと貸し出す。
+    // 呼び出し側が permit を手放すまで、他の操作はこのコネクションの分だけ待つ
+    pub(crate) async fn dedicated(
+        &self,
+    ) -> Result<
+        (
+            OwnedSemaphorePermit,
+            PooledConnection<ConnectionManager<SqliteConnection>>,
+        ),
+        DomainError,
+    > {
+        let started = Instant::now();
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| DomainError::Unavailable(e.to_string()))?;
+
+        let pool = self.pool();
+        let conn = tokio::task::spawn_blocking(move || pool.get().map_err(connection_error))
+            .await
+            .map_err(anyhow::Error::from)??;
+        observe_pool_wait(started.elapsed());
+
+        Ok((permit, conn))
+    }
+
+    pub(crate) async fn run<R, F>(&self, f: F) -> Result<R, DomainError>
+    where
+        F: FnOnce(&SqliteConnection) -> Result<R, DomainError> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.run_pooled(move |conn| f(&conn)).await
+    }
+
+    pub(crate) async fn run_pooled<R, F>(&self, f: F) -> Result<R, DomainError>
+    where
+        F: FnOnce(PooledConnection<ConnectionManager<SqliteConnection>>) -> Result<R, DomainError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    {
+        let started = Instant::now();
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(|e| DomainError::Unavailable(e.to_string()))?;
+
+        let pool = self.pool();
+        tokio::task::spawn_blocking(move || {
+            let conn = pool.get().map_err(connection_error)?;
+            observe_pool_wait(started.elapsed());
+
+            f(conn)
+        })
+        .await
+        .map_err(anyhow::Error::from)?
+    }
+}
+
+// 外部キーの制約はコネクションごとに有効にする必要がある。
+// 書き込みが重なった場合は SQLITE_BUSY を返さずにしばらく待つ
+#[derive(Debug)]
+struct Pragmas;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for Pragmas {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        conn.batch_execute(
+            "PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000; PRAGMA journal_mode = WAL;",
+        )
+        .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check() {
+        let connections = DbPool::new(IN_MEMORY);
+        assert!(matches!(
+            connections.check().await,
+            Err(DomainError::Unavailable(_))
+        ));
+
+        connections.init().unwrap();
+        assert!(connections.check().await.is_ok());
+    }
+
+    #[test]
+    fn test_in_memory_pool() {
+        let connections = DbPool::new(IN_MEMORY);
+        assert_eq!(connections.pool().max_size(), 1);
+        assert_eq!(DbPool::new("fusen.db").pool().max_size(), 10);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::metrics::query_timer;
+use crate::postgres::error::query_error;
+use crate::postgres::{kind_from_str, kind_to_str};
+use crate::sqlite::models::*;
+use crate::sqlite::schema::fusen_events;
+use crate::sqlite::DbPool;
+use async_stream::try_stream;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use diesel::prelude::*;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::event::{FusenDomainEvent, FusenEvent};
+use domain::repository::{EventStream, PruneRepository, SubscribeRepository};
+use domain::vo::*;
+use futures::stream::StreamExt;
+use std::time::Duration;
+
+const FETCH_SIZE: i64 = 100;
+// 同じプロセスの書き込みしかないので、通知の仕組みを持たずに取りに行く
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Clone)]
+pub struct FusenEventRepository {
+    connections: DbPool,
+}
+
+impl FusenEventRepository {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+
+    fn last_id_with_conn(&self, conn: &SqliteConnection) -> Result<i64, DomainError> {
+        Ok(fusen_events::table
+            .select(diesel::dsl::max(fusen_events::id))
+            .first::<Option<i64>>(conn)
+            .map_err(query_error)?
+            .unwrap_or(0))
+    }
+
+    // 消したイベントからは再開できない
+    fn check_available_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        after: i64,
+    ) -> Result<i64, DomainError> {
+        if after == 0 {
+            return Ok(after);
+        }
+
+        fusen_events::table
+            .select(fusen_events::id)
+            .find(after)
+            .first::<i64>(conn)
+            .optional()
+            .map_err(query_error)?
+            .ok_or_else(|| {
+                DomainError::InvalidArgument(format!("event {} is no longer available", after))
+            })
+    }
+
+    fn fetch_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        after: i64,
+    ) -> Result<Vec<(EventId, FusenEvent)>, DomainError> {
+        fusen_events::table
+            .filter(fusen_events::id.gt(after))
+            .order(fusen_events::id.asc())
+            .limit(FETCH_SIZE)
+            .load::<FusenEventModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_event)
+            .collect()
+    }
+
+    fn prune_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        before: Timestamp,
+        limit: usize,
+    ) -> Result<usize, DomainError> {
+        let expired = fusen_events::table
+            .select(fusen_events::id)
+            .filter(fusen_events::occurred_at.lt(before.value().naive_utc()))
+            .order(fusen_events::id.asc())
+            .limit(limit as i64)
+            .load::<i64>(conn)
+            .map_err(query_error)?;
+        diesel::delete(fusen_events::table.filter(fusen_events::id.eq_any(expired)))
+            .execute(conn)
+            .map_err(query_error)
+    }
+}
+
+// Watch に流すイベントはドメインイベントから作り、集約の変更と同じトランザクションで保存する
+pub(crate) fn insert_events(
+    conn: &SqliteConnection,
+    events: &[FusenDomainEvent],
+) -> Result<(), DomainError> {
+    let models = events
+        .iter()
+        .map(FusenEvent::from)
+        .map(|event| to_model(&event))
+        .collect::<Vec<_>>();
+    diesel::insert_into(fusen_events::table)
+        .values(&models)
+        .execute(conn)
+        .map_err(query_error)?;
+
+    Ok(())
+}
+
+fn to_model(event: &FusenEvent) -> NewFusenEventModel {
+    let fusen = event.fusen();
+    NewFusenEventModel {
+        kind: kind_to_str(*event.kind()).to_string(),
+        fusen_id: fusen.id().to_string(),
+        title: fusen.title().to_string(),
+        note: fusen.note().to_string(),
+        create_at: fusen.create_at().value().naive_utc(),
+        update_at: fusen.update_at().value().naive_utc(),
+        occurred_at: event.occurred_at().value().naive_utc(),
+        owner_id: fusen.owner_id().to_string(),
+        board_id: fusen.board_id().to_string(),
+        x: fusen.position().x(),
+        y: fusen.position().y(),
+        width: fusen.size().width(),
+        height: fusen.size().height(),
+        color: fusen.color().to_string(),
+        z_index: fusen.z_index().value(),
+    }
+}
+
+fn to_event(event: FusenEventModel) -> Result<(EventId, FusenEvent), DomainError> {
+    let kind = kind_from_str(&event.kind)?;
+    let fusen = FusenBuilder::default()
+        .id(event
+            .fusen_id
+            .parse::<Id<Fusen>>()
+            .map_err(anyhow::Error::from)?)
+        .owner_id(
+            event
+                .owner_id
+                .parse::<OwnerId>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .board_id(
+            event
+                .board_id
+                .parse::<Id<Board>>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .title(
+            event
+                .title
+                .parse::<FusenTitle>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .note(
+            event
+                .note
+                .parse::<FusenNote>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .position(Position::new(event.x, event.y).map_err(anyhow::Error::from)?)
+        .size(FusenSize::new(event.width, event.height).map_err(anyhow::Error::from)?)
+        .color(
+            event
+                .color
+                .parse::<FusenColor>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .z_index(ZIndex::new(event.z_index).map_err(anyhow::Error::from)?)
+        .create_at(Utc.from_utc_datetime(&event.create_at))
+        .update_at(Utc.from_utc_datetime(&event.update_at))
+        .build()
+        .map_err(anyhow::Error::from)?;
+
+    Ok((
+        EventId::new(event.id as u64),
+        FusenEvent::new(
+            kind,
+            fusen,
+            Timestamp::new(Utc.from_utc_datetime(&event.occurred_at)),
+        ),
+    ))
+}
+
+#[async_trait]
+impl SubscribeRepository<FusenEvent> for FusenEventRepository {
+    async fn subscribe(
+        &self,
+        after: Option<EventId>,
+    ) -> Result<EventStream<FusenEvent>, DomainError> {
+        let repository = self.clone();
+        let mut last = self
+            .connections
+            .run(move |conn| match after {
+                Some(after) => repository.check_available_with_conn(conn, after.value() as i64),
+                None => repository.last_id_with_conn(conn),
+            })
+            .await?;
+
+        let repository = self.clone();
+        let stream = try_stream! {
+            loop {
+                let fetcher = repository.clone();
+                let events = repository
+                    .connections
+                    .run(move |conn| fetcher.fetch_with_conn(conn, last))
+                    .await?;
+                let fetched = events.len() as i64;
+
+                for (id, event) in events {
+                    last = id.value() as i64;
+                    yield (id, event);
+                }
+
+                if fetched < FETCH_SIZE {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            }
+        };
+
+        Ok(stream.boxed())
+    }
+}
+
+#[async_trait]
+impl PruneRepository for FusenEventRepository {
+    async fn prune(&self, before: Timestamp, limit: usize) -> Result<usize, DomainError> {
+        let _timer = query_timer("fusen_event", "prune");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.prune_with_conn(conn, before, limit))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::save_with_outbox;
+    use domain::event::FusenEventKind;
+    use tokio::time::timeout;
+    use ulid::Ulid;
+
+    fn new_fusen() -> Fusen {
+        FusenBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    async fn next(stream: &mut EventStream<FusenEvent>) -> (EventId, FusenEvent) {
+        timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap()
+    }
+
+    // :memory: のプールはコネクションがひとつなので、リポジトリを呼び出す前に返す
+    fn save(connections: &DbPool, events: Vec<FusenDomainEvent>) -> Result<(), DomainError> {
+        let conn = connections.pool().get().unwrap();
+        save_with_outbox(&conn, events, || Ok(()))
+    }
+
+    #[tokio::test]
+    async fn test_fusen_event_repository() {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+        let sut = FusenEventRepository::new(connections.clone());
+
+        let mut live = sut.subscribe(None).await.unwrap();
+
+        let mut fusen = new_fusen();
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        fusen.record_created(now);
+        fusen.record_deleted(now);
+        let events = fusen.take_events();
+        save(&connections, events.clone()).unwrap();
+
+        let (created_id, created) = next(&mut live).await;
+        let (deleted_id, deleted) = next(&mut live).await;
+        assert!(created_id < deleted_id);
+        assert_eq!(*created.kind(), FusenEventKind::Created);
+        assert_eq!(created.occurred_at(), &now);
+        assert_eq!(deleted, FusenEvent::from(&events[1]));
+        assert_eq!(*deleted.kind(), FusenEventKind::Deleted);
+
+        // 保存に失敗した変更のイベントは流さない
+        {
+            let conn = connections.pool().get().unwrap();
+            let mut rejected = new_fusen();
+            rejected.record_created(now);
+            assert!(save_with_outbox(&conn, rejected.take_events(), || Err(
+                DomainError::Conflict("conflict".to_string())
+            ))
+            .is_err());
+        }
+        let mut accepted = new_fusen();
+        accepted.record_created(now);
+        save(&connections, accepted.take_events()).unwrap();
+        assert_eq!(next(&mut live).await.1.fusen().id(), accepted.id());
+
+        let mut resumed = sut.subscribe(Some(created_id)).await.unwrap();
+        assert_eq!(next(&mut resumed).await, (deleted_id, deleted));
+
+        // 保持期間を過ぎたイベントは消え、そこからは再開できない
+        let before = "2021-10-19T07:08:37Z".parse::<Timestamp>().unwrap();
+        assert_eq!(sut.prune(before, 2).await.unwrap(), 2);
+        assert_eq!(sut.prune(before, 2).await.unwrap(), 1);
+        assert!(matches!(
+            sut.subscribe(Some(created_id)).await,
+            Err(DomainError::InvalidArgument(_))
+        ));
+    }
+}
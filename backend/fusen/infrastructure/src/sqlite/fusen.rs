@@ -0,0 +1,680 @@
+use crate::metrics::query_timer;
+use crate::postgres::error::query_error;
+use crate::postgres::{batch_with_conn, escape_like};
+use crate::sqlite::models::*;
+use crate::sqlite::schema::fusens;
+use crate::sqlite::{save_with_outbox, DbPool};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use diesel::prelude::*;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+
+#[derive(Clone)]
+pub struct FusenRepository {
+    connections: DbPool,
+}
+
+impl FusenRepository {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+
+    pub(crate) fn create_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        mut aggregate: Fusen,
+    ) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
+        save_with_outbox(conn, events, || {
+            diesel::insert_into(fusens::table)
+                .values(&NewFusenModel {
+                    id: aggregate.id().clone().to_string(),
+                    title: aggregate.title().clone().to_string(),
+                    note: aggregate.note().clone().to_string(),
+                    create_at: aggregate.create_at().value().naive_utc(),
+                    update_at: aggregate.update_at().value().naive_utc(),
+                    owner_id: aggregate.owner_id().to_string(),
+                    board_id: aggregate.board_id().to_string(),
+                    x: aggregate.position().x(),
+                    y: aggregate.position().y(),
+                    width: aggregate.size().width(),
+                    height: aggregate.size().height(),
+                    color: aggregate.color().to_string(),
+                    z_index: aggregate.z_index().value(),
+                })
+                .execute(conn)
+                .map_err(query_error)?;
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn create_all_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        batch_with_conn(conn, aggregates, mode, |aggregate| {
+            self.create_with_conn(conn, aggregate)
+        })
+    }
+
+    pub(crate) fn get_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        id: Id<Fusen>,
+    ) -> Result<Fusen, DomainError> {
+        let fusen = fusens::table
+            .filter(fusens::id.eq(id.to_string()))
+            .first::<FusenModel>(conn)
+            .optional()
+            .map_err(query_error)?
+            .ok_or_else(|| DomainError::NotFound(format!("fusen {} not found", id.to_string())))?;
+
+        to_aggregate(fusen)
+    }
+
+    pub(crate) fn get_all_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        ids: Vec<Id<Fusen>>,
+    ) -> Result<Vec<Fusen>, DomainError> {
+        fusens::table
+            .filter(fusens::id.eq_any(ids.iter().map(|id| id.to_string())))
+            .order(fusens::id.asc())
+            .load::<FusenModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_aggregate)
+            .collect()
+    }
+
+    pub(crate) fn list_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        query: ListQuery<Fusen>,
+    ) -> Result<Vec<Fusen>, DomainError> {
+        let mut statement = fusens::table.into_boxed();
+
+        if let Some(owner_id) = query.owner_id {
+            statement = statement.filter(fusens::owner_id.eq(owner_id.to_string()));
+        }
+
+        if let Some(board_id) = query.board_id {
+            statement = statement.filter(fusens::board_id.eq(board_id.to_string()));
+        }
+
+        if let Some(keyword) = query.keyword {
+            // SQLite の LIKE は ASCII の範囲でのみ大文字と小文字を区別しない
+            let pattern = format!("%{}%", escape_like(&keyword));
+            statement = statement.filter(
+                fusens::title
+                    .like(pattern.clone())
+                    .escape('\\')
+                    .or(fusens::note.like(pattern).escape('\\')),
+            );
+        }
+
+        statement = match (query.cursor, query.order) {
+            (Some(cursor), SortOrder::Asc) => statement.filter(fusens::id.gt(cursor.to_string())),
+            (Some(cursor), SortOrder::Desc) => statement.filter(fusens::id.lt(cursor.to_string())),
+            (None, _) => statement,
+        };
+
+        statement = match query.order {
+            SortOrder::Asc => statement.order(fusens::id.asc()),
+            SortOrder::Desc => statement.order(fusens::id.desc()),
+        };
+
+        statement
+            .limit(query.limit as i64)
+            .load::<FusenModel>(conn)
+            .map_err(query_error)?
+            .into_iter()
+            .map(to_aggregate)
+            .collect()
+    }
+
+    pub(crate) fn update_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        mut aggregate: Fusen,
+    ) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
+        save_with_outbox(conn, events, || {
+            let updated =
+                diesel::update(fusens::table.filter(fusens::id.eq(aggregate.id().to_string())))
+                    .set((
+                        fusens::title.eq(aggregate.title().to_string()),
+                        fusens::note.eq(aggregate.note().to_string()),
+                        fusens::x.eq(aggregate.position().x()),
+                        fusens::y.eq(aggregate.position().y()),
+                        fusens::width.eq(aggregate.size().width()),
+                        fusens::height.eq(aggregate.size().height()),
+                        fusens::color.eq(aggregate.color().to_string()),
+                        fusens::z_index.eq(aggregate.z_index().value()),
+                        fusens::update_at.eq(aggregate.update_at().value().naive_utc()),
+                    ))
+                    .execute(conn)
+                    .map_err(query_error)?;
+
+            if updated == 0 {
+                return Err(DomainError::NotFound(format!(
+                    "fusen {} not found",
+                    aggregate.id().to_string()
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
+    // 配置に関する項目だけを書き換える
+    pub(crate) fn move_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        mut aggregate: Fusen,
+    ) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
+        save_with_outbox(conn, events, || {
+            let updated =
+                diesel::update(fusens::table.filter(fusens::id.eq(aggregate.id().to_string())))
+                    .set((
+                        fusens::x.eq(aggregate.position().x()),
+                        fusens::y.eq(aggregate.position().y()),
+                        fusens::width.eq(aggregate.size().width()),
+                        fusens::height.eq(aggregate.size().height()),
+                        fusens::z_index.eq(aggregate.z_index().value()),
+                        fusens::update_at.eq(aggregate.update_at().value().naive_utc()),
+                    ))
+                    .execute(conn)
+                    .map_err(query_error)?;
+
+            if updated == 0 {
+                return Err(DomainError::NotFound(format!(
+                    "fusen {} not found",
+                    aggregate.id().to_string()
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn move_all_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregates: Vec<Fusen>,
+    ) -> Result<(), DomainError> {
+        batch_with_conn(conn, aggregates, BatchMode::AllOrNothing, |aggregate| {
+            self.move_with_conn(conn, aggregate)
+        })
+        .map(|_| ())
+    }
+
+    pub(crate) fn delete_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        mut aggregate: Fusen,
+    ) -> Result<(), DomainError> {
+        let events = aggregate.take_events();
+        save_with_outbox(conn, events, || {
+            let deleted =
+                diesel::delete(fusens::table.filter(fusens::id.eq(aggregate.id().to_string())))
+                    .execute(conn)
+                    .map_err(query_error)?;
+
+            if deleted == 0 {
+                return Err(DomainError::NotFound(format!(
+                    "fusen {} not found",
+                    aggregate.id().to_string()
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn delete_all_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        batch_with_conn(conn, aggregates, mode, |aggregate| {
+            self.delete_with_conn(conn, aggregate)
+        })
+    }
+}
+
+// 保存済みのデータが不正な場合はクライアントの入力エラーではないので Unexpected として扱う
+fn to_aggregate(fusen: FusenModel) -> Result<Fusen, DomainError> {
+    Ok(FusenBuilder::default()
+        .id(fusen.id.parse::<Id<Fusen>>().map_err(anyhow::Error::from)?)
+        .owner_id(
+            fusen
+                .owner_id
+                .parse::<OwnerId>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .board_id(
+            fusen
+                .board_id
+                .parse::<Id<Board>>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .title(
+            fusen
+                .title
+                .parse::<FusenTitle>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .note(
+            fusen
+                .note
+                .parse::<FusenNote>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .position(Position::new(fusen.x, fusen.y).map_err(anyhow::Error::from)?)
+        .size(FusenSize::new(fusen.width, fusen.height).map_err(anyhow::Error::from)?)
+        .color(
+            fusen
+                .color
+                .parse::<FusenColor>()
+                .map_err(anyhow::Error::from)?,
+        )
+        .z_index(ZIndex::new(fusen.z_index).map_err(anyhow::Error::from)?)
+        .create_at(Utc.from_utc_datetime(&fusen.create_at))
+        .update_at(Utc.from_utc_datetime(&fusen.update_at))
+        .build()
+        .map_err(anyhow::Error::from)?)
+}
+
+#[async_trait]
+impl CreateRepository<Fusen> for FusenRepository {
+    async fn create(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "create");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.create_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl CreateAllRepository<Fusen> for FusenRepository {
+    async fn create_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let _timer = query_timer("fusen", "create_all");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.create_all_with_conn(conn, aggregates, mode))
+            .await
+    }
+}
+
+#[async_trait]
+impl GetRepository<Fusen> for FusenRepository {
+    async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+        let _timer = query_timer("fusen", "get");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.get_with_conn(conn, id))
+            .await
+    }
+}
+
+#[async_trait]
+impl GetAllRepository<Fusen> for FusenRepository {
+    async fn get_all(&self, ids: Vec<Id<Fusen>>) -> Result<Vec<Fusen>, DomainError> {
+        let _timer = query_timer("fusen", "get_all");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.get_all_with_conn(conn, ids))
+            .await
+    }
+}
+
+#[async_trait]
+impl ListRepository<Fusen> for FusenRepository {
+    async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+        let _timer = query_timer("fusen", "list");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.list_with_conn(conn, query))
+            .await
+    }
+}
+
+#[async_trait]
+impl UpdateRepository<Fusen> for FusenRepository {
+    async fn update(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "update");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.update_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl MoveAllRepository<Fusen> for FusenRepository {
+    async fn move_all(&self, aggregates: Vec<Fusen>) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "move_all");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.move_all_with_conn(conn, aggregates))
+            .await
+    }
+}
+
+#[async_trait]
+impl DeleteRepository<Fusen> for FusenRepository {
+    async fn delete(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "delete");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.delete_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl DeleteAllRepository<Fusen> for FusenRepository {
+    async fn delete_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let _timer = query_timer("fusen", "delete_all");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.delete_all_with_conn(conn, aggregates, mode))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conformance::{fusen_repository_conformance, BOARD_ID};
+    use crate::sqlite::schema::boards;
+    use ulid::Ulid;
+
+    // テストごとに空のデータベースを使う
+    fn connections() -> DbPool {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+        connections
+    }
+
+    // fusens.board_id の外部キーを満たす board を作成する
+    fn create_board(conn: &SqliteConnection) -> Id<Board> {
+        insert_board(conn, Ulid::new().to_string())
+    }
+
+    fn insert_board(conn: &SqliteConnection, id: String) -> Id<Board> {
+        diesel::insert_into(boards::table)
+            .values(&NewBoardModel {
+                id: id.clone(),
+                owner_id: "owner".to_string(),
+                name: "board".to_string(),
+                member_ids: "[]".to_string(),
+                create_at: "2021-10-17T07:08:37".parse().unwrap(),
+                update_at: "2021-10-17T07:08:37".parse().unwrap(),
+            })
+            .execute(conn)
+            .unwrap();
+
+        id.parse::<Id<Board>>().unwrap()
+    }
+
+    async fn setup() -> (FusenRepository, Id<Board>) {
+        let connections = connections();
+        let board_id = insert_board(&connections.pool().get().unwrap(), BOARD_ID.to_string());
+
+        (FusenRepository::new(connections), board_id)
+    }
+
+    fusen_repository_conformance!(setup());
+
+    fn new_fusen(board_id: &Id<Board>, owner_id: &str, note: &str) -> Fusen {
+        FusenBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Fusen>>().unwrap())
+            .owner_id(owner_id.parse::<OwnerId>().unwrap())
+            .board_id(board_id.clone())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note(note.parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fusen_repository_create() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let board_id = create_board(&conn);
+        let entity = new_fusen(&board_id, "owner", "note");
+
+        let sut = FusenRepository::new(connections.clone());
+
+        assert!(sut.create_with_conn(&conn, entity.clone()).is_ok());
+        assert!(matches!(
+            sut.create_with_conn(&conn, entity),
+            Err(DomainError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_fusen_repository_create_without_board() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let entity = new_fusen(
+            &Ulid::new().to_string().parse::<Id<Board>>().unwrap(),
+            "owner",
+            "note",
+        );
+
+        let sut = FusenRepository::new(connections.clone());
+
+        assert!(matches!(
+            sut.create_with_conn(&conn, entity),
+            Err(DomainError::FailedPrecondition(_))
+        ));
+    }
+
+    #[test]
+    fn test_fusen_repository_get() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let board_id = create_board(&conn);
+        let entity = new_fusen(&board_id, "owner", "note");
+
+        let sut = FusenRepository::new(connections.clone());
+        sut.create_with_conn(&conn, entity.clone()).unwrap();
+
+        assert_eq!(
+            sut.get_with_conn(&conn, entity.id().clone()).unwrap(),
+            entity
+        );
+        assert!(matches!(
+            sut.get_with_conn(&conn, Ulid::new().to_string().parse::<Id<Fusen>>().unwrap()),
+            Err(DomainError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_fusen_repository_list() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let board_id = create_board(&conn);
+        let sut = FusenRepository::new(connections.clone());
+
+        let mut entities = Vec::new();
+        for _ in 0..3 {
+            let entity = new_fusen(&board_id, "owner", "100% Note");
+            sut.create_with_conn(&conn, entity.clone()).unwrap();
+            entities.push(entity);
+        }
+        entities.sort_by_key(|f| f.id().to_string());
+        sut.create_with_conn(&conn, new_fusen(&board_id, "another", "100% Note"))
+            .unwrap();
+        sut.create_with_conn(&conn, new_fusen(&board_id, "owner", "1000 notes"))
+            .unwrap();
+        let owner_id = Some("owner".parse::<OwnerId>().unwrap());
+
+        // % はワイルドカードではなく文字として扱う
+        let asc = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(
+                    None,
+                    2,
+                    SortOrder::Asc,
+                    Some("0% note".to_string()),
+                    owner_id.clone(),
+                    Some(board_id.clone()),
+                ),
+            )
+            .unwrap();
+        assert_eq!(asc, entities[..2].to_vec());
+
+        let next = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(
+                    Some(asc[1].id().clone()),
+                    2,
+                    SortOrder::Asc,
+                    Some("0% note".to_string()),
+                    owner_id.clone(),
+                    Some(board_id.clone()),
+                ),
+            )
+            .unwrap();
+        assert_eq!(next, entities[2..].to_vec());
+
+        let desc = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(
+                    None,
+                    3,
+                    SortOrder::Desc,
+                    Some("0% note".to_string()),
+                    owner_id,
+                    Some(board_id.clone()),
+                ),
+            )
+            .unwrap();
+        assert_eq!(desc, entities.iter().rev().cloned().collect::<Vec<_>>());
+
+        let board = sut
+            .list_with_conn(
+                &conn,
+                ListQuery::new(None, 10, SortOrder::Asc, None, None, Some(board_id)),
+            )
+            .unwrap();
+        assert_eq!(board.len(), 5);
+    }
+
+    #[test]
+    fn test_fusen_repository_update() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let board_id = create_board(&conn);
+        let entity = new_fusen(&board_id, "owner", "note");
+
+        let sut = FusenRepository::new(connections.clone());
+        assert!(matches!(
+            sut.update_with_conn(&conn, entity.clone()),
+            Err(DomainError::NotFound(_))
+        ));
+
+        sut.create_with_conn(&conn, entity.clone()).unwrap();
+        let mut target = entity.clone();
+        target.set_title("updated title".parse::<FusenTitle>().unwrap());
+        target.set_position(Position::new(-10, 20).unwrap());
+        target.set_color(FusenColor::Blue);
+
+        assert!(sut.update_with_conn(&conn, target.clone()).is_ok());
+        assert_eq!(
+            sut.get_with_conn(&conn, entity.id().clone()).unwrap(),
+            target
+        );
+    }
+
+    #[test]
+    fn test_fusen_repository_batch() {
+        let connections = connections();
+        let conn = connections.pool().get().unwrap();
+        let board_id = create_board(&conn);
+        let first = new_fusen(&board_id, "owner", "note");
+        let second = new_fusen(&board_id, "owner", "note");
+        let third = new_fusen(&board_id, "owner", "note");
+
+        let sut = FusenRepository::new(connections.clone());
+        sut.create_with_conn(&conn, first.clone()).unwrap();
+
+        // 重複する fusen が含まれる場合は何も作成しない
+        assert!(matches!(
+            sut.create_all_with_conn(
+                &conn,
+                vec![second.clone(), first.clone()],
+                BatchMode::AllOrNothing
+            ),
+            Err(DomainError::Conflict(_))
+        ));
+        assert_eq!(
+            sut.get_all_with_conn(&conn, vec![first.id().clone(), second.id().clone()])
+                .unwrap(),
+            vec![first.clone()]
+        );
+
+        let results = sut
+            .create_all_with_conn(
+                &conn,
+                vec![second.clone(), first.clone(), third.clone()],
+                BatchMode::PartialSuccess,
+            )
+            .unwrap();
+        assert!(matches!(
+            &results[..],
+            [Ok(()), Err(DomainError::Conflict(_)), Ok(())]
+        ));
+
+        let results = sut
+            .delete_all_with_conn(&conn, vec![first, second, third], BatchMode::PartialSuccess)
+            .unwrap();
+        assert!(results.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn test_fusen_repository_delete() {
+        let connections = connections();
+        let board_id = create_board(&connections.pool().get().unwrap());
+        let entity = new_fusen(&board_id, "owner", "note");
+
+        let sut = FusenRepository::new(connections);
+        sut.create(entity.clone()).await.unwrap();
+
+        assert!(sut.delete(entity.clone()).await.is_ok());
+        assert!(matches!(
+            sut.get(entity.id().clone()).await,
+            Err(DomainError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.delete(entity).await,
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
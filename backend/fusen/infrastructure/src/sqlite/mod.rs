@@ -0,0 +1,19 @@
+mod attachment;
+mod board;
+mod connection;
+mod event;
+mod fusen;
+mod models;
+mod outbox;
+mod schema;
+mod unit_of_work;
+
+pub use self::attachment::*;
+pub use self::board::*;
+pub use self::connection::*;
+pub use self::event::*;
+pub use self::fusen::*;
+pub use self::models::*;
+pub use self::outbox::*;
+pub use self::schema::*;
+pub use self::unit_of_work::*;
@@ -0,0 +1,141 @@
+use crate::sqlite::schema::{attachments, boards, fusen_events, fusens, outbox_messages};
+use chrono::NaiveDateTime;
+
+// SQLite にはタイムゾーン付きの型がないので UTC で保存する
+#[derive(Queryable, Debug)]
+pub struct FusenModel {
+    pub id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Insertable)]
+#[table_name = "fusens"]
+pub struct NewFusenModel {
+    pub id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Queryable, Debug)]
+pub struct FusenEventModel {
+    pub id: i64,
+    pub kind: String,
+    pub fusen_id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+    pub occurred_at: NaiveDateTime,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Insertable)]
+#[table_name = "fusen_events"]
+pub struct NewFusenEventModel {
+    pub kind: String,
+    pub fusen_id: String,
+    pub title: String,
+    pub note: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+    pub occurred_at: NaiveDateTime,
+    pub owner_id: String,
+    pub board_id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub color: String,
+    pub z_index: i32,
+}
+
+#[derive(Queryable, Debug)]
+pub struct BoardModel {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub member_ids: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[table_name = "boards"]
+pub struct NewBoardModel {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub member_ids: String,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug)]
+pub struct OutboxModel {
+    pub id: i64,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: NaiveDateTime,
+    pub locked_until: Option<NaiveDateTime>,
+}
+
+#[derive(Insertable)]
+#[table_name = "outbox_messages"]
+pub struct NewOutboxModel {
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug)]
+pub struct AttachmentModel {
+    pub id: String,
+    pub fusen_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub create_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[table_name = "attachments"]
+pub struct NewAttachmentModel {
+    pub id: String,
+    pub fusen_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub create_at: NaiveDateTime,
+}
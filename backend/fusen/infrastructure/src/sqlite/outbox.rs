@@ -0,0 +1,240 @@
+use crate::metrics::query_timer;
+use crate::outbox::encode;
+use crate::postgres::error::{query_error, transaction};
+use crate::sqlite::event::insert_events;
+use crate::sqlite::models::*;
+use crate::sqlite::schema::outbox_messages;
+use crate::sqlite::DbPool;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use diesel::prelude::*;
+use domain::error::DomainError;
+use domain::event::FusenDomainEvent;
+use domain::repository::OutboxMessage;
+use domain::repository::OutboxRepository as Repository;
+use domain::vo::{EventId, Timestamp};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct OutboxRepository {
+    connections: DbPool,
+}
+
+impl OutboxRepository {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+
+    fn claim_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<OutboxMessage>, DomainError> {
+        let now = Utc::now();
+        let locked_until = now + chrono::Duration::from_std(lease).map_err(anyhow::Error::from)?;
+
+        // SQLite には SKIP LOCKED がないので、書き込みロックを取ってから読み出す
+        conn.immediate_transaction::<_, diesel::result::Error, _>(|| {
+            let messages = outbox_messages::table
+                .filter(
+                    outbox_messages::locked_until
+                        .is_null()
+                        .or(outbox_messages::locked_until.lt(now.naive_utc())),
+                )
+                .order(outbox_messages::id.asc())
+                .limit(limit as i64)
+                .load::<OutboxModel>(conn)?;
+
+            diesel::update(
+                outbox_messages::table.filter(outbox_messages::id.eq_any(ids(&messages))),
+            )
+            .set(outbox_messages::locked_until.eq(locked_until.naive_utc()))
+            .execute(conn)?;
+
+            Ok(messages.into_iter().map(to_message).collect())
+        })
+        .map_err(query_error)
+    }
+
+    fn acknowledge_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        ids: Vec<EventId>,
+    ) -> Result<(), DomainError> {
+        let ids = ids.iter().map(|id| id.value() as i64).collect::<Vec<_>>();
+        diesel::delete(outbox_messages::table.filter(outbox_messages::id.eq_any(ids)))
+            .execute(conn)
+            .map_err(query_error)?;
+
+        Ok(())
+    }
+
+    fn release_with_conn(
+        &self,
+        conn: &SqliteConnection,
+        ids: Vec<EventId>,
+    ) -> Result<(), DomainError> {
+        let ids = ids.iter().map(|id| id.value() as i64).collect::<Vec<_>>();
+        diesel::update(outbox_messages::table.filter(outbox_messages::id.eq_any(ids)))
+            .set(outbox_messages::locked_until.eq(None::<NaiveDateTime>))
+            .execute(conn)
+            .map_err(query_error)?;
+
+        Ok(())
+    }
+}
+
+// 集約の変更とイベントをひとつのトランザクションで保存する。保存に失敗した変更は配信しない。
+// Watch に流すイベントも同じトランザクションで書き出す
+pub(crate) fn save_with_outbox<F>(
+    conn: &SqliteConnection,
+    events: Vec<FusenDomainEvent>,
+    save: F,
+) -> Result<(), DomainError>
+where
+    F: FnOnce() -> Result<(), DomainError>,
+{
+    if events.is_empty() {
+        return save();
+    }
+
+    let messages = events
+        .iter()
+        .map(|event| {
+            encode(event).map(|encoded| NewOutboxModel {
+                event_type: encoded.event_type.to_string(),
+                aggregate_id: encoded.aggregate_id,
+                payload: encoded.payload,
+                occurred_at: encoded.occurred_at.naive_utc(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    transaction(conn, || {
+        save()?;
+        diesel::insert_into(outbox_messages::table)
+            .values(&messages)
+            .execute(conn)
+            .map_err(query_error)?;
+        insert_events(conn, &events)
+    })
+}
+
+fn ids(messages: &[OutboxModel]) -> Vec<i64> {
+    messages.iter().map(|message| message.id).collect()
+}
+
+fn to_message(message: OutboxModel) -> OutboxMessage {
+    OutboxMessage::new(
+        EventId::new(message.id as u64),
+        message.event_type,
+        message.aggregate_id,
+        message.payload,
+        Timestamp::new(Utc.from_utc_datetime(&message.occurred_at)),
+    )
+}
+
+#[async_trait]
+impl Repository for OutboxRepository {
+    async fn claim(
+        &self,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<OutboxMessage>, DomainError> {
+        let _timer = query_timer("outbox", "claim");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.claim_with_conn(conn, limit, lease))
+            .await
+    }
+
+    async fn acknowledge(&self, ids: Vec<EventId>) -> Result<(), DomainError> {
+        let _timer = query_timer("outbox", "acknowledge");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.acknowledge_with_conn(conn, ids))
+            .await
+    }
+
+    async fn release(&self, ids: Vec<EventId>) -> Result<(), DomainError> {
+        let _timer = query_timer("outbox", "release");
+        let repository = self.clone();
+        self.connections
+            .run(move |conn| repository.release_with_conn(conn, ids))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId};
+    use ulid::Ulid;
+
+    fn new_fusen() -> Fusen {
+        let mut fusen = FusenBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id(Ulid::new().to_string().parse::<Id<Board>>().unwrap())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let now = "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap();
+        fusen.record_created(now);
+        fusen.record_deleted(now);
+        fusen
+    }
+
+    #[tokio::test]
+    async fn test_outbox_repository() {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+        let repository = OutboxRepository::new(connections.clone());
+
+        // :memory: のプールはコネクションがひとつなので、リポジトリを呼び出す前に返す
+        {
+            let conn = connections.pool().get().unwrap();
+            let mut rejected = new_fusen();
+            assert!(save_with_outbox(&conn, rejected.take_events(), || Err(
+                DomainError::Conflict("conflict".to_string())
+            ))
+            .is_err());
+
+            let mut fusen = new_fusen();
+            save_with_outbox(&conn, fusen.take_events(), || Ok(())).unwrap();
+        }
+
+        let claimed = repository.claim(10, Duration::from_secs(30)).await.unwrap();
+        assert_eq!(
+            claimed
+                .iter()
+                .map(|message| message.event_type().as_str())
+                .collect::<Vec<_>>(),
+            vec!["FusenCreated", "FusenDeleted"]
+        );
+        assert_eq!(
+            claimed[0].occurred_at(),
+            &"2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap()
+        );
+
+        // lease の間は取り出せない
+        let lease = Duration::from_secs(30);
+        assert!(repository.claim(10, lease).await.unwrap().is_empty());
+
+        let ids = claimed.iter().map(|m| *m.id()).collect::<Vec<_>>();
+        repository.release(ids.clone()).await.unwrap();
+        assert_eq!(repository.claim(1, lease).await.unwrap(), claimed[..1]);
+
+        repository.acknowledge(ids).await.unwrap();
+        assert!(repository
+            .claim(10, Duration::ZERO)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
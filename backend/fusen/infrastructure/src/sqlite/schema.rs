@@ -0,0 +1,76 @@
+table! {
+    attachments (id) {
+        id -> Text,
+        fusen_id -> Text,
+        file_name -> Text,
+        content_type -> Text,
+        size -> BigInt,
+        checksum -> Text,
+        create_at -> Timestamp,
+    }
+}
+
+table! {
+    boards (id) {
+        id -> Text,
+        owner_id -> Text,
+        name -> Text,
+        member_ids -> Text,
+        create_at -> Timestamp,
+        update_at -> Timestamp,
+    }
+}
+
+table! {
+    fusen_events (id) {
+        id -> BigInt,
+        kind -> Text,
+        fusen_id -> Text,
+        title -> Text,
+        note -> Text,
+        create_at -> Timestamp,
+        update_at -> Timestamp,
+        occurred_at -> Timestamp,
+        owner_id -> Text,
+        board_id -> Text,
+        x -> Integer,
+        y -> Integer,
+        width -> Integer,
+        height -> Integer,
+        color -> Text,
+        z_index -> Integer,
+    }
+}
+
+table! {
+    fusens (id) {
+        id -> Text,
+        title -> Text,
+        note -> Text,
+        create_at -> Timestamp,
+        update_at -> Timestamp,
+        owner_id -> Text,
+        board_id -> Text,
+        x -> Integer,
+        y -> Integer,
+        width -> Integer,
+        height -> Integer,
+        color -> Text,
+        z_index -> Integer,
+    }
+}
+
+table! {
+    outbox_messages (id) {
+        id -> BigInt,
+        event_type -> Text,
+        aggregate_id -> Text,
+        payload -> Text,
+        occurred_at -> Timestamp,
+        locked_until -> Nullable<Timestamp>,
+    }
+}
+
+joinable!(fusens -> boards (board_id));
+
+allow_tables_to_appear_in_same_query!(attachments, boards, fusen_events, fusens, outbox_messages,);
@@ -0,0 +1,353 @@
+use crate::metrics::query_timer;
+use crate::postgres::error::query_error;
+use crate::sqlite::{DbPool, FusenRepository};
+use async_trait::async_trait;
+use diesel::connection::TransactionManager;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::{Connection, SqliteConnection};
+use domain::entity::Fusen;
+use domain::error::DomainError;
+use domain::repository::UnitOfWork as Repository;
+use domain::repository::*;
+use domain::vo::Id;
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::runtime::Handle;
+use tokio::sync::OwnedSemaphorePermit;
+
+#[derive(Clone)]
+pub struct UnitOfWork {
+    connections: DbPool,
+}
+
+impl UnitOfWork {
+    pub fn new(connections: DbPool) -> Self {
+        Self { connections }
+    }
+}
+
+#[async_trait]
+impl Repository for UnitOfWork {
+    type Repositories = Transaction;
+
+    async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+            + Send
+            + 'static,
+    {
+        let (permit, conn) = self.connections.dedicated().await?;
+        let transaction =
+            Transaction::begin(conn, permit, FusenRepository::new(self.connections.clone()))
+                .await?;
+
+        match work(&transaction).await {
+            Ok(value) => transaction.commit().await.map(|_| value),
+            Err(e) => transaction.rollback().await.and(Err(e)),
+        }
+    }
+}
+
+// ひとつのコネクションで開始したトランザクションの中で操作するリポジトリ。
+// work は runtime 上で実行し、クエリだけを blocking スレッドで実行する
+pub struct Transaction {
+    // Diesel のコネクションは Sync ではないため Mutex で包む
+    conn: Arc<Mutex<PooledConnection<ConnectionManager<SqliteConnection>>>>,
+    // トランザクションを終えてコネクションを返すまで保持する
+    permit: Option<OwnedSemaphorePermit>,
+    fusen_repository: FusenRepository,
+}
+
+impl Transaction {
+    async fn begin(
+        conn: PooledConnection<ConnectionManager<SqliteConnection>>,
+        permit: OwnedSemaphorePermit,
+        fusen_repository: FusenRepository,
+    ) -> Result<Self, DomainError> {
+        let transaction = Self {
+            conn: Arc::new(Mutex::new(conn)),
+            permit: Some(permit),
+            fusen_repository,
+        };
+        // 読んでから書き戻すので、最初から書き込みのロックを取って他の書き込みを待たせる
+        transaction
+            .execute(|_, conn| {
+                conn.transaction_manager()
+                    .begin_transaction_sql(conn, "BEGIN IMMEDIATE")
+                    .map_err(query_error)
+            })
+            .await?;
+
+        Ok(transaction)
+    }
+
+    async fn commit(&self) -> Result<(), DomainError> {
+        self.execute(|_, conn| {
+            conn.transaction_manager()
+                .commit_transaction(conn)
+                .map_err(query_error)
+        })
+        .await
+    }
+
+    async fn rollback(&self) -> Result<(), DomainError> {
+        self.execute(|_, conn| {
+            conn.transaction_manager()
+                .rollback_transaction(conn)
+                .map_err(query_error)
+        })
+        .await
+    }
+
+    // Diesel は同期 API なので tokio の worker を塞がないよう blocking スレッドで実行する
+    async fn execute<R, F>(&self, f: F) -> Result<R, DomainError>
+    where
+        F: FnOnce(&FusenRepository, &SqliteConnection) -> Result<R, DomainError> + Send + 'static,
+        R: Send + 'static,
+    {
+        let conn = self.conn.clone();
+        let repository = self.fusen_repository.clone();
+        tokio::task::spawn_blocking(move || f(&repository, &lock(&conn)))
+            .await
+            .map_err(anyhow::Error::from)?
+    }
+}
+
+// work が panic した場合や途中で破棄された場合も、トランザクションを開いたままプールに返さない
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        // commit か rollback を終えていればそのまま返す
+        if let Ok(conn) = self.conn.try_lock() {
+            if depth(&conn) == 0 {
+                return;
+            }
+        }
+
+        let conn = self.conn.clone();
+        let permit = self.permit.take();
+        let rollback = move || {
+            {
+                let conn = lock(&conn);
+                if depth(&conn) > 0 {
+                    let _ = conn.transaction_manager().rollback_transaction(&**conn);
+                }
+            }
+            // コネクションを返してから permit を手放す
+            drop(conn);
+            drop(permit);
+        };
+        match Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(rollback);
+            }
+            Err(_) => rollback(),
+        }
+    }
+}
+
+fn lock(
+    conn: &Mutex<PooledConnection<ConnectionManager<SqliteConnection>>>,
+) -> MutexGuard<'_, PooledConnection<ConnectionManager<SqliteConnection>>> {
+    // クエリの実行中に panic しても、ロールバックできるようコネクションは使い続ける
+    conn.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn depth(conn: &SqliteConnection) -> u32 {
+    TransactionManager::<SqliteConnection>::get_transaction_depth(conn.transaction_manager())
+}
+
+#[async_trait]
+impl CreateRepository<Fusen> for Transaction {
+    async fn create(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "create");
+        self.execute(move |repository, conn| repository.create_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl CreateAllRepository<Fusen> for Transaction {
+    async fn create_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let _timer = query_timer("fusen", "create_all");
+        self.execute(move |repository, conn| {
+            repository.create_all_with_conn(conn, aggregates, mode)
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl GetRepository<Fusen> for Transaction {
+    async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+        let _timer = query_timer("fusen", "get");
+        self.execute(move |repository, conn| repository.get_with_conn(conn, id))
+            .await
+    }
+}
+
+#[async_trait]
+impl GetAllRepository<Fusen> for Transaction {
+    async fn get_all(&self, ids: Vec<Id<Fusen>>) -> Result<Vec<Fusen>, DomainError> {
+        let _timer = query_timer("fusen", "get_all");
+        self.execute(move |repository, conn| repository.get_all_with_conn(conn, ids))
+            .await
+    }
+}
+
+#[async_trait]
+impl ListRepository<Fusen> for Transaction {
+    async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+        let _timer = query_timer("fusen", "list");
+        self.execute(move |repository, conn| repository.list_with_conn(conn, query))
+            .await
+    }
+}
+
+#[async_trait]
+impl UpdateRepository<Fusen> for Transaction {
+    async fn update(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "update");
+        self.execute(move |repository, conn| repository.update_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl MoveAllRepository<Fusen> for Transaction {
+    async fn move_all(&self, aggregates: Vec<Fusen>) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "move_all");
+        self.execute(move |repository, conn| repository.move_all_with_conn(conn, aggregates))
+            .await
+    }
+}
+
+#[async_trait]
+impl DeleteRepository<Fusen> for Transaction {
+    async fn delete(&self, aggregate: Fusen) -> Result<(), DomainError> {
+        let _timer = query_timer("fusen", "delete");
+        self.execute(move |repository, conn| repository.delete_with_conn(conn, aggregate))
+            .await
+    }
+}
+
+#[async_trait]
+impl DeleteAllRepository<Fusen> for Transaction {
+    async fn delete_all(
+        &self,
+        aggregates: Vec<Fusen>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+        let _timer = query_timer("fusen", "delete_all");
+        self.execute(move |repository, conn| {
+            repository.delete_all_with_conn(conn, aggregates, mode)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::models::NewBoardModel;
+    use crate::sqlite::schema::boards;
+    use diesel::prelude::*;
+    use domain::entity::{Board, FusenBuilder};
+    use domain::vo::{FusenNote, FusenTitle, OwnerId, Timestamp};
+    use futures::FutureExt;
+    use ulid::Ulid;
+
+    fn new_fusen(board_id: &Id<Board>) -> Fusen {
+        FusenBuilder::default()
+            .id(Ulid::new().to_string().parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id(board_id.clone())
+            .title("title".parse::<FusenTitle>().unwrap())
+            .note("note".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unit_of_work() {
+        let connections = DbPool::new(":memory:");
+        connections.init().unwrap();
+
+        let board_id = Ulid::new().to_string();
+        diesel::insert_into(boards::table)
+            .values(&NewBoardModel {
+                id: board_id.clone(),
+                owner_id: "owner".to_string(),
+                name: "board".to_string(),
+                member_ids: "[]".to_string(),
+                create_at: "2021-10-17T07:08:37".parse().unwrap(),
+                update_at: "2021-10-17T07:08:37".parse().unwrap(),
+            })
+            .execute(&connections.pool().get().unwrap())
+            .unwrap();
+        let board_id = board_id.parse::<Id<Board>>().unwrap();
+        let committed = new_fusen(&board_id);
+        let rolled_back = new_fusen(&board_id);
+        let panicked = new_fusen(&board_id);
+
+        let sut = UnitOfWork::new(connections.clone());
+        let entity = committed.clone();
+        let fusen = sut
+            .run(move |repositories| {
+                async move {
+                    repositories.create(entity.clone()).await?;
+                    repositories.get(entity.id().clone()).await
+                }
+                .boxed()
+            })
+            .await
+            .unwrap();
+        assert_eq!(fusen, committed);
+
+        // 後続の操作が失敗した場合は先に作成したものも取り消す
+        let entity = rolled_back.clone();
+        let result = sut
+            .run(move |repositories| {
+                async move {
+                    repositories.create(entity.clone()).await?;
+                    repositories.create(entity).await
+                }
+                .boxed()
+            })
+            .await;
+        assert!(matches!(result, Err(DomainError::Conflict(_))));
+
+        // work が panic した場合もロールバックしてからコネクションを返す
+        let entity = panicked.clone();
+        let unit_of_work = sut.clone();
+        let task = tokio::spawn(async move {
+            unit_of_work
+                .run::<(), _>(move |repositories| {
+                    async move {
+                        repositories.create(entity).await?;
+                        panic!("work panicked")
+                    }
+                    .boxed()
+                })
+                .await
+        });
+        assert!(task.await.unwrap_err().is_panic());
+
+        let repository = FusenRepository::new(connections);
+        assert!(repository.get(committed.id().clone()).await.is_ok());
+        assert!(matches!(
+            repository.get(rolled_back.id().clone()).await,
+            Err(DomainError::NotFound(_))
+        ));
+        assert!(matches!(
+            repository.get(panicked.id().clone()).await,
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
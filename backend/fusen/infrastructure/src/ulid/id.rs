@@ -1,4 +1,4 @@
-use anyhow::{Error, Result};
+use domain::error::DomainError;
 use domain::repository::IdRepository as Repository;
 use domain::vo::Id;
 use ulid::Ulid;
@@ -7,7 +7,7 @@ use ulid::Ulid;
 pub struct IdRepository {}
 
 impl Repository for IdRepository {
-    fn generate<T>(&self) -> Result<Id<T>, Error> {
+    fn generate<T>(&self) -> Result<Id<T>, DomainError> {
         Ulid::new().to_string().as_str().parse::<Id<T>>()
     }
 }
@@ -1,5 +1,20 @@
+use std::env;
+use std::path::PathBuf;
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::compile_protos("../../../api/peta/fusen/v1/fusen.proto")?;
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    // server reflection 用に import 先も含めた descriptor を出力する
+    tonic_build::configure()
+        .file_descriptor_set_path(out_dir.join("fusen_descriptor.bin"))
+        .compile(
+            &[
+                "../../../api/peta/fusen/v1/fusen.proto",
+                "../../../api/peta/fusen/v1/board.proto",
+                "../../../api/peta/fusen/v1/attachment.proto",
+            ],
+            &["../../../api/peta/fusen/v1"],
+        )?;
 
     Ok(())
 }
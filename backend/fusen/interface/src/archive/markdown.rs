@@ -0,0 +1,139 @@
+use domain::entity::Fusen;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::io::Read;
+use std::path::Path;
+use tar::{Archive, Header};
+use usecase::port::{ImportFusenFailure, ImportFusenRecord};
+
+const BLOCK_SIZE: usize = 512;
+const FRONT_MATTER_DELIMITER: &str = "---\n";
+
+#[derive(Serialize, Deserialize)]
+struct FrontMatter {
+    title: String,
+    create_time: Option<String>,
+    update_time: Option<String>,
+}
+
+pub(super) fn encode(fusen: &Fusen) -> Vec<u8> {
+    let body = to_markdown(fusen).into_bytes();
+    let mut header = Header::new_ustar();
+    // ULID はパスとして常に有効
+    header
+        .set_path(format!("{}.md", fusen.id().to_string()))
+        .unwrap();
+    header.set_size(body.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(fusen.update_at().value().timestamp().max(0) as u64);
+    header.set_cksum();
+
+    let mut entry = header.as_bytes().to_vec();
+    entry.extend_from_slice(&body);
+    // tar のエントリは 512 バイト単位に揃える
+    entry.resize(
+        entry.len() + (BLOCK_SIZE - body.len() % BLOCK_SIZE) % BLOCK_SIZE,
+        0,
+    );
+    entry
+}
+
+// tar の終端は空ブロック 2 つ
+pub(super) fn finish() -> Vec<u8> {
+    vec![0; BLOCK_SIZE * 2]
+}
+
+pub(super) fn decode(data: &[u8]) -> Vec<Result<ImportFusenRecord, ImportFusenFailure>> {
+    let mut archive = Archive::new(data);
+    let entries = match archive.entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            return vec![Err(ImportFusenFailure::new(
+                0,
+                "".to_string(),
+                e.to_string(),
+            ))]
+        }
+    };
+
+    let mut records = Vec::new();
+    for entry in entries {
+        let index = records.len();
+        let mut entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                // 壊れたヘッダ以降は読み進められない
+                records.push(Err(ImportFusenFailure::new(
+                    index,
+                    "".to_string(),
+                    e.to_string(),
+                )));
+                break;
+            }
+        };
+        // ディレクトリや .md 以外のファイルは対象外
+        let id = match entry.path() {
+            Ok(path) if entry.header().entry_type().is_file() && is_markdown(&path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            _ => continue,
+        };
+
+        let mut text = String::new();
+        records.push(
+            entry
+                .read_to_string(&mut text)
+                .map_err(|e| e.to_string())
+                .and_then(|_| from_markdown(id.clone(), &text))
+                .map_err(|reason| ImportFusenFailure::new(index, id, reason)),
+        );
+    }
+    records
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension() == Some(OsStr::new("md"))
+}
+
+fn to_markdown(fusen: &Fusen) -> String {
+    let front_matter = FrontMatter {
+        title: fusen.title().to_string(),
+        create_time: Some(fusen.create_at().to_string()),
+        update_time: Some(fusen.update_at().to_string()),
+    };
+    // 文字列のみなのでシリアライズは失敗しない
+    let yaml = serde_yaml::to_string(&front_matter).unwrap();
+    format!(
+        "{}{}\n{}{}",
+        FRONT_MATTER_DELIMITER,
+        // serde_yaml は先頭に区切りを付けて出力する
+        yaml.trim_start_matches(FRONT_MATTER_DELIMITER).trim_end(),
+        FRONT_MATTER_DELIMITER,
+        fusen.note().to_string()
+    )
+}
+
+fn from_markdown(id: String, text: &str) -> Result<ImportFusenRecord, String> {
+    let (yaml, note) = text
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(|rest| {
+            rest.find(&format!("\n{}", FRONT_MATTER_DELIMITER))
+                .map(|end| {
+                    (
+                        &rest[..end],
+                        &rest[end + 1 + FRONT_MATTER_DELIMITER.len()..],
+                    )
+                })
+        })
+        .ok_or_else(|| "missing front-matter".to_string())?;
+    let front_matter = serde_yaml::from_str::<FrontMatter>(yaml).map_err(|e| e.to_string())?;
+
+    Ok(ImportFusenRecord::new(
+        id,
+        front_matter.title,
+        note.to_string(),
+        front_matter.create_time,
+        front_matter.update_time,
+    ))
+}
@@ -0,0 +1,201 @@
+mod markdown;
+mod ndjson;
+
+use anyhow::anyhow;
+use domain::entity::Fusen;
+use std::str::FromStr;
+use usecase::port::{ImportFusenFailure, ImportFusenRecord};
+
+// Export/Import でやり取りするファイルの形式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    // 1 行に 1 件の JSON
+    Ndjson,
+    // <ulid>.md を並べた tar。タイトルと日時は front-matter に書く
+    MarkdownTar,
+}
+
+impl ArchiveFormat {
+    // 1 件分を書き出す。fusen ごとの出力を連結して finish を続けると archive になる
+    pub fn encode(&self, fusen: &Fusen) -> Vec<u8> {
+        match self {
+            ArchiveFormat::Ndjson => ndjson::encode(fusen),
+            ArchiveFormat::MarkdownTar => markdown::encode(fusen),
+        }
+    }
+
+    pub fn finish(&self) -> Vec<u8> {
+        match self {
+            ArchiveFormat::Ndjson => Vec::new(),
+            ArchiveFormat::MarkdownTar => markdown::finish(),
+        }
+    }
+
+    // 読み取れなかったレコードも位置を保ったまま失敗として返す
+    pub fn decode(&self, data: &[u8]) -> Vec<Result<ImportFusenRecord, ImportFusenFailure>> {
+        match self {
+            ArchiveFormat::Ndjson => ndjson::decode(data),
+            ArchiveFormat::MarkdownTar => markdown::decode(data),
+        }
+    }
+
+    // usecase に渡すレコードと、読み取れなかったレコードに分ける
+    pub fn decode_records(&self, data: &[u8]) -> (Vec<ImportFusenRecord>, DecodeFailures) {
+        let mut records = Vec::new();
+        let mut failures = DecodeFailures::default();
+        for (index, record) in self.decode(data).into_iter().enumerate() {
+            match record {
+                Ok(record) => {
+                    records.push(record);
+                    failures.indexes.push(index);
+                }
+                Err(failure) => failures.failures.push(failure),
+            }
+        }
+        (records, failures)
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct DecodeFailures {
+    failures: Vec<ImportFusenFailure>,
+    // usecase に渡したレコードの archive 内の位置
+    indexes: Vec<usize>,
+}
+
+impl DecodeFailures {
+    // usecase の失敗の位置を archive 内の位置に戻して合わせる
+    pub fn merge(mut self, failures: Vec<ImportFusenFailure>) -> Vec<ImportFusenFailure> {
+        self.failures.extend(failures.into_iter().map(|failure| {
+            ImportFusenFailure::new(self.indexes[failure.index], failure.id, failure.reason)
+        }));
+        self.failures.sort_by_key(|failure| failure.index);
+        self.failures
+    }
+}
+
+impl FromStr for ArchiveFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ndjson" => Ok(ArchiveFormat::Ndjson),
+            "markdown_tar" => Ok(ArchiveFormat::MarkdownTar),
+            _ => Err(anyhow!("invalid archive format: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{Board, FusenBuilder};
+    use domain::vo::*;
+
+    fn fusen(id: &str, note: &str) -> Fusen {
+        FusenBuilder::default()
+            .id(id.parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title("fusen title".parse::<FusenTitle>().unwrap())
+            .note(note.parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_archive_format_from_str() {
+        assert_eq!(
+            "ndjson".parse::<ArchiveFormat>().unwrap(),
+            ArchiveFormat::Ndjson
+        );
+        assert_eq!(
+            "markdown_tar".parse::<ArchiveFormat>().unwrap(),
+            ArchiveFormat::MarkdownTar
+        );
+        assert!("zip".parse::<ArchiveFormat>().is_err());
+    }
+
+    #[test]
+    fn test_archive_format_round_trip() {
+        let fusens = vec![
+            fusen("01F8MECHZX3TBDSZ7XRADM79XV", "# note\n\n- [ ] todo\n"),
+            fusen("01F8MECHZX3TBDSZ7XRADM79XW", ""),
+        ];
+        for format in [ArchiveFormat::Ndjson, ArchiveFormat::MarkdownTar] {
+            let mut data = Vec::new();
+            for fusen in &fusens {
+                data.extend(format.encode(fusen));
+            }
+            data.extend(format.finish());
+
+            let records = format
+                .decode(&data)
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(
+                records,
+                fusens
+                    .iter()
+                    .map(|fusen| ImportFusenRecord::new(
+                        fusen.id().to_string(),
+                        fusen.title().to_string(),
+                        fusen.note().to_string(),
+                        Some(fusen.create_at().to_string()),
+                        Some(fusen.update_at().to_string()),
+                    ))
+                    .collect::<Vec<_>>(),
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_archive_format_decode_err() {
+        let records = ArchiveFormat::Ndjson
+            .decode(b"{\"id\":\"01F8MECHZX3TBDSZ7XRADM79XV\",\"title\":\"title\"}\n\nbroken\n");
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0],
+            Ok(ImportFusenRecord::new(
+                "01F8MECHZX3TBDSZ7XRADM79XV".to_string(),
+                "title".to_string(),
+                "".to_string(),
+                None,
+                None,
+            ))
+        );
+        assert!(matches!(&records[1], Err(failure) if failure.index == 1));
+
+        let (records, failures) = ArchiveFormat::Ndjson.decode_records(
+            b"broken\n{\"id\":\"01F8MECHZX3TBDSZ7XRADM79XV\",\"title\":\"title\"}\n",
+        );
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            failures
+                .merge(vec![ImportFusenFailure::new(
+                    0,
+                    "01F8MECHZX3TBDSZ7XRADM79XV".to_string(),
+                    "invalid".to_string(),
+                )])
+                .iter()
+                .map(|failure| failure.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+
+        let mut data = markdown::encode(&fusen("01F8MECHZX3TBDSZ7XRADM79XV", "note"));
+        let start = data.iter().position(|b| *b == b'-').unwrap();
+        data[start] = b'x';
+        data.extend(markdown::finish());
+        let records = ArchiveFormat::MarkdownTar.decode(&data);
+        assert!(matches!(
+            &records[..],
+            [Err(failure)] if failure.id == "01F8MECHZX3TBDSZ7XRADM79XV"
+        ));
+    }
+}
@@ -0,0 +1,47 @@
+use domain::entity::Fusen;
+use serde::{Deserialize, Serialize};
+use usecase::port::{ImportFusenFailure, ImportFusenRecord};
+
+#[derive(Serialize, Deserialize)]
+struct FusenLine {
+    id: String,
+    title: String,
+    #[serde(default)]
+    note: String,
+    create_time: Option<String>,
+    update_time: Option<String>,
+}
+
+pub(super) fn encode(fusen: &Fusen) -> Vec<u8> {
+    let line = FusenLine {
+        id: fusen.id().to_string(),
+        title: fusen.title().to_string(),
+        note: fusen.note().to_string(),
+        create_time: Some(fusen.create_at().to_string()),
+        update_time: Some(fusen.update_at().to_string()),
+    };
+    // 文字列のみなのでシリアライズは失敗しない
+    let mut data = serde_json::to_vec(&line).unwrap();
+    data.push(b'\n');
+    data
+}
+
+pub(super) fn decode(data: &[u8]) -> Vec<Result<ImportFusenRecord, ImportFusenFailure>> {
+    data.split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .enumerate()
+        .map(|(index, line)| {
+            serde_json::from_slice::<FusenLine>(line)
+                .map(|line| {
+                    ImportFusenRecord::new(
+                        line.id,
+                        line.title,
+                        line.note,
+                        line.create_time,
+                        line.update_time,
+                    )
+                })
+                .map_err(|e| ImportFusenFailure::new(index, "".to_string(), e.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,397 @@
+use crate::controller::caller::caller;
+use crate::controller::controller::{to_pb_timestamp, to_status};
+use crate::peta_fusen_v1::Attachment as PBAttachment;
+use crate::peta_fusen_v1::{DownloadAttachmentRequest, DownloadAttachmentResponse};
+use crate::peta_fusen_v1::{ListAttachmentsRequest, ListAttachmentsResponse};
+use crate::peta_fusen_v1::{UploadAttachmentRequest, UploadAttachmentResponse};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::Attachment;
+use std::pin::Pin;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+use usecase::error::UsecaseError;
+use usecase::port::*;
+
+pub type UploadStream = Pin<Box<dyn Stream<Item = Result<UploadAttachmentRequest, Status>> + Send>>;
+pub type DownloadStream =
+    Pin<Box<dyn Stream<Item = Result<DownloadAttachmentResponse, Status>> + Send + Sync>>;
+
+const CHUNK_BUFFER_SIZE: usize = 16;
+
+#[async_trait]
+pub trait AttachmentController: Send + Sync {
+    async fn upload(
+        &self,
+        request: Request<UploadStream>,
+    ) -> Result<Response<UploadAttachmentResponse>, Status>;
+    async fn download(
+        &self,
+        request: Request<DownloadAttachmentRequest>,
+    ) -> Result<Response<DownloadStream>, Status>;
+    async fn list(
+        &self,
+        request: Request<ListAttachmentsRequest>,
+    ) -> Result<Response<ListAttachmentsResponse>, Status>;
+}
+
+#[derive(new)]
+pub struct DefaultAttachmentController<Upload, Download, List>
+where
+    Upload: Port<UploadAttachmentInputData, UploadAttachmentOutputData>,
+    Download: Port<DownloadAttachmentInputData, DownloadAttachmentOutputData>,
+    List: Port<ListAttachmentInputData, ListAttachmentOutputData>,
+{
+    upload_attachment: Upload,
+    download_attachment: Download,
+    list_attachment: List,
+}
+
+#[async_trait]
+impl<Upload, Download, List> AttachmentController
+    for DefaultAttachmentController<Upload, Download, List>
+where
+    Upload: Port<UploadAttachmentInputData, UploadAttachmentOutputData>,
+    Download: Port<DownloadAttachmentInputData, DownloadAttachmentOutputData>,
+    List: Port<ListAttachmentInputData, ListAttachmentOutputData>,
+{
+    async fn upload(
+        &self,
+        request: Request<UploadStream>,
+    ) -> Result<Response<UploadAttachmentResponse>, Status> {
+        let owner_id = caller(&request)?.subject.to_string();
+        let mut stream = request.into_inner();
+        let first = stream
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("empty upload request"))??;
+
+        // 内容はメモリに溜めず、ユースケースが読み込む速さに合わせて受け取る
+        let (sender, receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+        tokio::spawn(async move {
+            if sender.send(Ok(first.chunk)).await.is_err() {
+                return;
+            }
+            while let Some(message) = stream.next().await {
+                let chunk = message.map(|message| message.chunk).map_err(|status| {
+                    UsecaseError::Unavailable(format!("upload interrupted: {}", status.message()))
+                });
+                let failed = chunk.is_err();
+                // ユースケースが中断したら受け取りをやめる
+                if sender.send(chunk).await.is_err() || failed {
+                    return;
+                }
+            }
+        });
+        let input = UploadAttachmentInputData::new(
+            owner_id,
+            first.fusen_id,
+            first.file_name,
+            first.content_type,
+            receiver,
+        );
+
+        match self.upload_attachment.handle(input).await {
+            Ok(output) => Ok(Response::new(UploadAttachmentResponse {
+                attachment: Some(to_pb_attachment(&output.attachment)),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn download(
+        &self,
+        request: Request<DownloadAttachmentRequest>,
+    ) -> Result<Response<DownloadStream>, Status> {
+        let input = DownloadAttachmentInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+        );
+
+        match self.download_attachment.handle(input).await {
+            Ok(output) => {
+                let first = DownloadAttachmentResponse {
+                    attachment: Some(to_pb_attachment(&output.attachment)),
+                    chunk: Vec::new(),
+                };
+                let stream = tokio_stream::iter(Some(Ok(first))).chain(
+                    ReceiverStream::new(output.chunks).map(|chunk| match chunk {
+                        Ok(chunk) => Ok(DownloadAttachmentResponse {
+                            attachment: None,
+                            chunk,
+                        }),
+                        Err(e) => Err(to_status(e)),
+                    }),
+                );
+                Ok(Response::new(Box::pin(stream) as DownloadStream))
+            }
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn list(
+        &self,
+        request: Request<ListAttachmentsRequest>,
+    ) -> Result<Response<ListAttachmentsResponse>, Status> {
+        let input = ListAttachmentInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().fusen_id.to_string(),
+        );
+
+        match self.list_attachment.handle(input).await {
+            Ok(output) => Ok(Response::new(ListAttachmentsResponse {
+                attachments: output.attachments.iter().map(to_pb_attachment).collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+}
+
+fn to_pb_attachment(attachment: &Attachment) -> PBAttachment {
+    PBAttachment {
+        id: attachment.id().to_string(),
+        fusen_id: attachment.fusen_id().to_string(),
+        file_name: attachment.file_name().to_string(),
+        content_type: attachment.content_type().to_string(),
+        size: *attachment.size() as i64,
+        checksum_sha256: attachment.checksum().to_string(),
+        create_time: Some(to_pb_timestamp(attachment.create_at())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::Caller;
+    use domain::entity::{AttachmentBuilder, Fusen};
+    use domain::vo::{Checksum, ContentType, FileName, Id, Timestamp};
+    use prost_types::Timestamp as PBTimestamp;
+    use std::sync::{Arc, Mutex};
+    use usecase::port::MockPort;
+
+    fn authorized<T>(message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request
+            .extensions_mut()
+            .insert(Caller::new("owner".to_string()));
+        request
+    }
+
+    fn new_attachment() -> Attachment {
+        AttachmentBuilder::default()
+            .id("01FNATTACHXKQ7B1GZ1Z0K0T8R".parse::<Id<Attachment>>().unwrap())
+            .fusen_id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .file_name("screenshot.png".parse::<FileName>().unwrap())
+            .content_type("image/png".parse::<ContentType>().unwrap())
+            .size(3u64)
+            .checksum(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                    .parse::<Checksum>()
+                    .unwrap(),
+            )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn new_pb_attachment() -> PBAttachment {
+        PBAttachment {
+            id: "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            fusen_id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            file_name: "screenshot.png".to_string(),
+            content_type: "image/png".to_string(),
+            size: 3,
+            checksum_sha256: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                .to_string(),
+            create_time: Some(PBTimestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+        }
+    }
+
+    type Chunk = Result<Vec<u8>, String>;
+
+    // 受け取った内容を記録する
+    #[derive(Clone, Default)]
+    struct RecordingUpload {
+        received: Arc<Mutex<Vec<Chunk>>>,
+    }
+
+    #[async_trait]
+    impl Port<UploadAttachmentInputData, UploadAttachmentOutputData> for RecordingUpload {
+        async fn handle(
+            &self,
+            mut input: UploadAttachmentInputData,
+        ) -> Result<UploadAttachmentOutputData, UsecaseError> {
+            assert_eq!(input.fusen_id, "01F8MECHZX3TBDSZ7XRADM79XE");
+            assert_eq!(input.content_type, "image/png");
+            while let Some(chunk) = input.chunks.recv().await {
+                let failed = chunk.is_err();
+                self.received
+                    .lock()
+                    .unwrap()
+                    .push(chunk.map_err(|e| e.to_string()));
+                if failed {
+                    return Err(UsecaseError::Unavailable("interrupted".to_string()));
+                }
+            }
+            Ok(UploadAttachmentOutputData::new(new_attachment()))
+        }
+    }
+
+    fn upload_request(chunk: &[u8]) -> UploadAttachmentRequest {
+        UploadAttachmentRequest {
+            fusen_id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            file_name: "screenshot.png".to_string(),
+            content_type: "image/png".to_string(),
+            chunk: chunk.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_attachment_controller_ok() {
+        let upload = RecordingUpload::default();
+        let mut download =
+            MockPort::<DownloadAttachmentInputData, DownloadAttachmentOutputData>::new();
+        let mut list = MockPort::<ListAttachmentInputData, ListAttachmentOutputData>::new();
+        download
+            .expect_handle()
+            .withf(|input| input.owner_id == "owner" && input.id == "01FNATTACHXKQ7B1GZ1Z0K0T8R")
+            .returning(|_| {
+                let (sender, receiver) = mpsc::channel(2);
+                sender.try_send(Ok(b"ab".to_vec())).unwrap();
+                sender.try_send(Ok(b"c".to_vec())).unwrap();
+                Ok(DownloadAttachmentOutputData::new(new_attachment(), receiver))
+            });
+        list.expect_handle()
+            .withf(|input| input.fusen_id == "01F8MECHZX3TBDSZ7XRADM79XE")
+            .returning(|_| Ok(ListAttachmentOutputData::new(vec![new_attachment()])));
+        let sut = DefaultAttachmentController::new(upload.clone(), download, list);
+
+        let stream = tokio_stream::iter(vec![
+            Ok(upload_request(b"ab")),
+            Ok(UploadAttachmentRequest {
+                chunk: b"c".to_vec(),
+                ..Default::default()
+            }),
+        ]);
+        assert_eq!(
+            sut.upload(authorized(Box::pin(stream) as UploadStream))
+                .await
+                .unwrap()
+                .into_inner(),
+            UploadAttachmentResponse {
+                attachment: Some(new_pb_attachment()),
+            }
+        );
+        assert_eq!(
+            upload.received.lock().unwrap().clone(),
+            vec![Ok(b"ab".to_vec()), Ok(b"c".to_vec())]
+        );
+
+        let responses = sut
+            .download(authorized(DownloadAttachmentRequest {
+                id: "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            responses,
+            vec![
+                DownloadAttachmentResponse {
+                    attachment: Some(new_pb_attachment()),
+                    chunk: vec![],
+                },
+                DownloadAttachmentResponse {
+                    attachment: None,
+                    chunk: b"ab".to_vec(),
+                },
+                DownloadAttachmentResponse {
+                    attachment: None,
+                    chunk: b"c".to_vec(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            sut.list(authorized(ListAttachmentsRequest {
+                fusen_id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner(),
+            ListAttachmentsResponse {
+                attachments: vec![new_pb_attachment()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_attachment_controller_err() {
+        let upload = RecordingUpload::default();
+        let mut download =
+            MockPort::<DownloadAttachmentInputData, DownloadAttachmentOutputData>::new();
+        let list = MockPort::<ListAttachmentInputData, ListAttachmentOutputData>::new();
+        download
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::NotFound("attachment".to_string())));
+        let sut = DefaultAttachmentController::new(upload.clone(), download, list);
+
+        let empty = tokio_stream::iter(Vec::<Result<UploadAttachmentRequest, Status>>::new());
+        assert_eq!(
+            sut.upload(authorized(Box::pin(empty) as UploadStream))
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::InvalidArgument
+        );
+
+        // クライアントのストリームが途中で失敗した場合はユースケースに伝える
+        let interrupted = tokio_stream::iter(vec![
+            Ok(upload_request(b"ab")),
+            Err(Status::cancelled("cancelled")),
+        ]);
+        assert_eq!(
+            sut.upload(authorized(Box::pin(interrupted) as UploadStream))
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::Unavailable
+        );
+        assert_eq!(
+            upload.received.lock().unwrap().last(),
+            Some(&Err(
+                "unavailable: upload interrupted: cancelled".to_string()
+            ))
+        );
+
+        assert_eq!(
+            sut.download(authorized(DownloadAttachmentRequest {
+                id: "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            }))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::NotFound
+        );
+        assert_eq!(
+            sut.list(Request::new(ListAttachmentsRequest {
+                fusen_id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            }))
+            .await
+            .unwrap_err()
+            .code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+}
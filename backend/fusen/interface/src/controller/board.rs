@@ -0,0 +1,287 @@
+use crate::controller::caller::caller;
+use crate::controller::controller::{to_pb_timestamp, to_status};
+use crate::peta_fusen_v1::Board as PBBoard;
+use crate::peta_fusen_v1::{CreateBoardRequest, CreateBoardResponse};
+use crate::peta_fusen_v1::{DeleteBoardRequest, DeleteBoardResponse};
+use crate::peta_fusen_v1::{ListBoardsRequest, ListBoardsResponse};
+use crate::peta_fusen_v1::{RenameBoardRequest, RenameBoardResponse};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::Board;
+use tonic::{Request, Response, Status};
+use usecase::port::*;
+
+#[async_trait]
+pub trait BoardController: Send + Sync {
+    async fn create(
+        &self,
+        request: Request<CreateBoardRequest>,
+    ) -> Result<Response<CreateBoardResponse>, Status>;
+    async fn list(
+        &self,
+        request: Request<ListBoardsRequest>,
+    ) -> Result<Response<ListBoardsResponse>, Status>;
+    async fn rename(
+        &self,
+        request: Request<RenameBoardRequest>,
+    ) -> Result<Response<RenameBoardResponse>, Status>;
+    async fn delete(
+        &self,
+        request: Request<DeleteBoardRequest>,
+    ) -> Result<Response<DeleteBoardResponse>, Status>;
+}
+
+#[derive(new)]
+pub struct DefaultBoardController<Create, List, Rename, Delete>
+where
+    Create: Port<CreateBoardInputData, CreateBoardOutputData>,
+    List: Port<ListBoardInputData, ListBoardOutputData>,
+    Rename: Port<RenameBoardInputData, RenameBoardOutputData>,
+    Delete: Port<DeleteBoardInputData, DeleteBoardOutputData>,
+{
+    create_board: Create,
+    list_board: List,
+    rename_board: Rename,
+    delete_board: Delete,
+}
+
+#[async_trait]
+impl<Create, List, Rename, Delete> BoardController
+    for DefaultBoardController<Create, List, Rename, Delete>
+where
+    Create: Port<CreateBoardInputData, CreateBoardOutputData>,
+    List: Port<ListBoardInputData, ListBoardOutputData>,
+    Rename: Port<RenameBoardInputData, RenameBoardOutputData>,
+    Delete: Port<DeleteBoardInputData, DeleteBoardOutputData>,
+{
+    async fn create(
+        &self,
+        request: Request<CreateBoardRequest>,
+    ) -> Result<Response<CreateBoardResponse>, Status> {
+        let input = CreateBoardInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().name.to_string(),
+            request.get_ref().member_ids.clone(),
+        );
+
+        match self.create_board.handle(input).await {
+            Ok(output) => Ok(Response::new(CreateBoardResponse {
+                board: Some(to_pb_board(&output.board)),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn list(
+        &self,
+        request: Request<ListBoardsRequest>,
+    ) -> Result<Response<ListBoardsResponse>, Status> {
+        let input = ListBoardInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().page_size,
+            request.get_ref().page_token.to_string(),
+        );
+
+        match self.list_board.handle(input).await {
+            Ok(output) => Ok(Response::new(ListBoardsResponse {
+                boards: output.boards.iter().map(to_pb_board).collect(),
+                next_page_token: output.next_page_token,
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn rename(
+        &self,
+        request: Request<RenameBoardRequest>,
+    ) -> Result<Response<RenameBoardResponse>, Status> {
+        let input = RenameBoardInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+            request.get_ref().name.to_string(),
+        );
+
+        match self.rename_board.handle(input).await {
+            Ok(output) => Ok(Response::new(RenameBoardResponse {
+                board: Some(to_pb_board(&output.board)),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn delete(
+        &self,
+        request: Request<DeleteBoardRequest>,
+    ) -> Result<Response<DeleteBoardResponse>, Status> {
+        let input = DeleteBoardInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+        );
+
+        match self.delete_board.handle(input).await {
+            Ok(_) => Ok(Response::new(DeleteBoardResponse {})),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+}
+
+fn to_pb_board(board: &Board) -> PBBoard {
+    PBBoard {
+        id: board.id().to_string(),
+        owner_id: board.owner_id().to_string(),
+        name: board.name().to_string(),
+        member_ids: board.member_ids().iter().map(|m| m.to_string()).collect(),
+        create_time: Some(to_pb_timestamp(board.create_at())),
+        update_time: Some(to_pb_timestamp(board.update_at())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::Caller;
+    use domain::entity::BoardBuilder;
+    use domain::vo::{BoardName, Id, OwnerId, Timestamp};
+    use prost_types::Timestamp as PBTimestamp;
+    use usecase::error::UsecaseError;
+    use usecase::port::MockPort;
+
+    fn authorized<T>(message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request
+            .extensions_mut()
+            .insert(Caller::new("owner".to_string()));
+        request
+    }
+
+    fn new_board() -> Board {
+        BoardBuilder::default()
+            .id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn new_pb_board() -> PBBoard {
+        PBBoard {
+            id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            owner_id: "owner".to_string(),
+            name: "board".to_string(),
+            member_ids: vec!["member".to_string()],
+            create_time: Some(PBTimestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+            update_time: Some(PBTimestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_board_controller_ok() {
+        let mut create = MockPort::<CreateBoardInputData, CreateBoardOutputData>::new();
+        let mut list = MockPort::<ListBoardInputData, ListBoardOutputData>::new();
+        let mut rename = MockPort::<RenameBoardInputData, RenameBoardOutputData>::new();
+        let mut delete = MockPort::<DeleteBoardInputData, DeleteBoardOutputData>::new();
+        create
+            .expect_handle()
+            .withf(|input| input.owner_id == "owner" && input.member_ids == vec!["member"])
+            .returning(|_| Ok(CreateBoardOutputData::new(new_board())));
+        list.expect_handle().returning(|_| {
+            Ok(ListBoardOutputData::new(
+                vec![new_board()],
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            ))
+        });
+        rename
+            .expect_handle()
+            .withf(|input| input.name == "board")
+            .returning(|_| Ok(RenameBoardOutputData::new(new_board())));
+        delete
+            .expect_handle()
+            .returning(|_| Ok(DeleteBoardOutputData::new()));
+        let sut = DefaultBoardController::new(create, list, rename, delete);
+
+        assert_eq!(
+            sut.create(authorized(CreateBoardRequest {
+                name: "board".to_string(),
+                member_ids: vec!["member".to_string()],
+            }))
+            .await
+            .unwrap()
+            .into_inner(),
+            CreateBoardResponse {
+                board: Some(new_pb_board()),
+            }
+        );
+        assert_eq!(
+            sut.list(authorized(ListBoardsRequest {
+                page_size: 1,
+                page_token: "".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner(),
+            ListBoardsResponse {
+                boards: vec![new_pb_board()],
+                next_page_token: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            }
+        );
+        assert_eq!(
+            sut.rename(authorized(RenameBoardRequest {
+                id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                name: "board".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner(),
+            RenameBoardResponse {
+                board: Some(new_pb_board()),
+            }
+        );
+        assert!(sut
+            .delete(authorized(DeleteBoardRequest {
+                id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            }))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_board_controller_err() {
+        let create = MockPort::<CreateBoardInputData, CreateBoardOutputData>::new();
+        let list = MockPort::<ListBoardInputData, ListBoardOutputData>::new();
+        let rename = MockPort::<RenameBoardInputData, RenameBoardOutputData>::new();
+        let mut delete = MockPort::<DeleteBoardInputData, DeleteBoardOutputData>::new();
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::FailedPrecondition("not empty".to_string())));
+        let sut = DefaultBoardController::new(create, list, rename, delete);
+
+        assert_eq!(
+            sut.delete(authorized(DeleteBoardRequest {
+                id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            }))
+            .await
+            .unwrap_err()
+            .code(),
+            tonic::Code::FailedPrecondition
+        );
+        assert_eq!(
+            sut.list(Request::new(ListBoardsRequest {
+                page_size: 0,
+                page_token: "".to_string(),
+            }))
+            .await
+            .unwrap_err()
+            .code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use derive_new::new;
+use tonic::{Request, Status};
+
+// 認証済みの呼び出し元。認証を行う側が Request の extensions に格納する
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct Caller {
+    pub subject: String,
+}
+
+pub(crate) fn caller<T>(request: &Request<T>) -> Result<&Caller, Status> {
+    request
+        .extensions()
+        .get::<Caller>()
+        .ok_or_else(|| Status::unauthenticated("missing credentials"))
+}
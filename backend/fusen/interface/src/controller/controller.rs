@@ -1,76 +1,600 @@
+use crate::archive::ArchiveFormat;
+use crate::controller::caller::caller;
+use crate::controller::details::invalid_title;
+use crate::peta_fusen_v1::ArchiveFormat as PBArchiveFormat;
 use crate::peta_fusen_v1::Fusen as PBFusen;
+use crate::peta_fusen_v1::RenderFormat as PBRenderFormat;
+use crate::peta_fusen_v1::{BatchCreateRequest, BatchCreateResponse};
+use crate::peta_fusen_v1::{BatchDeleteRequest, BatchDeleteResponse};
+use crate::peta_fusen_v1::{BatchError, BatchResult};
+use crate::peta_fusen_v1::{BatchGetRequest, BatchGetResponse};
+use crate::peta_fusen_v1::{Color as PBColor, Size as PBSize};
 use crate::peta_fusen_v1::{CreateRequest, CreateResponse};
 use crate::peta_fusen_v1::{DeleteRequest, DeleteResponse};
+use crate::peta_fusen_v1::{EventType as PBEventType, WatchRequest, WatchResponse};
+use crate::peta_fusen_v1::{ExportRequest, ExportResponse};
 use crate::peta_fusen_v1::{GetRequest, GetResponse};
+use crate::peta_fusen_v1::{ImportFailure as PBImportFailure, ImportRequest, ImportResponse};
+use crate::peta_fusen_v1::{ListRequest, ListResponse, SortOrder as PBSortOrder};
+use crate::peta_fusen_v1::{MoveRequest, MoveResponse};
+use crate::peta_fusen_v1::{UpdateRequest, UpdateResponse};
 use anyhow::Result;
+use async_trait::async_trait;
 use derive_new::new;
+use domain::entity::Fusen;
+use domain::event::{FusenEvent, FusenEventKind};
+use domain::repository::SortOrder;
+use domain::vo::{EventId, FusenColor, Timestamp};
+use prost_types::Timestamp as PBTimestamp;
+use std::pin::Pin;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
 use tonic::{Request, Response, Status};
+use usecase::error::UsecaseError;
 use usecase::port::Port;
 use usecase::port::*;
 
-pub trait Controller {
-    fn create(&self, request: Request<CreateRequest>) -> Result<Response<CreateResponse>, Status>;
-    fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status>;
-    fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<DeleteResponse>, Status>;
+pub type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send + Sync>>;
+pub type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportResponse, Status>> + Send + Sync>>;
+pub type ImportStream = Pin<Box<dyn Stream<Item = Result<ImportRequest, Status>> + Send>>;
+
+// 取り込みはメモリ上で行うため archive の大きさを制限する
+const MAX_IMPORT_SIZE: usize = 64 * 1024 * 1024;
+
+#[async_trait]
+pub trait Controller: Send + Sync {
+    async fn create(
+        &self,
+        request: Request<CreateRequest>,
+    ) -> Result<Response<CreateResponse>, Status>;
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status>;
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status>;
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status>;
+    async fn delete(
+        &self,
+        request: Request<DeleteRequest>,
+    ) -> Result<Response<DeleteResponse>, Status>;
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateRequest>,
+    ) -> Result<Response<BatchCreateResponse>, Status>;
+    async fn batch_get(
+        &self,
+        request: Request<BatchGetRequest>,
+    ) -> Result<Response<BatchGetResponse>, Status>;
+    async fn batch_delete(
+        &self,
+        request: Request<BatchDeleteRequest>,
+    ) -> Result<Response<BatchDeleteResponse>, Status>;
+    async fn r#move(&self, request: Request<MoveRequest>)
+        -> Result<Response<MoveResponse>, Status>;
+    async fn export(
+        &self,
+        request: Request<ExportRequest>,
+    ) -> Result<Response<ExportStream>, Status>;
+    async fn import(
+        &self,
+        request: Request<ImportStream>,
+    ) -> Result<Response<ImportResponse>, Status>;
+    async fn watch(&self, request: Request<WatchRequest>) -> Result<Response<WatchStream>, Status>;
 }
 
+// ユースケースごとに型引数を取るため引数が多くなる
+#[allow(clippy::too_many_arguments)]
 #[derive(new)]
-pub struct FusenController<Create, Get, Delete>
-where
+pub struct FusenController<
+    Create,
+    List,
+    Get,
+    Update,
+    Delete,
+    BatchCreate,
+    BatchGet,
+    BatchDelete,
+    Move,
+    Export,
+    Import,
+    Watch,
+> where
     Create: Port<CreateFusenInputData, CreateFusenOutputData>,
+    List: Port<ListFusenInputData, ListFusenOutputData>,
     Get: Port<GetFusenInputData, GetFusenOutputData>,
+    Update: Port<UpdateFusenInputData, UpdateFusenOutputData>,
     Delete: Port<DeleteFusenInputData, DeleteFusenOutputData>,
+    BatchCreate: Port<BatchCreateFusenInputData, BatchCreateFusenOutputData>,
+    BatchGet: Port<BatchGetFusenInputData, BatchGetFusenOutputData>,
+    BatchDelete: Port<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>,
+    Move: Port<MoveFusenInputData, MoveFusenOutputData>,
+    Export: Port<ExportFusenInputData, ExportFusenOutputData>,
+    Import: Port<ImportFusenInputData, ImportFusenOutputData>,
+    Watch: Port<WatchFusenInputData, WatchFusenOutputData>,
 {
     create_fusen: Create,
+    list_fusen: List,
     get_fusen: Get,
+    update_fusen: Update,
     delete_fusen: Delete,
+    batch_create_fusen: BatchCreate,
+    batch_get_fusen: BatchGet,
+    batch_delete_fusen: BatchDelete,
+    move_fusen: Move,
+    export_fusen: Export,
+    import_fusen: Import,
+    watch_fusen: Watch,
 }
 
-impl<Create, Get, Delete> Controller for FusenController<Create, Get, Delete>
+#[async_trait]
+impl<
+        Create,
+        List,
+        Get,
+        Update,
+        Delete,
+        BatchCreate,
+        BatchGet,
+        BatchDelete,
+        Move,
+        Export,
+        Import,
+        Watch,
+    > Controller
+    for FusenController<
+        Create,
+        List,
+        Get,
+        Update,
+        Delete,
+        BatchCreate,
+        BatchGet,
+        BatchDelete,
+        Move,
+        Export,
+        Import,
+        Watch,
+    >
 where
     Create: Port<CreateFusenInputData, CreateFusenOutputData>,
+    List: Port<ListFusenInputData, ListFusenOutputData>,
     Get: Port<GetFusenInputData, GetFusenOutputData>,
+    Update: Port<UpdateFusenInputData, UpdateFusenOutputData>,
     Delete: Port<DeleteFusenInputData, DeleteFusenOutputData>,
+    BatchCreate: Port<BatchCreateFusenInputData, BatchCreateFusenOutputData>,
+    BatchGet: Port<BatchGetFusenInputData, BatchGetFusenOutputData>,
+    BatchDelete: Port<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>,
+    Move: Port<MoveFusenInputData, MoveFusenOutputData>,
+    Export: Port<ExportFusenInputData, ExportFusenOutputData>,
+    Import: Port<ImportFusenInputData, ImportFusenOutputData>,
+    Watch: Port<WatchFusenInputData, WatchFusenOutputData>,
 {
-    fn create(&self, request: Request<CreateRequest>) -> Result<Response<CreateResponse>, Status> {
+    async fn create(
+        &self,
+        request: Request<CreateRequest>,
+    ) -> Result<Response<CreateResponse>, Status> {
         let input = CreateFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().board_id.to_string(),
             request.get_ref().title.to_string(),
             request.get_ref().note.to_string(),
         );
 
-        match self.create_fusen.handle(input) {
+        match self.create_fusen.handle(input).await {
             Ok(output) => Ok(Response::new(CreateResponse {
-                fusen: Some(PBFusen {
-                    id: output.fusen.id().to_string(),
-                    title: output.fusen.title().to_string(),
-                    note: output.fusen.note().to_string(),
-                }),
+                fusen: Some(to_pb_fusen(&output.fusen)),
             })),
-            Err(_) => Err(Status::internal("error")),
+            Err(e) => Err(to_status(e)),
         }
     }
 
-    fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
-        let input = GetFusenInputData::new(request.get_ref().id.to_string());
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
+        let order = match PBSortOrder::from_i32(request.get_ref().order) {
+            Some(PBSortOrder::Desc) => SortOrder::Desc,
+            _ => SortOrder::Asc,
+        };
+        let input = ListFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().board_id.to_string(),
+            request.get_ref().page_size,
+            request.get_ref().page_token.to_string(),
+            order,
+            request.get_ref().keyword.to_string(),
+        );
 
-        match self.get_fusen.handle(input) {
+        match self.list_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(ListResponse {
+                fusens: output.fusens.iter().map(to_pb_fusen).collect(),
+                next_page_token: output.next_page_token,
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        let input = GetFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+            to_render_format_name(request.get_ref().render_format),
+        );
+
+        match self.get_fusen.handle(input).await {
             Ok(output) => Ok(Response::new(GetResponse {
-                fusen: Some(PBFusen {
-                    id: output.fusen.id().to_string(),
-                    title: output.fusen.title().to_string(),
-                    note: output.fusen.note().to_string(),
-                }),
+                fusen: Some(to_pb_fusen(&output.fusen)),
+                rendered_note: output.rendered_note.unwrap_or_default(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        let paths = match &request.get_ref().update_mask {
+            Some(mask) if !mask.paths.is_empty() => mask.paths.clone(),
+            _ => vec!["title".to_string(), "note".to_string()],
+        };
+        if let Some(path) = paths
+            .iter()
+            .find(|p| !["title", "note", "color"].contains(&p.as_str()))
+        {
+            return Err(Status::invalid_argument(format!(
+                "invalid update_mask path {}",
+                path
+            )));
+        }
+        let input = UpdateFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+            paths
+                .contains(&"title".to_string())
+                .then(|| request.get_ref().title.to_string()),
+            paths
+                .contains(&"note".to_string())
+                .then(|| request.get_ref().note.to_string()),
+            paths
+                .contains(&"color".to_string())
+                .then(|| to_color_name(request.get_ref().color)),
+        );
+
+        match self.update_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(UpdateResponse {
+                fusen: Some(to_pb_fusen(&output.fusen)),
             })),
-            Err(_) => Err(Status::internal("error")),
+            Err(e) => Err(to_status(e)),
         }
     }
 
-    fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<DeleteResponse>, Status> {
-        let input = DeleteFusenInputData::new(request.get_ref().id.to_string());
+    async fn delete(
+        &self,
+        request: Request<DeleteRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let input = DeleteFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().id.to_string(),
+        );
 
-        match self.delete_fusen.handle(input) {
+        match self.delete_fusen.handle(input).await {
             Ok(_) => Ok(Response::new(DeleteResponse {})),
-            Err(_) => Err(Status::internal("error")),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateRequest>,
+    ) -> Result<Response<BatchCreateResponse>, Status> {
+        let input = BatchCreateFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request
+                .get_ref()
+                .requests
+                .iter()
+                .map(|item| {
+                    BatchCreateFusenItem::new(
+                        item.board_id.to_string(),
+                        item.title.to_string(),
+                        item.note.to_string(),
+                    )
+                })
+                .collect(),
+            request.get_ref().partial_success,
+        );
+
+        match self.batch_create_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(BatchCreateResponse {
+                results: output.results.into_iter().map(to_pb_batch_result).collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn batch_get(
+        &self,
+        request: Request<BatchGetRequest>,
+    ) -> Result<Response<BatchGetResponse>, Status> {
+        let input = BatchGetFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().ids.clone(),
+            request.get_ref().partial_success,
+        );
+
+        match self.batch_get_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(BatchGetResponse {
+                results: output.results.into_iter().map(to_pb_batch_result).collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn batch_delete(
+        &self,
+        request: Request<BatchDeleteRequest>,
+    ) -> Result<Response<BatchDeleteResponse>, Status> {
+        let input = BatchDeleteFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().ids.clone(),
+            request.get_ref().partial_success,
+        );
+
+        match self.batch_delete_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(BatchDeleteResponse {
+                results: output.results.into_iter().map(to_pb_batch_result).collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn r#move(
+        &self,
+        request: Request<MoveRequest>,
+    ) -> Result<Response<MoveResponse>, Status> {
+        let input = MoveFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request
+                .get_ref()
+                .placements
+                .iter()
+                .map(|placement| {
+                    FusenPlacement::new(
+                        placement.id.to_string(),
+                        placement.x,
+                        placement.y,
+                        placement.z_index,
+                        placement
+                            .size
+                            .as_ref()
+                            .map(|size| (size.width, size.height)),
+                    )
+                })
+                .collect(),
+        );
+
+        match self.move_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(MoveResponse {
+                fusens: output.fusens.iter().map(to_pb_fusen).collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn export(
+        &self,
+        request: Request<ExportRequest>,
+    ) -> Result<Response<ExportStream>, Status> {
+        let format = to_archive_format(request.get_ref().format)?;
+        let input = ExportFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().board_id.to_string(),
+        );
+
+        match self.export_fusen.handle(input).await {
+            Ok(output) => {
+                let stream = ReceiverStream::new(output.fusens)
+                    .map(move |fusen| match fusen {
+                        Ok(fusen) => Ok(ExportResponse {
+                            chunk: format.encode(&fusen),
+                        }),
+                        Err(e) => Err(to_status(e)),
+                    })
+                    .chain(tokio_stream::iter(
+                        Some(format.finish())
+                            .filter(|chunk| !chunk.is_empty())
+                            .map(|chunk| Ok(ExportResponse { chunk })),
+                    ));
+                Ok(Response::new(Box::pin(stream) as ExportStream))
+            }
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn import(
+        &self,
+        request: Request<ImportStream>,
+    ) -> Result<Response<ImportResponse>, Status> {
+        let owner_id = caller(&request)?.subject.to_string();
+        let mut stream = request.into_inner();
+        let first = stream
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("empty import request"))??;
+        let format = to_archive_format(first.format)?;
+        let mut data = Vec::new();
+        let mut chunk = first.chunk;
+        // 最初のメッセージの chunk も含めて、溜め込む前に大きさを確かめる
+        loop {
+            if data.len() + chunk.len() > MAX_IMPORT_SIZE {
+                return Err(Status::resource_exhausted(format!(
+                    "archive exceeds {} bytes",
+                    MAX_IMPORT_SIZE
+                )));
+            }
+            data.extend(chunk);
+            chunk = match stream.next().await {
+                Some(message) => message?.chunk,
+                None => break,
+            };
+        }
+
+        let (records, failures) = format.decode_records(&data);
+        let input = ImportFusenInputData::new(owner_id, first.board_id, records);
+
+        match self.import_fusen.handle(input).await {
+            Ok(output) => Ok(Response::new(ImportResponse {
+                imported_count: output.fusens.len() as i32,
+                failures: failures
+                    .merge(output.failures)
+                    .into_iter()
+                    .map(|failure| PBImportFailure {
+                        index: failure.index as i32,
+                        id: failure.id,
+                        reason: failure.reason,
+                    })
+                    .collect(),
+            })),
+            Err(e) => Err(to_status(e)),
+        }
+    }
+
+    async fn watch(&self, request: Request<WatchRequest>) -> Result<Response<WatchStream>, Status> {
+        let input = WatchFusenInputData::new(
+            caller(&request)?.subject.to_string(),
+            request.get_ref().after_event_id.to_string(),
+        );
+
+        match self.watch_fusen.handle(input).await {
+            Ok(output) => {
+                let stream = ReceiverStream::new(output.events).map(|event| match event {
+                    Ok((id, event)) => Ok(to_pb_event(id, &event)),
+                    Err(e) => Err(to_status(e)),
+                });
+                Ok(Response::new(Box::pin(stream) as WatchStream))
+            }
+            Err(e) => Err(to_status(e)),
+        }
+    }
+}
+
+fn to_pb_fusen(fusen: &Fusen) -> PBFusen {
+    PBFusen {
+        id: fusen.id().to_string(),
+        title: fusen.title().to_string(),
+        note: fusen.note().to_string(),
+        board_id: fusen.board_id().to_string(),
+        x: fusen.position().x(),
+        y: fusen.position().y(),
+        size: Some(PBSize {
+            width: fusen.size().width(),
+            height: fusen.size().height(),
+        }),
+        color: to_pb_color(fusen.color()) as i32,
+        z_index: fusen.z_index().value(),
+        create_time: Some(to_pb_timestamp(fusen.create_at())),
+        update_time: Some(to_pb_timestamp(fusen.update_at())),
+    }
+}
+
+fn to_pb_batch_result(result: Result<Fusen, UsecaseError>) -> BatchResult {
+    match result {
+        Ok(fusen) => BatchResult {
+            fusen: Some(to_pb_fusen(&fusen)),
+            error: None,
+        },
+        Err(e) => {
+            let status = to_status(e);
+            BatchResult {
+                fusen: None,
+                error: Some(BatchError {
+                    code: status.code() as i32,
+                    message: status.message().to_string(),
+                }),
+            }
+        }
+    }
+}
+
+fn to_pb_color(color: &FusenColor) -> PBColor {
+    match color {
+        FusenColor::Yellow => PBColor::Yellow,
+        FusenColor::Pink => PBColor::Pink,
+        FusenColor::Blue => PBColor::Blue,
+        FusenColor::Green => PBColor::Green,
+        FusenColor::Orange => PBColor::Orange,
+        FusenColor::Purple => PBColor::Purple,
+    }
+}
+
+// 未指定の場合は usecase で InvalidArgument になる
+fn to_color_name(color: i32) -> String {
+    match PBColor::from_i32(color) {
+        Some(PBColor::Yellow) => "yellow",
+        Some(PBColor::Pink) => "pink",
+        Some(PBColor::Blue) => "blue",
+        Some(PBColor::Green) => "green",
+        Some(PBColor::Orange) => "orange",
+        Some(PBColor::Purple) => "purple",
+        _ => "unspecified",
+    }
+    .to_string()
+}
+
+// 未知の値は usecase で InvalidArgument になる
+fn to_render_format_name(format: i32) -> Option<String> {
+    match PBRenderFormat::from_i32(format) {
+        Some(PBRenderFormat::Unspecified) => None,
+        Some(PBRenderFormat::Html) => Some("html".to_string()),
+        Some(PBRenderFormat::PlainText) => Some("plain_text".to_string()),
+        None => Some(format.to_string()),
+    }
+}
+
+fn to_archive_format(format: i32) -> Result<ArchiveFormat, Status> {
+    match PBArchiveFormat::from_i32(format) {
+        Some(PBArchiveFormat::Ndjson) => Ok(ArchiveFormat::Ndjson),
+        Some(PBArchiveFormat::MarkdownTar) => Ok(ArchiveFormat::MarkdownTar),
+        _ => Err(Status::invalid_argument(format!(
+            "invalid archive format {}",
+            format
+        ))),
+    }
+}
+
+fn to_pb_event(id: EventId, event: &FusenEvent) -> WatchResponse {
+    let event_type = match event.kind() {
+        FusenEventKind::Created => PBEventType::Created,
+        FusenEventKind::Updated => PBEventType::Updated,
+        FusenEventKind::Deleted => PBEventType::Deleted,
+    };
+
+    WatchResponse {
+        event_id: id.to_string(),
+        r#type: event_type as i32,
+        fusen: Some(to_pb_fusen(event.fusen())),
+        occur_time: Some(to_pb_timestamp(event.occurred_at())),
+    }
+}
+
+pub(crate) fn to_pb_timestamp(timestamp: &Timestamp) -> PBTimestamp {
+    PBTimestamp {
+        seconds: timestamp.value().timestamp(),
+        nanos: timestamp.value().timestamp_subsec_nanos() as i32,
+    }
+}
+
+pub(crate) fn to_status(error: UsecaseError) -> Status {
+    match error {
+        UsecaseError::NotFound(message) => Status::not_found(message),
+        UsecaseError::InvalidArgument(message) => Status::invalid_argument(message),
+        UsecaseError::InvalidTitle(error) => invalid_title(error),
+        UsecaseError::Conflict(message) => Status::already_exists(message),
+        UsecaseError::PermissionDenied(message) => Status::permission_denied(message),
+        UsecaseError::FailedPrecondition(message) => Status::failed_precondition(message),
+        UsecaseError::Unavailable(message) => Status::unavailable(message),
+        // クライアントには詳細を返さないので、原因をたどれるようここで記録する
+        UsecaseError::Unexpected(e) => {
+            tracing::error!(error = ?e, "unexpected error");
+            Status::internal("internal error")
         }
     }
 }
@@ -78,14 +602,27 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::bail;
-    use domain::entity::{Fusen, FusenBuilder};
-    use domain::vo::{FusenNote, FusenTitle, Id};
+    use crate::controller::Caller;
+    use crate::peta_fusen_v1::Placement;
+    use anyhow::anyhow;
+    use domain::entity::{Board, Fusen, FusenBuilder};
+    use domain::vo::{FusenNote, FusenTitle, Id, OwnerId};
+    use prost_types::FieldMask;
     use usecase::port::MockPort;
 
+    fn authorized<T>(message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request
+            .extensions_mut()
+            .insert(Caller::new("owner".to_string()));
+        request
+    }
+
     fn new_fusen() -> Fusen {
         FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title(
                 "Clean Architecture using Rust"
                     .parse::<FusenTitle>()
@@ -96,32 +633,103 @@ mod tests {
                     .parse::<FusenNote>()
                     .unwrap(),
             )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap()
     }
 
     #[test]
-    fn test_create_fusen_handle_ok() {
+    fn test_to_status() {
+        let status = to_status(UsecaseError::NotFound("fusen".to_string()));
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), "fusen");
+
+        let status = to_status(UsecaseError::InvalidArgument("title".to_string()));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "title");
+
+        let status = to_status(UsecaseError::InvalidTitle(FusenTitle::new("").unwrap_err()));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "invalid title: must not be empty");
+        assert!(!status.details().is_empty());
+
+        let status = to_status(UsecaseError::Conflict("fusen".to_string()));
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        assert_eq!(status.message(), "fusen");
+
+        let status = to_status(UsecaseError::PermissionDenied("fusen".to_string()));
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "fusen");
+
+        let status = to_status(UsecaseError::FailedPrecondition("board".to_string()));
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert_eq!(status.message(), "board");
+
+        let status = to_status(UsecaseError::Unavailable("database".to_string()));
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(status.message(), "database");
+
+        let status = to_status(UsecaseError::Unexpected(anyhow!("secret")));
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(status.message(), "internal error");
+    }
+
+    #[tokio::test]
+    async fn test_create_fusen_handle_ok() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
         create
             .expect_handle()
             .returning(|_| Ok(CreateFusenOutputData::new(new_fusen())));
+        list.expect_handle().returning(|_| {
+            Ok(ListFusenOutputData::new(
+                vec![new_fusen()],
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ))
+        });
         get.expect_handle()
-            .returning(|_| Ok(GetFusenOutputData::new(new_fusen())));
+            .returning(|_| Ok(GetFusenOutputData::new(new_fusen(), None)));
+        update
+            .expect_handle()
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
         delete
             .expect_handle()
             .returning(|_| Ok(DeleteFusenOutputData::new()));
-        let sut = FusenController::new(create, get, delete);
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert_eq!(
-            sut.create(Request::new(CreateRequest {
+            sut.create(authorized(CreateRequest {
                 title: entity.title().to_string(),
                 note: entity.note().to_string(),
+                board_id: entity.board_id().to_string(),
             }))
+            .await
             .unwrap()
             .get_ref(),
             Response::new(CreateResponse {
@@ -129,53 +737,286 @@ mod tests {
                     id: entity.id().to_string(),
                     title: entity.title().to_string(),
                     note: entity.note().to_string(),
+                    board_id: entity.board_id().to_string(),
+                    x: 0,
+                    y: 0,
+                    size: Some(PBSize {
+                        width: 200,
+                        height: 200,
+                    }),
+                    color: PBColor::Yellow as i32,
+                    z_index: 0,
+                    create_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                    update_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
                 }),
             })
             .get_ref(),
         );
     }
 
-    #[test]
-    fn test_create_fusen_handle_err() {
+    #[tokio::test]
+    async fn test_create_fusen_handle_err() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
-        create.expect_handle().returning(|_| bail!("error"));
-        get.expect_handle().returning(|_| bail!("error"));
-        delete.expect_handle().returning(|_| bail!("error"));
-        let sut = FusenController::new(create, get, delete);
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        list.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        get.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        update
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert!(sut
-            .create(Request::new(CreateRequest {
+            .create(authorized(CreateRequest {
                 title: entity.title().to_string(),
                 note: entity.note().to_string(),
+                board_id: entity.board_id().to_string(),
             }))
+            .await
             .is_err());
     }
 
-    #[test]
-    fn test_get_fusen_handle_ok() {
+    #[tokio::test]
+    async fn test_list_fusen_handle_ok() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
         create
             .expect_handle()
             .returning(|_| Ok(CreateFusenOutputData::new(new_fusen())));
+        list.expect_handle()
+            .withf(|input| {
+                input.owner_id == "owner"
+                    && input.board_id == "01FMB0ARDXKQ7B1GZ1Z0K0T8R0"
+                    && input.page_size == 1
+                    && input.order == SortOrder::Desc
+            })
+            .returning(|_| {
+                Ok(ListFusenOutputData::new(
+                    vec![new_fusen()],
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                ))
+            });
         get.expect_handle()
-            .returning(|_| Ok(GetFusenOutputData::new(new_fusen())));
+            .returning(|_| Ok(GetFusenOutputData::new(new_fusen(), None)));
+        update
+            .expect_handle()
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
         delete
             .expect_handle()
             .returning(|_| Ok(DeleteFusenOutputData::new()));
-        let sut = FusenController::new(create, get, delete);
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert_eq!(
-            sut.get(Request::new(GetRequest {
+            sut.list(authorized(ListRequest {
+                page_size: 1,
+                page_token: "".to_string(),
+                order: PBSortOrder::Desc as i32,
+                keyword: "".to_string(),
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            }))
+            .await
+            .unwrap()
+            .get_ref(),
+            Response::new(ListResponse {
+                fusens: vec![PBFusen {
+                    id: entity.id().to_string(),
+                    title: entity.title().to_string(),
+                    note: entity.note().to_string(),
+                    board_id: entity.board_id().to_string(),
+                    x: 0,
+                    y: 0,
+                    size: Some(PBSize {
+                        width: 200,
+                        height: 200,
+                    }),
+                    color: PBColor::Yellow as i32,
+                    z_index: 0,
+                    create_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                    update_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                }],
+                next_page_token: entity.id().to_string(),
+            })
+            .get_ref(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle_err() {
+        let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        list.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        get.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        update
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert!(sut
+            .list(authorized(ListRequest {
+                page_size: 1,
+                page_token: "".to_string(),
+                order: PBSortOrder::Asc as i32,
+                keyword: "".to_string(),
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            }))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_fusen_handle_ok() {
+        let entity = new_fusen();
+
+        let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Ok(CreateFusenOutputData::new(new_fusen())));
+        list.expect_handle().returning(|_| {
+            Ok(ListFusenOutputData::new(
+                vec![new_fusen()],
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ))
+        });
+        get.expect_handle()
+            .returning(|_| Ok(GetFusenOutputData::new(new_fusen(), None)));
+        update
+            .expect_handle()
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
+        delete
+            .expect_handle()
+            .returning(|_| Ok(DeleteFusenOutputData::new()));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.get(authorized(GetRequest {
                 id: entity.id().to_string(),
+                render_format: PBRenderFormat::Unspecified as i32,
             }))
+            .await
             .unwrap()
             .get_ref(),
             Response::new(GetResponse {
@@ -183,74 +1024,1187 @@ mod tests {
                     id: entity.id().to_string(),
                     title: entity.title().to_string(),
                     note: entity.note().to_string(),
+                    board_id: entity.board_id().to_string(),
+                    x: 0,
+                    y: 0,
+                    size: Some(PBSize {
+                        width: 200,
+                        height: 200,
+                    }),
+                    color: PBColor::Yellow as i32,
+                    z_index: 0,
+                    create_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                    update_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
                 }),
+                rendered_note: "".to_string(),
             })
             .get_ref(),
         );
     }
 
-    #[test]
-    fn test_get_fusen_handle_err() {
+    #[tokio::test]
+    async fn test_get_fusen_rendered_note() {
+        let entity = new_fusen();
+
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        get.expect_handle()
+            .withf(|input| input.render_format == Some("html".to_string()))
+            .returning(|_| {
+                Ok(GetFusenOutputData::new(
+                    new_fusen(),
+                    Some("<p>note</p>\n".to_string()),
+                ))
+            });
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let response = sut
+            .get(authorized(GetRequest {
+                id: entity.id().to_string(),
+                render_format: PBRenderFormat::Html as i32,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.get_ref().rendered_note,
+            "<p>note</p>\n".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_fusen_handle_err() {
+        let entity = new_fusen();
+
+        let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        list.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        get.expect_handle()
+            .returning(|input| Err(UsecaseError::NotFound(input.id)));
+        update
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let status = sut
+            .get(authorized(GetRequest {
+                id: entity.id().to_string(),
+                render_format: PBRenderFormat::Unspecified as i32,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), entity.id().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_update_fusen_handle_ok() {
+        let entity = new_fusen();
+
+        let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Ok(CreateFusenOutputData::new(new_fusen())));
+        list.expect_handle()
+            .returning(|_| Ok(ListFusenOutputData::new(vec![], "".to_string())));
+        get.expect_handle()
+            .returning(|_| Ok(GetFusenOutputData::new(new_fusen(), None)));
+        update
+            .expect_handle()
+            .withf(|input| input.title.is_some() && input.note.is_none())
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
+        delete
+            .expect_handle()
+            .returning(|_| Ok(DeleteFusenOutputData::new()));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.update(authorized(UpdateRequest {
+                id: entity.id().to_string(),
+                color: PBColor::Unspecified as i32,
+                title: entity.title().to_string(),
+                note: "".to_string(),
+                update_mask: Some(FieldMask {
+                    paths: vec!["title".to_string()],
+                }),
+            }))
+            .await
+            .unwrap()
+            .get_ref(),
+            Response::new(UpdateResponse {
+                fusen: Some(PBFusen {
+                    id: entity.id().to_string(),
+                    title: entity.title().to_string(),
+                    note: entity.note().to_string(),
+                    board_id: entity.board_id().to_string(),
+                    x: 0,
+                    y: 0,
+                    size: Some(PBSize {
+                        width: 200,
+                        height: 200,
+                    }),
+                    color: PBColor::Yellow as i32,
+                    z_index: 0,
+                    create_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                    update_time: Some(PBTimestamp {
+                        seconds: 1634454517,
+                        nanos: 0,
+                    }),
+                }),
+            })
+            .get_ref(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_fusen_handle_err() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
-        create.expect_handle().returning(|_| bail!("error"));
-        get.expect_handle().returning(|_| bail!("error"));
-        delete.expect_handle().returning(|_| bail!("error"));
-        let sut = FusenController::new(create, get, delete);
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        list.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        get.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        update
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert!(sut
-            .get(Request::new(GetRequest {
-                id: entity.id().to_string()
+            .update(authorized(UpdateRequest {
+                id: entity.id().to_string(),
+                color: PBColor::Unspecified as i32,
+                title: entity.title().to_string(),
+                note: entity.note().to_string(),
+                update_mask: None,
             }))
+            .await
             .is_err());
+        assert_eq!(
+            sut.update(authorized(UpdateRequest {
+                id: entity.id().to_string(),
+                color: PBColor::Unspecified as i32,
+                title: entity.title().to_string(),
+                note: entity.note().to_string(),
+                update_mask: Some(FieldMask {
+                    paths: vec!["id".to_string()],
+                }),
+            }))
+            .await
+            .unwrap_err()
+            .code(),
+            tonic::Code::InvalidArgument,
+        );
     }
 
-    #[test]
-    fn test_delete_fusen_handle_ok() {
+    #[tokio::test]
+    async fn test_update_fusen_color() {
+        let entity = new_fusen();
+
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        update
+            .expect_handle()
+            .withf(|input| {
+                input.title.is_none()
+                    && input.note.is_none()
+                    && input.color == Some("pink".to_string())
+            })
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert!(sut
+            .update(authorized(UpdateRequest {
+                id: entity.id().to_string(),
+                color: PBColor::Pink as i32,
+                title: "".to_string(),
+                note: "".to_string(),
+                update_mask: Some(FieldMask {
+                    paths: vec!["color".to_string()],
+                }),
+            }))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_fusen_handle_ok() {
+        let entity = new_fusen();
+
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let mut batch_create =
+            MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        batch_create
+            .expect_handle()
+            .withf(|input| {
+                input.partial_success
+                    && input.items
+                        == vec![BatchCreateFusenItem::new(
+                            "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                            "title".to_string(),
+                            "note".to_string(),
+                        )]
+            })
+            .returning(|_| {
+                Ok(BatchCreateFusenOutputData::new(vec![
+                    Ok(new_fusen()),
+                    Err(UsecaseError::InvalidArgument("title".to_string())),
+                ]))
+            });
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let response = sut
+            .batch_create(authorized(BatchCreateRequest {
+                requests: vec![CreateRequest {
+                    title: "title".to_string(),
+                    note: "note".to_string(),
+                    board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                }],
+                partial_success: true,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.get_ref().results,
+            vec![
+                BatchResult {
+                    fusen: Some(to_pb_fusen(&entity)),
+                    error: None,
+                },
+                BatchResult {
+                    fusen: None,
+                    error: Some(BatchError {
+                        code: tonic::Code::InvalidArgument as i32,
+                        message: "title".to_string(),
+                    }),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_fusen_handle_ok() {
+        let entity = new_fusen();
+
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let mut batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        batch_get
+            .expect_handle()
+            .withf(|input| {
+                input.ids == vec!["01F8MECHZX3TBDSZ7XRADM79XE".to_string()]
+                    && !input.partial_success
+            })
+            .returning(|_| Ok(BatchGetFusenOutputData::new(vec![Ok(new_fusen())])));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let response = sut
+            .batch_get(authorized(BatchGetRequest {
+                ids: vec!["01F8MECHZX3TBDSZ7XRADM79XE".to_string()],
+                partial_success: false,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.get_ref().results[0].fusen,
+            Some(to_pb_fusen(&entity))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_fusen_handle_err() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let mut batch_delete =
+            MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        batch_delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::PermissionDenied("fusen".to_string())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.batch_delete(authorized(BatchDeleteRequest {
+                ids: vec!["01F8MECHZX3TBDSZ7XRADM79XE".to_string()],
+                partial_success: false,
+            }))
+            .await
+            .unwrap_err()
+            .code(),
+            tonic::Code::PermissionDenied,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_move_fusen_handle_ok() {
+        let entity = new_fusen();
+
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let mut move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        move_fusen
+            .expect_handle()
+            .withf(|input| {
+                input.placements
+                    == vec![
+                        FusenPlacement::new(
+                            "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                            10,
+                            -20,
+                            3,
+                            None,
+                        ),
+                        FusenPlacement::new(
+                            "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                            0,
+                            0,
+                            0,
+                            Some((120, 80)),
+                        ),
+                    ]
+            })
+            .returning(|_| Ok(MoveFusenOutputData::new(vec![new_fusen()])));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let response = sut
+            .r#move(authorized(MoveRequest {
+                placements: vec![
+                    Placement {
+                        id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                        x: 10,
+                        y: -20,
+                        z_index: 3,
+                        size: None,
+                    },
+                    Placement {
+                        id: "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                        x: 0,
+                        y: 0,
+                        z_index: 0,
+                        size: Some(PBSize {
+                            width: 120,
+                            height: 80,
+                        }),
+                    },
+                ],
+            }))
+            .await
+            .unwrap();
+        assert_eq!(response.get_ref().fusens, vec![to_pb_fusen(&entity)]);
+    }
+
+    #[tokio::test]
+    async fn test_move_fusen_handle_err() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let mut move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        move_fusen
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::PermissionDenied("fusen".to_string())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.r#move(authorized(MoveRequest { placements: vec![] }))
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::PermissionDenied,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_fusen_handle_ok() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
         create
             .expect_handle()
             .returning(|_| Ok(CreateFusenOutputData::new(new_fusen())));
+        list.expect_handle().returning(|_| {
+            Ok(ListFusenOutputData::new(
+                vec![new_fusen()],
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ))
+        });
         get.expect_handle()
-            .returning(|_| Ok(GetFusenOutputData::new(new_fusen())));
+            .returning(|_| Ok(GetFusenOutputData::new(new_fusen(), None)));
+        update
+            .expect_handle()
+            .returning(|_| Ok(UpdateFusenOutputData::new(new_fusen())));
         delete
             .expect_handle()
             .returning(|_| Ok(DeleteFusenOutputData::new()));
-        let sut = FusenController::new(create, get, delete);
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert_eq!(
-            sut.delete(Request::new(DeleteRequest {
+            sut.delete(authorized(DeleteRequest {
                 id: entity.id().to_string(),
             }))
+            .await
             .unwrap()
             .get_ref(),
             Response::new(DeleteResponse {}).get_ref(),
         );
     }
 
-    #[test]
-    fn test_delete_fusen_handle_err() {
+    #[tokio::test]
+    async fn test_delete_fusen_handle_err() {
         let entity = new_fusen();
 
         let mut create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let mut list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
         let mut get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let mut update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
         let mut delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
-        create.expect_handle().returning(|_| bail!("error"));
-        get.expect_handle().returning(|_| bail!("error"));
-        delete.expect_handle().returning(|_| bail!("error"));
-        let sut = FusenController::new(create, get, delete);
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        create
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        list.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        get.expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        update
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        delete
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::Unexpected(anyhow!("error"))));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
 
         assert!(sut
-            .delete(Request::new(DeleteRequest {
+            .delete(authorized(DeleteRequest {
                 id: entity.id().to_string()
             }))
+            .await
             .is_err());
     }
+
+    #[tokio::test]
+    async fn test_export_fusen_handle_ok() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let mut export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        export
+            .expect_handle()
+            .withf(|input| {
+                input.owner_id == "owner" && input.board_id == "01FMB0ARDXKQ7B1GZ1Z0K0T8R0"
+            })
+            .returning(|_| {
+                let (sender, receiver) = tokio::sync::mpsc::channel(1);
+                sender.try_send(Ok(new_fusen())).unwrap();
+                Ok(ExportFusenOutputData::new(receiver))
+            });
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let stream = sut
+            .export(authorized(ExportRequest {
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                format: PBArchiveFormat::MarkdownTar as i32,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let data = stream
+            .map(|response| response.unwrap().chunk)
+            .collect::<Vec<_>>()
+            .await
+            .concat();
+
+        let records = ArchiveFormat::MarkdownTar.decode(&data);
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].as_ref().unwrap().id,
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_fusen_handle_err() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let mut export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        export
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::PermissionDenied("board".to_string())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.export(authorized(ExportRequest {
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                format: PBArchiveFormat::Unspecified as i32,
+            }))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            sut.export(authorized(ExportRequest {
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                format: PBArchiveFormat::Ndjson as i32,
+            }))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::PermissionDenied
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_fusen_handle_ok() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let mut import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        import
+            .expect_handle()
+            .withf(|input| {
+                input.owner_id == "owner"
+                    && input.board_id == "01FMB0ARDXKQ7B1GZ1Z0K0T8R0"
+                    && input
+                        .records
+                        .iter()
+                        .map(|record| record.id.as_str())
+                        .eq(["01F8MECHZX3TBDSZ7XRADM79XE", "01F8MECHZX3TBDSZ7XRADM79XF"])
+            })
+            .returning(|_| {
+                Ok(ImportFusenOutputData::new(
+                    vec![new_fusen()],
+                    vec![ImportFusenFailure::new(
+                        1,
+                        "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                        "invalid title".to_string(),
+                    )],
+                ))
+            });
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        // レコードの途中でメッセージが分かれても連結して読み取る
+        let stream = tokio_stream::iter(vec![
+            Ok(ImportRequest {
+                board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                format: PBArchiveFormat::Ndjson as i32,
+                chunk: b"{\"id\":\"01F8MECHZX3TBDSZ7XRADM79XE\",\"ti".to_vec(),
+            }),
+            Ok(ImportRequest {
+                chunk: b"tle\":\"title\"}\nbroken\n{\"id\":\"01F8MECHZX3TBDSZ7XRADM79XF\",\"title\":\"!\"}\n"
+                    .to_vec(),
+                ..Default::default()
+            }),
+        ]);
+        let response = sut
+            .import(authorized(Box::pin(stream) as ImportStream))
+            .await
+            .unwrap();
+
+        assert_eq!(response.get_ref().imported_count, 1);
+        assert_eq!(
+            response
+                .get_ref()
+                .failures
+                .iter()
+                .map(|failure| (failure.index, failure.id.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, ""), (2, "01F8MECHZX3TBDSZ7XRADM79XF")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_fusen_handle_err() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let mut import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        import
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::NotFound("board".to_string())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.import(authorized(Box::pin(tokio_stream::empty()) as ImportStream))
+                .await
+                .err()
+                .unwrap()
+                .code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            sut.import(authorized(
+                Box::pin(tokio_stream::iter(vec![Ok(ImportRequest {
+                    board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                    format: PBArchiveFormat::MarkdownTar as i32,
+                    chunk: vec![],
+                })])) as ImportStream
+            ))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::NotFound
+        );
+        // 最初のメッセージだけで上限を超えていても受け付けない
+        assert_eq!(
+            sut.import(authorized(
+                Box::pin(tokio_stream::iter(vec![Ok(ImportRequest {
+                    board_id: "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                    format: PBArchiveFormat::MarkdownTar as i32,
+                    chunk: vec![0; MAX_IMPORT_SIZE + 1],
+                })])) as ImportStream
+            ))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::ResourceExhausted
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_ok() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let mut watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        watch.expect_handle().returning(|_| {
+            let (sender, receiver) = tokio::sync::mpsc::channel(2);
+            sender
+                .try_send(Ok((
+                    EventId::new(1),
+                    FusenEvent::new(
+                        FusenEventKind::Deleted,
+                        new_fusen(),
+                        "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap(),
+                    ),
+                )))
+                .unwrap();
+            sender
+                .try_send(Err(UsecaseError::Unavailable("database".to_string())))
+                .unwrap();
+            Ok(WatchFusenOutputData::new(receiver))
+        });
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        let mut stream = sut
+            .watch(authorized(WatchRequest {
+                after_event_id: "".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let response = stream.next().await.unwrap().unwrap();
+        assert_eq!(response.event_id, "1".to_string());
+        assert_eq!(response.r#type, PBEventType::Deleted as i32);
+        assert_eq!(
+            response.fusen.unwrap().id,
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+        assert_eq!(
+            response.occur_time,
+            Some(PBTimestamp {
+                seconds: 1634454517,
+                nanos: 0,
+            })
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap_err().code(),
+            tonic::Code::Unavailable
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.get(Request::new(GetRequest {
+                id: "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                render_format: PBRenderFormat::Unspecified as i32,
+            }))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::Unauthenticated
+        );
+        assert_eq!(
+            sut.list(Request::new(ListRequest::default()))
+                .await
+                .err()
+                .unwrap()
+                .code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_err() {
+        let create = MockPort::<CreateFusenInputData, CreateFusenOutputData>::new();
+        let list = MockPort::<ListFusenInputData, ListFusenOutputData>::new();
+        let get = MockPort::<GetFusenInputData, GetFusenOutputData>::new();
+        let update = MockPort::<UpdateFusenInputData, UpdateFusenOutputData>::new();
+        let delete = MockPort::<DeleteFusenInputData, DeleteFusenOutputData>::new();
+        let batch_create = MockPort::<BatchCreateFusenInputData, BatchCreateFusenOutputData>::new();
+        let batch_get = MockPort::<BatchGetFusenInputData, BatchGetFusenOutputData>::new();
+        let batch_delete = MockPort::<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>::new();
+        let move_fusen = MockPort::<MoveFusenInputData, MoveFusenOutputData>::new();
+        let export = MockPort::<ExportFusenInputData, ExportFusenOutputData>::new();
+        let import = MockPort::<ImportFusenInputData, ImportFusenOutputData>::new();
+        let mut watch = MockPort::<WatchFusenInputData, WatchFusenOutputData>::new();
+        watch
+            .expect_handle()
+            .returning(|_| Err(UsecaseError::InvalidArgument("event id".to_string())));
+        let sut = FusenController::new(
+            create,
+            list,
+            get,
+            update,
+            delete,
+            batch_create,
+            batch_get,
+            batch_delete,
+            move_fusen,
+            export,
+            import,
+            watch,
+        );
+
+        assert_eq!(
+            sut.watch(authorized(WatchRequest {
+                after_event_id: "invalid".to_string(),
+            }))
+            .await
+            .err()
+            .unwrap()
+            .code(),
+            tonic::Code::InvalidArgument
+        );
+    }
 }
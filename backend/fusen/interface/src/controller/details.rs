@@ -0,0 +1,101 @@
+use domain::vo::TitleError;
+use prost::Message;
+use prost_types::Any;
+use tonic::{Code, Status};
+
+// google/rpc/status.proto と google/rpc/error_details.proto のうち使うものだけを定義する
+#[derive(Clone, PartialEq, Message)]
+pub struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    pub code: i32,
+    #[prost(string, tag = "2")]
+    pub message: String,
+    #[prost(message, repeated, tag = "3")]
+    pub details: Vec<Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct BadRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub field_violations: Vec<FieldViolation>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct FieldViolation {
+    #[prost(string, tag = "1")]
+    pub field: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+const BAD_REQUEST_TYPE_URL: &str = "type.googleapis.com/google.rpc.BadRequest";
+
+// 受け付けない理由ごとに field violation を返し、クライアントが項目に対応づけられるようにする
+pub(crate) fn invalid_title(error: TitleError) -> Status {
+    let bad_request = BadRequest {
+        field_violations: error
+            .violations()
+            .iter()
+            .map(|violation| FieldViolation {
+                field: "title".to_string(),
+                description: violation.to_string(),
+            })
+            .collect(),
+    };
+
+    bad_request_status(error.to_string(), bad_request)
+}
+
+fn bad_request_status(message: String, bad_request: BadRequest) -> Status {
+    let status = RpcStatus {
+        code: Code::InvalidArgument as i32,
+        message: message.clone(),
+        details: vec![Any {
+            type_url: BAD_REQUEST_TYPE_URL.to_string(),
+            value: bad_request.encode_to_vec(),
+        }],
+    };
+
+    Status::with_details(
+        Code::InvalidArgument,
+        message,
+        status.encode_to_vec().into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::vo::FusenTitle;
+
+    #[test]
+    fn test_invalid_title() {
+        let error = FusenTitle::new(&format!("{}\u{7f}", "a".repeat(65))).unwrap_err();
+        let status = invalid_title(error);
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "invalid title: must be at most 64 characters, got 66; must not contain control character U+007F"
+        );
+
+        let details = RpcStatus::decode(status.details()).unwrap();
+        assert_eq!(details.code, Code::InvalidArgument as i32);
+        assert_eq!(details.message, status.message());
+        assert_eq!(details.details.len(), 1);
+        assert_eq!(details.details[0].type_url, BAD_REQUEST_TYPE_URL);
+        let bad_request = BadRequest::decode(details.details[0].value.as_slice()).unwrap();
+        assert_eq!(
+            bad_request.field_violations,
+            vec![
+                FieldViolation {
+                    field: "title".to_string(),
+                    description: "must be at most 64 characters, got 66".to_string(),
+                },
+                FieldViolation {
+                    field: "title".to_string(),
+                    description: "must not contain control character U+007F".to_string(),
+                },
+            ]
+        );
+    }
+}
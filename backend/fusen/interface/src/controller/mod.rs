@@ -1,5 +1,21 @@
 #[allow(clippy::module_inception)]
 mod controller;
 
+mod attachment;
+mod board;
+mod caller;
+mod details;
+
+pub use self::attachment::AttachmentController;
+pub use self::attachment::DefaultAttachmentController;
+pub use self::attachment::DownloadStream;
+pub use self::attachment::UploadStream;
+pub use self::board::BoardController;
+pub use self::board::DefaultBoardController;
+pub use self::caller::Caller;
+pub use self::details::{BadRequest, FieldViolation, RpcStatus};
 pub use self::controller::Controller;
+pub use self::controller::ExportStream;
 pub use self::controller::FusenController;
+pub use self::controller::ImportStream;
+pub use self::controller::WatchStream;
@@ -1,5 +1,8 @@
 pub mod peta_fusen_v1 {
     tonic::include_proto!("peta.fusen.v1");
+
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("fusen_descriptor");
 }
 
+pub mod archive;
 pub mod controller;
@@ -1,34 +1,372 @@
+mod cli;
+
 use anyhow::Result;
-use infrastructure::grpc::Service;
-use infrastructure::postgres::DbPool;
-use infrastructure::postgres::FusenRepository;
+use domain::entity::{Board, Fusen};
+use domain::event::FusenEvent;
+use domain::repository::{AttachmentRepository, BlobStore, OutboxRepository};
+use domain::repository::{CreateAllRepository, DeleteAllRepository, GetAllRepository};
+use domain::repository::{CreateRepository, DeleteRepository, GetRepository, ListRepository};
+use domain::repository::{MoveAllRepository, PruneRepository, UpdateRepository};
+use domain::repository::{SubscribeRepository, UnitOfWork};
+use infrastructure::blob::{Collector, LocalBlobStore, S3BlobStore};
+use infrastructure::chrono::Clock;
+use infrastructure::config::{Backend, BlobStoreKind, Config};
+use infrastructure::event::Pruner;
+use infrastructure::grpc::{HealthCheck, Service};
+use infrastructure::http::{Gateway, MetricsExporter};
+use infrastructure::logger;
+use infrastructure::markdown::NoteRenderer;
+use infrastructure::memory;
+use infrastructure::metrics::PoolCollector;
+use infrastructure::outbox::{DiscardSink, HttpSink, Relay, Sink};
+use infrastructure::postgres;
+use infrastructure::shutdown::Shutdown;
+#[cfg(feature = "sqlite")]
+use infrastructure::sqlite;
 use infrastructure::ulid::IdRepository;
+use interface::controller::DefaultAttachmentController;
+use interface::controller::DefaultBoardController;
 use interface::controller::FusenController;
 use std::env;
+use std::future::Future;
+use std::sync::Arc;
+use usecase::interactor::AttachmentLimits;
+use usecase::interactor::BatchCreateFusenInteractor;
+use usecase::interactor::BatchDeleteFusenInteractor;
+use usecase::interactor::BatchGetFusenInteractor;
+use usecase::interactor::CreateBoardInteractor;
 use usecase::interactor::CreateFusenInteractor;
+use usecase::interactor::DeleteBoardInteractor;
 use usecase::interactor::DeleteFusenInteractor;
+use usecase::interactor::DownloadAttachmentInteractor;
+use usecase::interactor::ExportFusenInteractor;
 use usecase::interactor::GetFusenInteractor;
+use usecase::interactor::ImportFusenInteractor;
+use usecase::interactor::ListAttachmentInteractor;
+use usecase::interactor::ListBoardInteractor;
+use usecase::interactor::ListFusenInteractor;
+use usecase::interactor::MoveFusenInteractor;
+use usecase::interactor::RenameBoardInteractor;
+use usecase::interactor::UpdateFusenInteractor;
+use usecase::interactor::UploadAttachmentInteractor;
+use usecase::interactor::WatchFusenInteractor;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let database_url = env::var("FUSEN_DATABASE_URL").expect("FUSEN_DATABASE_URL must be set");
+    let config = Config::load()?;
+
+    match config.backend {
+        Backend::Postgres => {
+            let connections = postgres::DbPool::with_config(&config.database);
+            PoolCollector::new(connections.pool())?.register()?;
+            let event_repository =
+                postgres::FusenEventRepository::new(connections.clone(), &config.database.url);
+            let init = {
+                let connections = connections.clone();
+                let event_repository = event_repository.clone();
+                async move {
+                    tokio::task::spawn_blocking(move || connections.init()).await??;
+                    tokio::spawn(event_repository.listen());
+
+                    Ok(())
+                }
+            };
+
+            let result = run(
+                config,
+                postgres::BoardRepository::new(connections.clone()),
+                postgres::FusenRepository::new(connections.clone()),
+                postgres::UnitOfWork::new(connections.clone()),
+                event_repository,
+                postgres::OutboxRepository::new(connections.clone()),
+                postgres::AttachmentRepository::new(connections.clone()),
+                connections.clone(),
+                init,
+            )
+            .await;
+
+            connections.close().await;
+            tracing::info!("database connections closed");
+
+            result
+        }
+        Backend::Memory => {
+            let outbox_repository = memory::OutboxRepository::default();
+            let event_repository = memory::FusenEventRepository::default();
+            let fusen_repository =
+                memory::FusenRepository::new(outbox_repository.clone(), event_repository.clone());
+
+            run(
+                config,
+                memory::BoardRepository::default(),
+                fusen_repository.clone(),
+                memory::UnitOfWork::new(fusen_repository.clone()),
+                event_repository,
+                outbox_repository,
+                memory::AttachmentRepository::new(fusen_repository),
+                (),
+                async { Ok(()) },
+            )
+            .await
+        }
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => {
+            let connections = sqlite::DbPool::with_config(&config.database);
+            PoolCollector::new(connections.pool())?.register()?;
+            let init = {
+                let connections = connections.clone();
+                async move {
+                    tokio::task::spawn_blocking(move || connections.init()).await??;
+
+                    Ok(())
+                }
+            };
+
+            let result = run(
+                config,
+                sqlite::BoardRepository::new(connections.clone()),
+                sqlite::FusenRepository::new(connections.clone()),
+                sqlite::UnitOfWork::new(connections.clone()),
+                sqlite::FusenEventRepository::new(connections.clone()),
+                sqlite::OutboxRepository::new(connections.clone()),
+                sqlite::AttachmentRepository::new(connections.clone()),
+                connections.clone(),
+                init,
+            )
+            .await;
 
-    let connections = DbPool::new(&database_url);
+            connections.close().await;
+            tracing::info!("database connections closed");
 
+            result
+        }
+        // Config::validate で拒否しているので到達しない
+        #[cfg(not(feature = "sqlite"))]
+        Backend::Sqlite => unreachable!("backend sqlite requires the sqlite feature"),
+    }
+}
+
+// init はサーバーの起動と並行して実行する
+#[allow(clippy::too_many_arguments)]
+async fn run<B, S, U, P, O, A, H, I>(
+    config: Config,
+    board_repository: B,
+    fusen_repository: S,
+    unit_of_work: U,
+    event_repository: P,
+    outbox_repository: O,
+    attachment_repository: A,
+    connections: H,
+    init: I,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    B: CreateRepository<Board>
+        + GetRepository<Board>
+        + ListRepository<Board>
+        + UpdateRepository<Board>
+        + DeleteRepository<Board>
+        + Clone
+        + 'static,
+    S: CreateRepository<Fusen>
+        + CreateAllRepository<Fusen>
+        + GetRepository<Fusen>
+        + GetAllRepository<Fusen>
+        + ListRepository<Fusen>
+        + UpdateRepository<Fusen>
+        + MoveAllRepository<Fusen>
+        + DeleteRepository<Fusen>
+        + Clone
+        + 'static,
+    U: UnitOfWork + Clone + 'static,
+    U::Repositories: CreateRepository<Fusen>
+        + GetRepository<Fusen>
+        + UpdateRepository<Fusen>
+        + MoveAllRepository<Fusen>
+        + DeleteRepository<Fusen>
+        + GetAllRepository<Fusen>
+        + DeleteAllRepository<Fusen>,
+    P: SubscribeRepository<FusenEvent> + PruneRepository + Clone + 'static,
+    O: OutboxRepository + 'static,
+    A: AttachmentRepository + Clone + 'static,
+    H: HealthCheck,
+    I: Future<Output = Result<(), Box<dyn std::error::Error>>>,
+{
     let id_repository = IdRepository::default();
-    let fusen_repository = FusenRepository::new(connections.clone());
-    let create = CreateFusenInteractor::new(id_repository, fusen_repository.clone());
-    let get = GetFusenInteractor::new(fusen_repository.clone());
-    let delete = DeleteFusenInteractor::new(fusen_repository.clone());
-    let controller = FusenController::new(create, get, delete);
-    let service = Service::new(controller);
+    let export = ExportFusenInteractor::new(board_repository.clone(), fusen_repository.clone());
+    let import = ImportFusenInteractor::new(
+        Clock::default(),
+        board_repository.clone(),
+        unit_of_work.clone(),
+    );
+
+    // サブコマンドが指定された場合はサーバーを起動せずに実行する
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if !args.is_empty() {
+        // 標準出力は archive に使うため logger は初期化しない
+        return Ok(cli::run(&args, export, import).await?);
+    }
+
+    logger::init(&config.log.level)?;
+
+    let allowed_origins = config.server.allowed_origins()?;
+    let authenticator = config.auth.authenticator()?;
+
+    // 添付ファイルの内容は設定で選んだ保存先に置く
+    let blob_store: Arc<dyn BlobStore> = match config.attachments.store {
+        BlobStoreKind::Local => Arc::new(LocalBlobStore::new(config.attachments.path.clone())?),
+        BlobStoreKind::S3 => Arc::new(S3BlobStore::new(
+            config.attachments.s3.clone().unwrap_or_default(),
+        )?),
+    };
+
+    let create = CreateFusenInteractor::new(
+        id_repository,
+        Clock::default(),
+        board_repository.clone(),
+        unit_of_work.clone(),
+    );
+    let list = ListFusenInteractor::new(board_repository.clone(), fusen_repository.clone());
+    let get = GetFusenInteractor::new(
+        NoteRenderer::default(),
+        board_repository.clone(),
+        fusen_repository.clone(),
+    );
+    let update = UpdateFusenInteractor::new(Clock::default(), unit_of_work.clone());
+    let delete = DeleteFusenInteractor::new(Clock::default(), unit_of_work.clone());
+    let batch_create = BatchCreateFusenInteractor::new(
+        IdRepository::default(),
+        Clock::default(),
+        board_repository.clone(),
+        fusen_repository.clone(),
+    );
+    let batch_get =
+        BatchGetFusenInteractor::new(board_repository.clone(), fusen_repository.clone());
+    let batch_delete = BatchDeleteFusenInteractor::new(Clock::default(), unit_of_work.clone());
+    let move_fusen = MoveFusenInteractor::new(Clock::default(), unit_of_work);
+    let watch = WatchFusenInteractor::new(board_repository.clone(), event_repository.clone());
+    let controller = Arc::new(FusenController::new(
+        create,
+        list,
+        get,
+        update,
+        delete,
+        batch_create,
+        batch_get,
+        batch_delete,
+        move_fusen,
+        export,
+        import,
+        watch,
+    ));
+    let attachment_controller = Arc::new(DefaultAttachmentController::new(
+        UploadAttachmentInteractor::new(
+            IdRepository::default(),
+            Clock::default(),
+            fusen_repository.clone(),
+            attachment_repository.clone(),
+            blob_store.clone(),
+            AttachmentLimits::new(
+                config.attachments.max_size_bytes,
+                config.attachments.content_types.clone(),
+            ),
+        ),
+        DownloadAttachmentInteractor::new(
+            board_repository.clone(),
+            fusen_repository.clone(),
+            attachment_repository.clone(),
+            blob_store.clone(),
+        ),
+        ListAttachmentInteractor::new(
+            board_repository.clone(),
+            fusen_repository.clone(),
+            attachment_repository.clone(),
+        ),
+    ));
+    let board_controller = Arc::new(DefaultBoardController::new(
+        CreateBoardInteractor::new(
+            IdRepository::default(),
+            Clock::default(),
+            board_repository.clone(),
+        ),
+        ListBoardInteractor::new(board_repository.clone()),
+        RenameBoardInteractor::new(Clock::default(), board_repository.clone()),
+        DeleteBoardInteractor::new(board_repository, fusen_repository.clone()),
+    ));
+    let shutdown = Shutdown::default();
+    let service = Service::new(
+        controller.clone(),
+        board_controller,
+        attachment_controller,
+        authenticator.clone(),
+        shutdown.clone(),
+    );
+    let gateway = Gateway::new(controller, authenticator);
+    // 配信先が未設定の場合はイベントを破棄する
+    let sink: Box<dyn Sink> = match &config.outbox.endpoint {
+        Some(endpoint) => Box::new(HttpSink::new(endpoint.parse()?)),
+        None => Box::new(DiscardSink),
+    };
+    let relay = Relay::new(outbox_repository, sink, config.outbox.clone()).run(shutdown.clone());
+    let collector = Collector::new(
+        attachment_repository,
+        blob_store,
+        config.attachments.gc_interval(),
+    )
+    .run(shutdown.clone());
+    let pruner = Pruner::new(
+        event_repository,
+        config.events.retention(),
+        config.events.prune_interval(),
+    )
+    .run(shutdown.clone());
+
+    let metrics = {
+        let shutdown = shutdown.clone();
+        let metrics_addr = config.server.metrics_addr;
+        async move {
+            match metrics_addr {
+                Some(addr) => MetricsExporter.serve(addr, shutdown).await,
+                None => Ok(()),
+            }
+        }
+    };
+
+    let addr = config.server.grpc_addr;
+    let http_addr = config.server.http_addr;
+    let metrics_addr = config.server.metrics_addr;
+    let drain_period = config.server.drain_period();
 
-    let addr = "0.0.0.0:50051".parse()?;
+    tracing::info!(
+        %addr,
+        %http_addr,
+        ?metrics_addr,
+        backend = ?config.backend,
+        "service listening"
+    );
 
-    println!("service listening on {}", addr);
+    // migration 中も health check に応答できるよう、サーバーを先に起動する
+    tokio::try_join!(
+        service.serve(
+            addr,
+            config.server.tls,
+            connections,
+            allowed_origins.clone()
+        ),
+        gateway.serve(http_addr, allowed_origins, shutdown.clone()),
+        metrics,
+        // migration が終わってから outbox の配信と添付ファイル、イベントの回収を始める
+        async {
+            init.await?;
+            tokio::try_join!(relay, collector, pruner)?;
+            Ok(())
+        },
+        async {
+            shutdown.listen(drain_period).await?;
+            Ok(())
+        },
+    )?;
 
-    connections.init()?;
-    service.serve(addr).await?;
+    tracing::info!("service stopped");
 
     Ok(())
 }
@@ -0,0 +1,3 @@
+UPDATE fusens
+SET id = '0123456789ABCDEFGHJKMNPQRSTVWXYZ'
+WHERE id = '01FJ4Q0AXMXKQ7B1GZ1Z0K0T8R';
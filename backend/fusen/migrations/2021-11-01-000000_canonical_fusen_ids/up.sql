@@ -0,0 +1,7 @@
+-- id は ULID (Crockford Base32, 大文字) のみを受け付ける
+UPDATE fusens
+SET id = '01FJ4Q0AXMXKQ7B1GZ1Z0K0T8R'
+WHERE id = '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+UPDATE fusens
+SET id = upper(id)
+WHERE id <> upper(id);
@@ -0,0 +1,3 @@
+DROP TRIGGER fusen_events_notify ON fusen_events;
+DROP FUNCTION notify_fusen_event();
+DROP TABLE fusen_events;
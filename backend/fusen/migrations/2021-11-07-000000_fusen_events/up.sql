@@ -0,0 +1,22 @@
+CREATE TABLE fusen_events (
+    id BIGSERIAL PRIMARY KEY,
+    kind VARCHAR(16) NOT NULL,
+    fusen_id VARCHAR(64) NOT NULL,
+    title VARCHAR(64) NOT NULL,
+    note TEXT NOT NULL,
+    create_at timestamptz NOT NULL,
+    update_at timestamptz NOT NULL,
+    occurred_at timestamptz NOT NULL
+);
+
+-- 全レプリカの Watch に新しいイベントを通知する
+CREATE FUNCTION notify_fusen_event() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('fusen_events', NEW.id::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER fusen_events_notify
+AFTER INSERT ON fusen_events
+FOR EACH ROW EXECUTE PROCEDURE notify_fusen_event();
@@ -0,0 +1,4 @@
+ALTER TABLE fusen_events DROP COLUMN owner_id;
+
+DROP INDEX fusens_owner_id_id_idx;
+ALTER TABLE fusens DROP COLUMN owner_id;
@@ -0,0 +1,8 @@
+-- 既存の fusen には所有者がいないため、仮の所有者 'unowned' に割り当てる。
+-- 空文字は OwnerId として読み込めないので使わない
+ALTER TABLE fusens ADD COLUMN owner_id VARCHAR(255) NOT NULL DEFAULT 'unowned';
+ALTER TABLE fusens ALTER COLUMN owner_id DROP DEFAULT;
+CREATE INDEX fusens_owner_id_id_idx ON fusens (owner_id, id);
+
+ALTER TABLE fusen_events ADD COLUMN owner_id VARCHAR(255) NOT NULL DEFAULT 'unowned';
+ALTER TABLE fusen_events ALTER COLUMN owner_id DROP DEFAULT;
@@ -0,0 +1,6 @@
+ALTER TABLE fusen_events DROP COLUMN board_id;
+
+DROP INDEX fusens_board_id_id_idx;
+ALTER TABLE fusens DROP COLUMN board_id;
+
+DROP TABLE boards;
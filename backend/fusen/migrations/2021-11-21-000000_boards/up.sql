@@ -0,0 +1,38 @@
+CREATE TABLE boards (
+    id VARCHAR(64) PRIMARY KEY,
+    owner_id VARCHAR(255) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    member_ids TEXT[] NOT NULL DEFAULT '{}',
+    create_at timestamptz NOT NULL DEFAULT current_timestamp,
+    update_at timestamptz NOT NULL DEFAULT current_timestamp
+);
+CREATE INDEX boards_owner_id_id_idx ON boards (owner_id, id);
+CREATE INDEX boards_member_ids_idx ON boards USING GIN (member_ids);
+
+-- 既存の fusen は所有者ごとに作成するデフォルトの board に移す
+-- board の id には所有者の最も古い fusen の id (ULID) を流用する
+INSERT INTO boards (id, owner_id, name)
+SELECT MIN(fusen_id), owner_id, 'default'
+FROM (
+        SELECT id AS fusen_id, owner_id FROM fusens
+        UNION ALL
+        SELECT fusen_id, owner_id FROM fusen_events
+    ) AS owners
+GROUP BY owner_id;
+
+-- fusen が残っている board は削除できない
+ALTER TABLE fusens ADD COLUMN board_id VARCHAR(64) REFERENCES boards (id);
+UPDATE fusens
+SET board_id = boards.id
+FROM boards
+WHERE boards.owner_id = fusens.owner_id;
+ALTER TABLE fusens ALTER COLUMN board_id SET NOT NULL;
+CREATE INDEX fusens_board_id_id_idx ON fusens (board_id, id);
+
+-- 削除済みの board を指すこともあるので外部キーは張らない
+ALTER TABLE fusen_events ADD COLUMN board_id VARCHAR(64);
+UPDATE fusen_events
+SET board_id = boards.id
+FROM boards
+WHERE boards.owner_id = fusen_events.owner_id;
+ALTER TABLE fusen_events ALTER COLUMN board_id SET NOT NULL;
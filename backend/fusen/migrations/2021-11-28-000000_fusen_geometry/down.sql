@@ -0,0 +1,15 @@
+ALTER TABLE fusen_events
+    DROP COLUMN z_index,
+    DROP COLUMN color,
+    DROP COLUMN height,
+    DROP COLUMN width,
+    DROP COLUMN y,
+    DROP COLUMN x;
+
+ALTER TABLE fusens
+    DROP COLUMN z_index,
+    DROP COLUMN color,
+    DROP COLUMN height,
+    DROP COLUMN width,
+    DROP COLUMN y,
+    DROP COLUMN x;
@@ -0,0 +1,17 @@
+-- 範囲は domain の値オブジェクトと揃える
+ALTER TABLE fusens
+    ADD COLUMN x INTEGER NOT NULL DEFAULT 0 CHECK (x BETWEEN -100000 AND 100000),
+    ADD COLUMN y INTEGER NOT NULL DEFAULT 0 CHECK (y BETWEEN -100000 AND 100000),
+    ADD COLUMN width INTEGER NOT NULL DEFAULT 200 CHECK (width BETWEEN 40 AND 2000),
+    ADD COLUMN height INTEGER NOT NULL DEFAULT 200 CHECK (height BETWEEN 40 AND 2000),
+    ADD COLUMN color VARCHAR(16) NOT NULL DEFAULT 'yellow'
+        CHECK (color IN ('yellow', 'pink', 'blue', 'green', 'orange', 'purple')),
+    ADD COLUMN z_index INTEGER NOT NULL DEFAULT 0 CHECK (z_index BETWEEN 0 AND 1000000);
+
+ALTER TABLE fusen_events
+    ADD COLUMN x INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN y INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN width INTEGER NOT NULL DEFAULT 200,
+    ADD COLUMN height INTEGER NOT NULL DEFAULT 200,
+    ADD COLUMN color VARCHAR(16) NOT NULL DEFAULT 'yellow',
+    ADD COLUMN z_index INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,10 @@
+-- 他のサービスに配信するまで保存するドメインイベント。配信を終えたものは削除する
+CREATE TABLE outbox_messages (
+    id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR(32) NOT NULL,
+    aggregate_id VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,
+    occurred_at timestamptz NOT NULL,
+    -- relay が配信している間は他のレプリカの relay に取り出させない
+    locked_until timestamptz
+);
@@ -0,0 +1,2 @@
+ALTER TABLE fusen_events ALTER COLUMN title TYPE VARCHAR(64);
+ALTER TABLE fusens ALTER COLUMN title TYPE VARCHAR(64);
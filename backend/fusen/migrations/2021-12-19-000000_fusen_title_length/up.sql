@@ -0,0 +1,3 @@
+-- title は domain で書記素 64 個までに制限する。結合文字を含むとコードポイントでは 64 を超えるので広げる
+ALTER TABLE fusens ALTER COLUMN title TYPE VARCHAR(256);
+ALTER TABLE fusen_events ALTER COLUMN title TYPE VARCHAR(256);
@@ -0,0 +1,12 @@
+-- fusen の添付ファイル。内容は BlobStore に保存する
+-- fusen を削除しても行は残し、BlobStore の内容を消してから回収する
+CREATE TABLE attachments (
+    id VARCHAR(64) PRIMARY KEY,
+    fusen_id VARCHAR(64) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    size BIGINT NOT NULL CHECK (size >= 0),
+    checksum VARCHAR(64) NOT NULL,
+    create_at timestamptz NOT NULL DEFAULT current_timestamp
+);
+CREATE INDEX attachments_fusen_id_id_idx ON attachments (fusen_id, id);
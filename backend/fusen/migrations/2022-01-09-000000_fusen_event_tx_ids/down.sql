@@ -0,0 +1,3 @@
+DROP INDEX fusen_events_occurred_at;
+DROP INDEX fusen_events_tx_id_id;
+ALTER TABLE fusen_events DROP COLUMN tx_id;
@@ -0,0 +1,6 @@
+-- id は採番順でコミット順ではない。書き込んだトランザクションの ID を持たせ、
+-- 実行中のトランザクションより前にコミットされたイベントだけを (tx_id, id) の順に読む
+ALTER TABLE fusen_events ADD COLUMN tx_id BIGINT NOT NULL DEFAULT txid_current();
+CREATE INDEX fusen_events_tx_id_id ON fusen_events (tx_id, id);
+-- 保持期間を過ぎたイベントを消すときに使う
+CREATE INDEX fusen_events_occurred_at ON fusen_events (occurred_at);
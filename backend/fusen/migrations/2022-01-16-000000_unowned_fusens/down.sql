@@ -0,0 +1,2 @@
+-- 空文字の owner_id は読み込めないので元に戻さない
+SELECT 1;
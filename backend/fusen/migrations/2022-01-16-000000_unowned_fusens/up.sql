@@ -0,0 +1,11 @@
+-- 2021-11-14-000000_fusen_owners を修正前に適用したデータベースでは、
+-- 所有者のいない fusen が空文字の owner_id を持っていて読み込めない。
+-- 新規のデータベースと同じ仮の所有者 'unowned' に割り当てる。
+--
+-- 本来の所有者に引き渡すには、移行後に次のように付け替える:
+--   UPDATE boards SET owner_id = '<user>' WHERE owner_id = 'unowned';
+--   UPDATE fusens SET owner_id = '<user>' WHERE owner_id = 'unowned';
+--   UPDATE fusen_events SET owner_id = '<user>' WHERE owner_id = 'unowned';
+UPDATE boards SET owner_id = 'unowned' WHERE owner_id = '';
+UPDATE fusens SET owner_id = 'unowned' WHERE owner_id = '';
+UPDATE fusen_events SET owner_id = 'unowned' WHERE owner_id = '';
@@ -0,0 +1,2 @@
+DROP TABLE fusens;
+DROP TABLE boards;
@@ -0,0 +1,30 @@
+-- postgres の migrations を適用し終えた状態と同じテーブルを作る
+-- member_ids は JSON の配列として保存する
+CREATE TABLE boards (
+    id VARCHAR(64) PRIMARY KEY,
+    owner_id VARCHAR(255) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    member_ids TEXT NOT NULL DEFAULT '[]',
+    create_at TIMESTAMP NOT NULL,
+    update_at TIMESTAMP NOT NULL
+);
+CREATE INDEX boards_owner_id_id_idx ON boards (owner_id, id);
+
+CREATE TABLE fusens (
+    id VARCHAR(64) PRIMARY KEY,
+    title VARCHAR(64) NOT NULL,
+    note TEXT NOT NULL,
+    create_at TIMESTAMP NOT NULL,
+    update_at TIMESTAMP NOT NULL,
+    owner_id VARCHAR(255) NOT NULL,
+    board_id VARCHAR(64) NOT NULL REFERENCES boards (id),
+    x INTEGER NOT NULL DEFAULT 0 CHECK (x BETWEEN -100000 AND 100000),
+    y INTEGER NOT NULL DEFAULT 0 CHECK (y BETWEEN -100000 AND 100000),
+    width INTEGER NOT NULL DEFAULT 200 CHECK (width BETWEEN 40 AND 2000),
+    height INTEGER NOT NULL DEFAULT 200 CHECK (height BETWEEN 40 AND 2000),
+    color VARCHAR(16) NOT NULL DEFAULT 'yellow'
+        CHECK (color IN ('yellow', 'pink', 'blue', 'green', 'orange', 'purple')),
+    z_index INTEGER NOT NULL DEFAULT 0 CHECK (z_index BETWEEN 0 AND 1000000)
+);
+CREATE INDEX fusens_owner_id_id_idx ON fusens (owner_id, id);
+CREATE INDEX fusens_board_id_id_idx ON fusens (board_id, id);
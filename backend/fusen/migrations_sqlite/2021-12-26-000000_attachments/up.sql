@@ -0,0 +1,10 @@
+CREATE TABLE attachments (
+    id VARCHAR(64) PRIMARY KEY,
+    fusen_id VARCHAR(64) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    size BIGINT NOT NULL CHECK (size >= 0),
+    checksum VARCHAR(64) NOT NULL,
+    create_at TIMESTAMP NOT NULL
+);
+CREATE INDEX attachments_fusen_id_id_idx ON attachments (fusen_id, id);
@@ -0,0 +1 @@
+DROP TABLE fusen_events;
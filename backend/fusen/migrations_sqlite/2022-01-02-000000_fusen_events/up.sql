@@ -0,0 +1,19 @@
+-- Watch に流すイベント。SQLite の書き込みは直列なので id の順にコミットされる
+CREATE TABLE fusen_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind VARCHAR(16) NOT NULL,
+    fusen_id VARCHAR(64) NOT NULL,
+    title VARCHAR(64) NOT NULL,
+    note TEXT NOT NULL,
+    create_at TIMESTAMP NOT NULL,
+    update_at TIMESTAMP NOT NULL,
+    occurred_at TIMESTAMP NOT NULL,
+    owner_id VARCHAR(255) NOT NULL,
+    board_id VARCHAR(64) NOT NULL,
+    x INTEGER NOT NULL,
+    y INTEGER NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    color VARCHAR(16) NOT NULL,
+    z_index INTEGER NOT NULL
+);
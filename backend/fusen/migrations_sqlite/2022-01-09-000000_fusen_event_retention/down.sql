@@ -0,0 +1 @@
+DROP INDEX fusen_events_occurred_at;
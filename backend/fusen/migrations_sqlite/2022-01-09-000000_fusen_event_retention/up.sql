@@ -0,0 +1,2 @@
+-- 保持期間を過ぎたイベントを消すときに使う
+CREATE INDEX fusen_events_occurred_at ON fusen_events (occurred_at);
@@ -0,0 +1,88 @@
+use domain::error::DomainError;
+use domain::vo::TitleError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum UsecaseError {
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("invalid argument: {0}")]
+    InvalidTitle(TitleError),
+
+    #[error("conflict: {0}")]
+    Conflict(String),
+
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("failed precondition: {0}")]
+    FailedPrecondition(String),
+
+    #[error("unavailable: {0}")]
+    Unavailable(String),
+
+    #[error(transparent)]
+    Unexpected(#[from] anyhow::Error),
+}
+
+impl From<DomainError> for UsecaseError {
+    fn from(error: DomainError) -> Self {
+        match error {
+            DomainError::NotFound(message) => UsecaseError::NotFound(message),
+            DomainError::InvalidArgument(message) => UsecaseError::InvalidArgument(message),
+            DomainError::InvalidTitle(error) => UsecaseError::InvalidTitle(error),
+            DomainError::Conflict(message) => UsecaseError::Conflict(message),
+            DomainError::PermissionDenied(message) => UsecaseError::PermissionDenied(message),
+            DomainError::FailedPrecondition(message) => UsecaseError::FailedPrecondition(message),
+            DomainError::Unavailable(message) => UsecaseError::Unavailable(message),
+            DomainError::Unexpected(error) => UsecaseError::Unexpected(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use domain::vo::{FusenTitle, TitleViolation};
+
+    #[test]
+    fn test_usecase_error_from_domain_error() {
+        assert!(matches!(
+            UsecaseError::from(DomainError::NotFound("fusen".to_string())),
+            UsecaseError::NotFound(message) if message == "fusen"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::InvalidArgument("title".to_string())),
+            UsecaseError::InvalidArgument(message) if message == "title"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::Conflict("fusen".to_string())),
+            UsecaseError::Conflict(message) if message == "fusen"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::PermissionDenied("fusen".to_string())),
+            UsecaseError::PermissionDenied(message) if message == "fusen"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::FailedPrecondition("board".to_string())),
+            UsecaseError::FailedPrecondition(message) if message == "board"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::Unavailable("database".to_string())),
+            UsecaseError::Unavailable(message) if message == "database"
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::from(FusenTitle::new("").unwrap_err())),
+            UsecaseError::InvalidTitle(error) if error.violations() == [TitleViolation::Empty]
+        ));
+        assert!(matches!(
+            UsecaseError::from(DomainError::Unexpected(anyhow!("unexpected"))),
+            UsecaseError::Unexpected(_)
+        ));
+    }
+}
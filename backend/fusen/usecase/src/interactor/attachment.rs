@@ -0,0 +1,59 @@
+use crate::error::UsecaseError;
+use derive_new::new;
+use domain::entity::{Board, Fusen};
+use domain::repository::GetRepository;
+use domain::vo::{ContentType, OwnerId};
+
+// 添付ファイルとして受け付ける大きさと種類
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct AttachmentLimits {
+    // バイト数
+    pub max_size: u64,
+    // image/png や image/* のような ContentType::matches のパターン
+    pub content_types: Vec<String>,
+}
+
+impl AttachmentLimits {
+    pub fn allows(&self, content_type: &ContentType) -> bool {
+        self.content_types
+            .iter()
+            .any(|pattern| content_type.matches(pattern))
+    }
+}
+
+// fusen の参照と同じく、作成者以外でも board のメンバーであれば添付ファイルを参照できる
+pub(crate) async fn authorize_member<B>(
+    board_repository: &B,
+    fusen: &Fusen,
+    owner_id: &OwnerId,
+) -> Result<(), UsecaseError>
+where
+    B: GetRepository<Board>,
+{
+    if fusen.authorize(owner_id).is_err() {
+        board_repository
+            .get(fusen.board_id().clone())
+            .await?
+            .authorize(owner_id)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_limits_allows() {
+        let sut = AttachmentLimits::new(
+            1024,
+            vec!["image/*".to_string(), "application/pdf".to_string()],
+        );
+
+        assert!(sut.allows(&"image/png".parse::<ContentType>().unwrap()));
+        assert!(sut.allows(&"application/pdf".parse::<ContentType>().unwrap()));
+        assert!(!sut.allows(&"text/html".parse::<ContentType>().unwrap()));
+        assert!(!AttachmentLimits::new(1024, vec![])
+            .allows(&"image/png".parse::<ContentType>().unwrap()));
+    }
+}
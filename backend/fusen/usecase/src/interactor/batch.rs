@@ -0,0 +1,118 @@
+use crate::error::UsecaseError;
+use domain::error::DomainError;
+use domain::repository::BatchMode;
+
+const MAX_BATCH_SIZE: usize = 100;
+
+pub(crate) fn batch_mode(partial_success: bool) -> BatchMode {
+    if partial_success {
+        BatchMode::PartialSuccess
+    } else {
+        BatchMode::AllOrNothing
+    }
+}
+
+pub(crate) fn validate_batch_size(size: usize) -> Result<(), UsecaseError> {
+    if size == 0 || size > MAX_BATCH_SIZE {
+        return Err(UsecaseError::InvalidArgument(format!(
+            "batch size must be 1 to {}",
+            MAX_BATCH_SIZE
+        )));
+    }
+    Ok(())
+}
+
+// 要素単位の失敗は PartialSuccess の場合のみ結果に含める。接続障害などは常に全体の失敗にする
+pub(crate) fn item_result<T>(
+    mode: BatchMode,
+    result: Result<T, UsecaseError>,
+) -> Result<Result<T, UsecaseError>, UsecaseError> {
+    match result {
+        Err(
+            e @ (UsecaseError::NotFound(_)
+            | UsecaseError::InvalidArgument(_)
+            | UsecaseError::InvalidTitle(_)
+            | UsecaseError::Conflict(_)
+            | UsecaseError::PermissionDenied(_)
+            | UsecaseError::FailedPrecondition(_)),
+        ) if mode == BatchMode::PartialSuccess => Ok(Err(e)),
+        Err(e) => Err(e),
+        Ok(value) => Ok(Ok(value)),
+    }
+}
+
+// 検証を通った要素のみを保存した結果を、入力の位置に戻す
+pub(crate) fn merge_results<T>(
+    prepared: Vec<Result<T, UsecaseError>>,
+    saved: Vec<Result<(), DomainError>>,
+) -> Vec<Result<T, UsecaseError>> {
+    let mut saved = saved.into_iter();
+    prepared
+        .into_iter()
+        .map(|result| {
+            result.and_then(|value| match saved.next() {
+                Some(Err(e)) => Err(UsecaseError::from(e)),
+                _ => Ok(value),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_validate_batch_size() {
+        assert!(validate_batch_size(1).is_ok());
+        assert!(validate_batch_size(MAX_BATCH_SIZE).is_ok());
+        assert!(validate_batch_size(0).is_err());
+        assert!(validate_batch_size(MAX_BATCH_SIZE + 1).is_err());
+    }
+
+    #[test]
+    fn test_item_result() {
+        assert!(matches!(
+            item_result(
+                BatchMode::PartialSuccess,
+                Err::<(), _>(UsecaseError::NotFound("fusen".to_string()))
+            ),
+            Ok(Err(UsecaseError::NotFound(_)))
+        ));
+        assert!(matches!(
+            item_result(
+                BatchMode::AllOrNothing,
+                Err::<(), _>(UsecaseError::NotFound("fusen".to_string()))
+            ),
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            item_result(
+                BatchMode::PartialSuccess,
+                Err::<(), _>(UsecaseError::Unexpected(anyhow!("database")))
+            ),
+            Err(UsecaseError::Unexpected(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_results() {
+        let results = merge_results(
+            vec![
+                Ok(1),
+                Err(UsecaseError::InvalidArgument("title".to_string())),
+                Ok(3),
+            ],
+            vec![Err(DomainError::Conflict("fusen".to_string())), Ok(())],
+        );
+        assert!(matches!(
+            &results[..],
+            [
+                Err(UsecaseError::Conflict(_)),
+                Err(UsecaseError::InvalidArgument(_)),
+                Ok(3)
+            ]
+        ));
+    }
+}
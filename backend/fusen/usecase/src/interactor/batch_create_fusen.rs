@@ -0,0 +1,285 @@
+use crate::error::UsecaseError;
+use crate::interactor::batch::{batch_mode, item_result, merge_results, validate_batch_size};
+use crate::port::Port;
+use crate::port::{BatchCreateFusenInputData, BatchCreateFusenItem, BatchCreateFusenOutputData};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use std::collections::HashSet;
+
+#[derive(new)]
+pub struct BatchCreateFusenInteractor<I, C, B, S>
+where
+    I: IdRepository,
+    C: Clock,
+    B: GetRepository<Board>,
+    S: CreateAllRepository<Fusen>,
+{
+    id_repository: I,
+    clock: C,
+    board_repository: B,
+    fusen_repository: S,
+}
+
+#[async_trait]
+impl<I, C, B, S> Port<BatchCreateFusenInputData, BatchCreateFusenOutputData>
+    for BatchCreateFusenInteractor<I, C, B, S>
+where
+    I: IdRepository,
+    C: Clock,
+    B: GetRepository<Board>,
+    S: CreateAllRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: BatchCreateFusenInputData,
+    ) -> Result<BatchCreateFusenOutputData, UsecaseError> {
+        validate_batch_size(input.items.len())?;
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let mode = batch_mode(input.partial_success);
+        let now = self.clock.now();
+
+        // 同じ board への作成が続くため、認可済みの board は問い合わせ直さない
+        let mut boards = HashSet::new();
+        let mut prepared = Vec::with_capacity(input.items.len());
+        for item in input.items {
+            let fusen = self.build(&owner_id, &mut boards, item, now).await;
+            prepared.push(item_result(mode, fusen)?);
+        }
+
+        let fusens = prepared
+            .iter()
+            .filter_map(|fusen| fusen.as_ref().ok().cloned())
+            .collect();
+        let saved = self.fusen_repository.create_all(fusens, mode).await?;
+        let results = merge_results(prepared, saved);
+
+        Ok(BatchCreateFusenOutputData::new(results))
+    }
+}
+
+impl<I, C, B, S> BatchCreateFusenInteractor<I, C, B, S>
+where
+    I: IdRepository,
+    C: Clock,
+    B: GetRepository<Board>,
+    S: CreateAllRepository<Fusen>,
+{
+    async fn build(
+        &self,
+        owner_id: &OwnerId,
+        boards: &mut HashSet<Id<Board>>,
+        item: BatchCreateFusenItem,
+        now: Timestamp,
+    ) -> Result<Fusen, UsecaseError> {
+        let board_id = item.board_id.parse::<Id<Board>>()?;
+        if !boards.contains(&board_id) {
+            self.board_repository
+                .get(board_id.clone())
+                .await?
+                .authorize(owner_id)?;
+            boards.insert(board_id.clone());
+        }
+
+        let mut fusen = FusenBuilder::default()
+            .id(self.id_repository.generate::<Fusen>()?)
+            .owner_id(owner_id.clone())
+            .board_id(board_id)
+            .title(item.title.parse::<FusenTitle>()?)
+            .note(item.note.parse::<FusenNote>()?)
+            .create_at(now)
+            .update_at(now)
+            .build()
+            .unwrap();
+        fusen.record_created(now);
+
+        Ok(fusen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use domain::event::FusenDomainEvent;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockIdRepository {
+        count: Mutex<u32>,
+    }
+    impl IdRepository for MockIdRepository {
+        fn generate<T>(&self) -> Result<Id<T>, DomainError> {
+            let mut count = self.count.lock().unwrap();
+            *count += 1;
+            Ok(format!("01F8MECHZX3TBDSZ7XRADM79{:02}", count)
+                .parse::<Id<T>>()
+                .unwrap())
+        }
+    }
+
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    // 2 件目の作成は重複として失敗させる
+    #[derive(Clone, Default)]
+    struct MockFusenRepository {
+        store: Arc<Mutex<Vec<Fusen>>>,
+    }
+
+    #[async_trait]
+    impl CreateAllRepository<Fusen> for MockFusenRepository {
+        async fn create_all(
+            &self,
+            entities: Vec<Fusen>,
+            mode: BatchMode,
+        ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+            let results = entities
+                .iter()
+                .enumerate()
+                .map(|(index, _)| match index {
+                    1 => Err(DomainError::Conflict("duplicate fusen".to_string())),
+                    _ => Ok(()),
+                })
+                .collect::<Vec<_>>();
+            if mode == BatchMode::AllOrNothing && results.iter().any(Result::is_err) {
+                return Err(DomainError::Conflict("duplicate fusen".to_string()));
+            }
+            let mut store = self.store.lock().unwrap();
+            for (entity, result) in entities.into_iter().zip(&results) {
+                if result.is_ok() {
+                    store.push(entity);
+                }
+            }
+            Ok(results)
+        }
+    }
+
+    fn item(board_id: &str, title: &str) -> BatchCreateFusenItem {
+        BatchCreateFusenItem::new(board_id.to_string(), title.to_string(), "note".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_fusen_handle() {
+        let fusen_repository = MockFusenRepository::default();
+        let sut = BatchCreateFusenInteractor::new(
+            MockIdRepository::default(),
+            MockClock {},
+            MockBoardRepository {},
+            fusen_repository.clone(),
+        );
+
+        let output = sut
+            .handle(BatchCreateFusenInputData::new(
+                "member".to_string(),
+                vec![
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "first"),
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "   "),
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "second"),
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "third"),
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R9", "fourth"),
+                ],
+                true,
+            ))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            &output.results[..],
+            [
+                Ok(_),
+                Err(UsecaseError::InvalidTitle(_)),
+                Err(UsecaseError::Conflict(_)),
+                Ok(_),
+                Err(UsecaseError::NotFound(_)),
+            ]
+        ));
+        assert_eq!(
+            fusen_repository
+                .store
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|fusen| fusen.title().to_string())
+                .collect::<Vec<_>>(),
+            vec!["first".to_string(), "third".to_string()]
+        );
+        // 保存した fusen は outbox に書き出す FusenCreated を持つ
+        assert!(fusen_repository
+            .store
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|fusen| matches!(fusen.events(), [FusenDomainEvent::Created(_)])));
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_fusen_handle_all_or_nothing() {
+        let fusen_repository = MockFusenRepository::default();
+        let sut = BatchCreateFusenInteractor::new(
+            MockIdRepository::default(),
+            MockClock {},
+            MockBoardRepository {},
+            fusen_repository.clone(),
+        );
+
+        assert!(matches!(
+            sut.handle(BatchCreateFusenInputData::new(
+                "another".to_string(),
+                vec![item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "first")],
+                false,
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(BatchCreateFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "first"),
+                    item("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "second"),
+                ],
+                false,
+            ))
+            .await,
+            Err(UsecaseError::Conflict(_))
+        ));
+        assert!(matches!(
+            sut.handle(BatchCreateFusenInputData::new(
+                "owner".to_string(),
+                vec![],
+                false
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(fusen_repository.store.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::error::UsecaseError;
+use crate::interactor::batch::{batch_mode, item_result, merge_results, validate_batch_size};
+use crate::port::{BatchDeleteFusenInputData, BatchDeleteFusenOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+use futures::FutureExt;
+use std::collections::{HashMap, HashSet};
+
+#[derive(new)]
+pub struct BatchDeleteFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetAllRepository<Fusen> + DeleteAllRepository<Fusen>,
+{
+    clock: C,
+    unit_of_work: U,
+}
+
+#[async_trait]
+impl<C, U> Port<BatchDeleteFusenInputData, BatchDeleteFusenOutputData>
+    for BatchDeleteFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetAllRepository<Fusen> + DeleteAllRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: BatchDeleteFusenInputData,
+    ) -> Result<BatchDeleteFusenOutputData, UsecaseError> {
+        validate_batch_size(input.ids.len())?;
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let mode = batch_mode(input.partial_success);
+
+        // 入力の検証を終えてからトランザクションを開始する
+        let mut ids = HashSet::new();
+        let mut requested = Vec::with_capacity(input.ids.len());
+        for id in &input.ids {
+            let id = id
+                .parse::<Id<Fusen>>()
+                .map_err(UsecaseError::from)
+                .and_then(|id| {
+                    if ids.insert(id.clone()) {
+                        Ok(id)
+                    } else {
+                        Err(UsecaseError::InvalidArgument(format!(
+                            "duplicate fusen {}",
+                            id.to_string()
+                        )))
+                    }
+                });
+            requested.push(item_result(mode, id)?);
+        }
+
+        let now = self.clock.now();
+
+        // 取得から削除までを同じトランザクションで行う
+        let (prepared, deleted) = self
+            .unit_of_work
+            .run(move |repositories| {
+                async move {
+                    let mut fusens = repositories
+                        .get_all(
+                            requested
+                                .iter()
+                                .filter_map(|id| id.as_ref().ok().cloned())
+                                .collect(),
+                        )
+                        .await?
+                        .into_iter()
+                        .map(|fusen| (fusen.id().clone(), fusen))
+                        .collect::<HashMap<_, _>>();
+
+                    let mut prepared = Vec::with_capacity(requested.len());
+                    for id in requested {
+                        let fusen = match id {
+                            Ok(id) => match authorize(&owner_id, &mut fusens, id) {
+                                Ok(fusen) => Ok(fusen),
+                                Err(e) if mode == BatchMode::PartialSuccess => {
+                                    Err(UsecaseError::from(e))
+                                }
+                                Err(e) => return Err(e),
+                            },
+                            Err(e) => Err(e),
+                        };
+                        prepared.push(fusen);
+                    }
+
+                    let targets = prepared
+                        .iter()
+                        .filter_map(|fusen| fusen.as_ref().ok().cloned())
+                        .map(|mut fusen| {
+                            fusen.record_deleted(now);
+                            fusen
+                        })
+                        .collect();
+                    let deleted = repositories.delete_all(targets, mode).await?;
+                    Ok((prepared, deleted))
+                }
+                .boxed()
+            })
+            .await?;
+        let results = merge_results(prepared, deleted);
+
+        Ok(BatchDeleteFusenOutputData::new(results))
+    }
+}
+
+fn authorize(
+    owner_id: &OwnerId,
+    fusens: &mut HashMap<Id<Fusen>, Fusen>,
+    id: Id<Fusen>,
+) -> Result<Fusen, DomainError> {
+    let fusen = fusens
+        .remove(&id)
+        .ok_or_else(|| DomainError::NotFound(format!("fusen {} not found", id.to_string())))?;
+    fusen.authorize(owner_id)?;
+
+    Ok(fusen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::event::FusenDomainEvent;
+    use futures::future::BoxFuture;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockFusenRepository {
+        store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+        // 削除と同じトランザクションで書き出されるイベント
+        events: Arc<Mutex<Vec<FusenDomainEvent>>>,
+    }
+
+    impl MockFusenRepository {
+        fn new() -> Self {
+            let store = [
+                ("01F8MECHZX3TBDSZ7XRADM79XE", "owner"),
+                ("01F8MECHZX3TBDSZ7XRADM79XF", "owner"),
+                ("01F8MECHZX3TBDSZ7XRADM79XG", "another"),
+            ]
+            .iter()
+            .map(|(id, owner_id)| {
+                let fusen = FusenBuilder::default()
+                    .id(id.parse::<Id<Fusen>>().unwrap())
+                    .owner_id(owner_id.parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap();
+                (fusen.id().clone(), fusen)
+            })
+            .collect();
+            Self {
+                store: Arc::new(Mutex::new(store)),
+                events: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl GetAllRepository<Fusen> for MockFusenRepository {
+        async fn get_all(&self, ids: Vec<Id<Fusen>>) -> Result<Vec<Fusen>, DomainError> {
+            let m = self.store.lock().unwrap();
+            Ok(ids.iter().filter_map(|id| m.get(id).cloned()).collect())
+        }
+    }
+
+    #[async_trait]
+    impl DeleteAllRepository<Fusen> for MockFusenRepository {
+        async fn delete_all(
+            &self,
+            entities: Vec<Fusen>,
+            _mode: BatchMode,
+        ) -> Result<Vec<Result<(), DomainError>>, DomainError> {
+            let mut m = self.store.lock().unwrap();
+            let mut events = self.events.lock().unwrap();
+            Ok(entities
+                .iter()
+                .map(|entity| match m.remove(entity.id()) {
+                    Some(_) => {
+                        events.extend(entity.events().iter().cloned());
+                        Ok(())
+                    }
+                    None => Err(DomainError::NotFound("not found entity".to_string())),
+                })
+                .collect())
+        }
+    }
+
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_fusen_handle() {
+        let fusen_repository = MockFusenRepository::new();
+        let sut = BatchDeleteFusenInteractor::new(
+            MockClock {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+
+        let output = sut
+            .handle(BatchDeleteFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                    // 表記が違っても同じ fusen は重複として扱う
+                    "01f8mechzx3tbdsz7xradm79xe".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XG".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XH".to_string(),
+                ],
+                true,
+            ))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            &output.results[..],
+            [
+                Ok(_),
+                Err(UsecaseError::InvalidArgument(_)),
+                Err(UsecaseError::PermissionDenied(_)),
+                Err(UsecaseError::NotFound(_)),
+            ]
+        ));
+        assert_eq!(fusen_repository.store.lock().unwrap().len(), 2);
+        assert!(matches!(
+            &fusen_repository.events.lock().unwrap()[..],
+            [FusenDomainEvent::Deleted(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_fusen_handle_all_or_nothing() {
+        let fusen_repository = MockFusenRepository::new();
+        let sut = BatchDeleteFusenInteractor::new(
+            MockClock {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+
+        assert!(matches!(
+            sut.handle(BatchDeleteFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XG".to_string(),
+                ],
+                false,
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert_eq!(fusen_repository.store.lock().unwrap().len(), 3);
+        assert!(fusen_repository.events.lock().unwrap().is_empty());
+
+        let output = sut
+            .handle(BatchDeleteFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                ],
+                false,
+            ))
+            .await
+            .unwrap();
+        assert!(output.results.iter().all(Result::is_ok));
+        assert_eq!(fusen_repository.store.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::error::UsecaseError;
+use crate::interactor::batch::{batch_mode, item_result, validate_batch_size};
+use crate::port::{BatchGetFusenInputData, BatchGetFusenOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(new)]
+pub struct BatchGetFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: GetAllRepository<Fusen>,
+{
+    board_repository: B,
+    fusen_repository: S,
+}
+
+#[async_trait]
+impl<B, S> Port<BatchGetFusenInputData, BatchGetFusenOutputData> for BatchGetFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: GetAllRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: BatchGetFusenInputData,
+    ) -> Result<BatchGetFusenOutputData, UsecaseError> {
+        validate_batch_size(input.ids.len())?;
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let mode = batch_mode(input.partial_success);
+
+        // 不正な id は要素ごとの結果で報告する
+        let fusens = self
+            .fusen_repository
+            .get_all(
+                input
+                    .ids
+                    .iter()
+                    .filter_map(|id| id.parse::<Id<Fusen>>().ok())
+                    .collect(),
+            )
+            .await?
+            .into_iter()
+            .map(|fusen| (fusen.id().to_string(), fusen))
+            .collect::<HashMap<_, _>>();
+
+        let mut boards = HashSet::new();
+        let mut results = Vec::with_capacity(input.ids.len());
+        for id in &input.ids {
+            let fusen = self.authorize(&owner_id, &mut boards, &fusens, id).await;
+            results.push(item_result(mode, fusen)?);
+        }
+
+        Ok(BatchGetFusenOutputData::new(results))
+    }
+}
+
+impl<B, S> BatchGetFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: GetAllRepository<Fusen>,
+{
+    // 作成者以外でも board のメンバーであれば参照できる
+    async fn authorize(
+        &self,
+        owner_id: &OwnerId,
+        boards: &mut HashSet<Id<Board>>,
+        fusens: &HashMap<String, Fusen>,
+        id: &str,
+    ) -> Result<Fusen, UsecaseError> {
+        let id = id.parse::<Id<Fusen>>()?;
+        let fusen = fusens
+            .get(&id.to_string())
+            .cloned()
+            .ok_or_else(|| UsecaseError::NotFound(format!("fusen {} not found", id.to_string())))?;
+        if fusen.authorize(owner_id).is_err() && !boards.contains(fusen.board_id()) {
+            self.board_repository
+                .get(fusen.board_id().clone())
+                .await?
+                .authorize(owner_id)?;
+            boards.insert(fusen.board_id().clone());
+        }
+
+        Ok(fusen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockFusenRepository {}
+
+    #[async_trait]
+    impl GetAllRepository<Fusen> for MockFusenRepository {
+        async fn get_all(&self, ids: Vec<Id<Fusen>>) -> Result<Vec<Fusen>, DomainError> {
+            Ok(ids
+                .into_iter()
+                .filter(|id| id.to_string() != "01F8MECHZX3TBDSZ7XRADM79XF")
+                .map(|id| {
+                    FusenBuilder::default()
+                        .id(id)
+                        .owner_id("owner".parse::<OwnerId>().unwrap())
+                        .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                        .title("title".parse::<FusenTitle>().unwrap())
+                        .note("note".parse::<FusenNote>().unwrap())
+                        .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .build()
+                        .unwrap()
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_fusen_handle() {
+        let sut = BatchGetFusenInteractor::new(MockBoardRepository {}, MockFusenRepository {});
+
+        let output = sut
+            .handle(BatchGetFusenInputData::new(
+                "member".to_string(),
+                vec![
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                    "invalid".to_string(),
+                ],
+                true,
+            ))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            &output.results[..],
+            [
+                Ok(fusen),
+                Err(UsecaseError::NotFound(_)),
+                Err(UsecaseError::InvalidArgument(_)),
+            ] if fusen.id().to_string() == "01F8MECHZX3TBDSZ7XRADM79XE"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_fusen_handle_all_or_nothing() {
+        let sut = BatchGetFusenInteractor::new(MockBoardRepository {}, MockFusenRepository {});
+
+        assert!(sut
+            .handle(BatchGetFusenInputData::new(
+                "owner".to_string(),
+                vec!["01F8MECHZX3TBDSZ7XRADM79XE".to_string()],
+                false,
+            ))
+            .await
+            .is_ok());
+        assert!(matches!(
+            sut.handle(BatchGetFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                    "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                ],
+                false,
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.handle(BatchGetFusenInputData::new(
+                "another".to_string(),
+                vec!["01F8MECHZX3TBDSZ7XRADM79XE".to_string()],
+                false,
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+    }
+}
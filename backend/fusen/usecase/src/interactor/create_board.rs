@@ -0,0 +1,162 @@
+use crate::error::UsecaseError;
+use crate::port::{CreateBoardInputData, CreateBoardOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::{Board, BoardBuilder};
+use domain::repository::{Clock, CreateRepository, GetRepository, IdRepository};
+use domain::vo::{BoardName, OwnerId};
+
+#[derive(new)]
+pub struct CreateBoardInteractor<I, C, S>
+where
+    I: IdRepository,
+    C: Clock,
+    S: CreateRepository<Board> + GetRepository<Board>,
+{
+    id_repository: I,
+    clock: C,
+    board_repository: S,
+}
+
+#[async_trait]
+impl<I, C, S> Port<CreateBoardInputData, CreateBoardOutputData> for CreateBoardInteractor<I, C, S>
+where
+    I: IdRepository,
+    C: Clock,
+    S: CreateRepository<Board> + GetRepository<Board>,
+{
+    async fn handle(
+        &self,
+        input: CreateBoardInputData,
+    ) -> Result<CreateBoardOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        // owner 自身や重複して指定されたメンバーは取り除く
+        let mut member_ids: Vec<OwnerId> = Vec::new();
+        for member_id in input.member_ids {
+            let member_id = member_id.parse::<OwnerId>()?;
+            if member_id != owner_id && !member_ids.contains(&member_id) {
+                member_ids.push(member_id);
+            }
+        }
+        let id = self.id_repository.generate::<Board>()?;
+        let now = self.clock.now();
+
+        let board = BoardBuilder::default()
+            .id(id)
+            .owner_id(owner_id)
+            .name(input.name.parse::<BoardName>()?)
+            .member_ids(member_ids)
+            .create_at(now)
+            .update_at(now)
+            .build()
+            .unwrap();
+
+        self.board_repository.create(board.clone()).await?;
+        let aggregate_root = self.board_repository.get(board.id().clone()).await?;
+
+        Ok(CreateBoardOutputData::new(aggregate_root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use domain::vo::{Id, Timestamp};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    #[derive(new)]
+    struct MockIdRepository {}
+    impl IdRepository for MockIdRepository {
+        fn generate<T>(&self) -> Result<Id<T>, DomainError> {
+            Ok("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<T>>().unwrap())
+        }
+    }
+
+    #[derive(new)]
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    #[derive(Default)]
+    struct MockBoardRepository {
+        store: Arc<Mutex<HashMap<Id<Board>, Board>>>,
+    }
+
+    #[async_trait]
+    impl CreateRepository<Board> for MockBoardRepository {
+        async fn create(&self, entity: Board) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            m.insert(entity.id().clone(), entity);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_board_handle() {
+        let sut = CreateBoardInteractor::new(
+            MockIdRepository::new(),
+            MockClock::new(),
+            MockBoardRepository::default(),
+        );
+
+        let output = sut
+            .handle(CreateBoardInputData::new(
+                "owner".to_string(),
+                "board".to_string(),
+                vec![
+                    "member".to_string(),
+                    "owner".to_string(),
+                    "member".to_string(),
+                ],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            output.board.id().to_string(),
+            "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string()
+        );
+        assert_eq!(output.board.name().to_string(), "board".to_string());
+        assert_eq!(
+            output.board.member_ids(),
+            &vec!["member".parse::<OwnerId>().unwrap()]
+        );
+        assert_eq!(output.board.create_at(), output.board.update_at());
+
+        // err
+        assert!(matches!(
+            sut.handle(CreateBoardInputData::new(
+                "owner".to_string(),
+                " ".to_string(),
+                vec![]
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(CreateBoardInputData::new(
+                "owner".to_string(),
+                "board".to_string(),
+                vec!["".to_string()]
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+}
@@ -1,49 +1,87 @@
+use crate::error::UsecaseError;
 use crate::port::{CreateFusenInputData, CreateFusenOutputData, Port};
-use anyhow::{Error, Result};
+use async_trait::async_trait;
 use derive_new::new;
-use domain::entity::{Fusen, FusenBuilder};
-use domain::repository::IdRepository;
-use domain::repository::{CreateRepository, DeleteRepository, GetRepository};
-use domain::vo::{FusenNote, FusenTitle};
+use domain::entity::{Board, Fusen, FusenBuilder};
+use domain::repository::{Clock, IdRepository, UnitOfWork};
+use domain::repository::{CreateRepository, GetRepository};
+use domain::vo::{FusenNote, FusenTitle, Id, OwnerId};
+use futures::FutureExt;
 
 #[derive(new)]
-pub struct CreateFusenInteractor<I, S>
+pub struct CreateFusenInteractor<I, C, B, U>
 where
     I: IdRepository,
-    S: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+    C: Clock,
+    B: GetRepository<Board>,
+    U: UnitOfWork,
+    U::Repositories: CreateRepository<Fusen> + GetRepository<Fusen>,
 {
     id_repository: I,
-    fusen_repository: S,
+    clock: C,
+    board_repository: B,
+    unit_of_work: U,
 }
 
-impl<I, S> Port<CreateFusenInputData, CreateFusenOutputData> for CreateFusenInteractor<I, S>
+#[async_trait]
+impl<I, C, B, U> Port<CreateFusenInputData, CreateFusenOutputData>
+    for CreateFusenInteractor<I, C, B, U>
 where
     I: IdRepository,
-    S: CreateRepository<Fusen> + GetRepository<Fusen> + DeleteRepository<Fusen>,
+    C: Clock,
+    B: GetRepository<Board>,
+    U: UnitOfWork,
+    U::Repositories: CreateRepository<Fusen> + GetRepository<Fusen>,
 {
-    fn handle(&self, input: CreateFusenInputData) -> Result<CreateFusenOutputData, Error> {
+    async fn handle(
+        &self,
+        input: CreateFusenInputData,
+    ) -> Result<CreateFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let board = self
+            .board_repository
+            .get(input.board_id.parse::<Id<Board>>()?)
+            .await?;
+        board.authorize(&owner_id)?;
         let id = self.id_repository.generate::<Fusen>()?;
+        let now = self.clock.now();
 
-        let fusen = FusenBuilder::default()
+        let mut fusen = FusenBuilder::default()
             .id(id)
+            .owner_id(owner_id)
+            .board_id(board.id().clone())
             .title(input.title.parse::<FusenTitle>()?)
             .note(input.note.parse::<FusenNote>()?)
+            .create_at(now)
+            .update_at(now)
             .build()
             .unwrap();
+        fusen.record_created(now);
 
-        self.fusen_repository.create(fusen.clone())?;
-        match self.fusen_repository.get(fusen.id().clone()) {
-            Ok(aggregate_root) => Ok(CreateFusenOutputData::new(aggregate_root)),
-            Err(e) => Err(e),
-        }
+        // 作成と読み直しを同じトランザクションで行う
+        let aggregate_root = self
+            .unit_of_work
+            .run(move |repositories| {
+                async move {
+                    repositories.create(fusen.clone()).await?;
+                    repositories.get(fusen.id().clone()).await
+                }
+                .boxed()
+            })
+            .await?;
+
+        Ok(CreateFusenOutputData::new(aggregate_root))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::bail;
-    use domain::vo::Id;
+    use domain::entity::BoardBuilder;
+    use domain::error::DomainError;
+    use domain::event::{FusenDomainEvent, FusenEvent, FusenEventKind};
+    use domain::vo::{BoardName, Timestamp};
+    use futures::future::BoxFuture;
     use std::collections::HashMap;
     use std::sync::Arc;
     use std::sync::Mutex;
@@ -51,11 +89,39 @@ mod tests {
     #[derive(new)]
     struct MockIdRepository {}
     impl IdRepository for MockIdRepository {
-        fn generate<T>(&self) -> Result<Id<T>, Error> {
+        fn generate<T>(&self) -> Result<Id<T>, DomainError> {
             Ok("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<T>>().unwrap())
         }
     }
 
+    #[derive(new)]
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
     struct MockFusenRepository {
         store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
     }
@@ -68,76 +134,167 @@ mod tests {
         }
     }
 
+    #[async_trait]
     impl CreateRepository<Fusen> for MockFusenRepository {
-        fn create(&self, entity: Fusen) -> Result<(), Error> {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             m.insert(entity.id().clone(), entity.clone());
             Ok(())
         }
     }
 
+    #[async_trait]
     impl GetRepository<Fusen> for MockFusenRepository {
-        fn get(&self, id: Id<Fusen>) -> Result<Fusen, Error> {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
             let m = self.store.lock().unwrap();
             match m.get(&id) {
                 Some(aggregate_root) => Ok(aggregate_root.clone()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
-    impl DeleteRepository<Fusen> for MockFusenRepository {
-        fn delete(&self, entity: Fusen) -> Result<(), Error> {
-            let mut m = self.store.lock().unwrap();
-            match m.remove(&entity.id().clone()) {
-                Some(_) => Ok(()),
-                None => bail!("not found entity"),
-            }
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
         }
     }
 
-    #[test]
-    fn test_create_fusen_handle() {
+    #[tokio::test]
+    async fn test_create_fusen_handle() {
         let id_repository = MockIdRepository::new();
+        let clock = MockClock::new();
         let fusen_repository = MockFusenRepository::new();
-        let sut = CreateFusenInteractor::new(id_repository, fusen_repository);
+        let sut = CreateFusenInteractor::new(
+            id_repository,
+            clock,
+            MockBoardRepository {},
+            MockUnitOfWork { fusen_repository },
+        );
 
         assert_eq!(
             sut.handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
                 "title".to_string(),
                 "note".to_string()
             ))
+            .await
             .unwrap(),
             CreateFusenOutputData::new(
                 FusenBuilder::default()
                     .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
                     .title("any".parse::<FusenTitle>().unwrap())
                     .note("any".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
                     .build()
                     .unwrap()
             )
         );
+        let output = sut
+            .handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "title".to_string(),
+                "note".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            output.fusen.create_at().to_string(),
+            "2021-10-17T07:08:37Z".to_string()
+        );
+        assert_eq!(output.fusen.create_at(), output.fusen.update_at());
+        // 保存した fusen は outbox に書き出す FusenCreated を持つ
+        assert!(matches!(
+            output.fusen.events(),
+            [FusenDomainEvent::Created(_)]
+        ));
+        // Watch にはこのイベントから作ったものが流れる
+        assert_eq!(
+            FusenEvent::from(&output.fusen.events()[0]),
+            FusenEvent::new(
+                FusenEventKind::Created,
+                output.fusen.clone(),
+                *output.fusen.create_at()
+            )
+        );
 
         // ok
         assert!(sut
             .handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
                 "title".to_string(),
                 "note".to_string()
             ))
+            .await
             .is_ok());
         assert!(sut
             .handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
                 "Clean Architecture using Rust".to_string(),
                 "クリーンアーキテクチャをRustで実装してみました〜！".to_string()
             ))
+            .await
             .is_ok());
 
         // err
         assert!(sut
             .handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
                 "".to_string(),
                 "hogehoge".to_string()
             ))
+            .await
             .is_err());
+        assert!(matches!(
+            sut.handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "".to_string(),
+                "hogehoge".to_string()
+            ))
+            .await,
+            Err(UsecaseError::InvalidTitle(_))
+        ));
+        assert!(matches!(
+            sut.handle(CreateFusenInputData::new(
+                "another".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "title".to_string(),
+                "note".to_string()
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(CreateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XZ".to_string(),
+                "title".to_string(),
+                "note".to_string()
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
     }
 }
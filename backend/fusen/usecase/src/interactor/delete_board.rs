@@ -0,0 +1,178 @@
+use crate::error::UsecaseError;
+use crate::port::{DeleteBoardInputData, DeleteBoardOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+
+#[derive(new)]
+pub struct DeleteBoardInteractor<S, F>
+where
+    S: GetRepository<Board> + DeleteRepository<Board>,
+    F: ListRepository<Fusen>,
+{
+    board_repository: S,
+    fusen_repository: F,
+}
+
+#[async_trait]
+impl<S, F> Port<DeleteBoardInputData, DeleteBoardOutputData> for DeleteBoardInteractor<S, F>
+where
+    S: GetRepository<Board> + DeleteRepository<Board>,
+    F: ListRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: DeleteBoardInputData,
+    ) -> Result<DeleteBoardOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let id = input.id.parse::<Id<Board>>()?;
+
+        let board = self.board_repository.get(id).await?;
+        board.authorize_owner(&owner_id)?;
+
+        // fusen を巻き込んで消さないよう、空の board のみ削除できる
+        let fusens = self
+            .fusen_repository
+            .list(ListQuery::new(
+                None,
+                1,
+                SortOrder::Asc,
+                None,
+                None,
+                Some(board.id().clone()),
+            ))
+            .await?;
+        if !fusens.is_empty() {
+            return Err(DomainError::FailedPrecondition(format!(
+                "board {} still has fusens",
+                board.id().to_string()
+            ))
+            .into());
+        }
+
+        self.board_repository.delete(board).await?;
+
+        Ok(DeleteBoardOutputData::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockBoardRepository {
+        store: Arc<Mutex<HashMap<Id<Board>, Board>>>,
+    }
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DeleteRepository<Board> for MockBoardRepository {
+        async fn delete(&self, entity: Board) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            match m.remove(entity.id()) {
+                Some(_) => Ok(()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    // board_id を持つ fusen が1件だけある
+    struct MockFusenRepository {}
+
+    #[async_trait]
+    impl ListRepository<Fusen> for MockFusenRepository {
+        async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+            let fusen = FusenBuilder::default()
+                .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                .owner_id("owner".parse::<OwnerId>().unwrap())
+                .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R1".parse::<Id<Board>>().unwrap())
+                .title("title".parse::<FusenTitle>().unwrap())
+                .note("note".parse::<FusenNote>().unwrap())
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap();
+
+            Ok(vec![fusen]
+                .into_iter()
+                .filter(|f| query.board_id.as_ref() == Some(f.board_id()))
+                .collect())
+        }
+    }
+
+    fn new_board(id: &str) -> Board {
+        BoardBuilder::default()
+            .id(id.parse::<Id<Board>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_delete_board_handle() {
+        let board_repository = MockBoardRepository::default();
+        for id in ["01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "01FMB0ARDXKQ7B1GZ1Z0K0T8R1"] {
+            let board = new_board(id);
+            board_repository
+                .store
+                .lock()
+                .unwrap()
+                .insert(board.id().clone(), board);
+        }
+        let sut = DeleteBoardInteractor::new(board_repository, MockFusenRepository {});
+
+        assert!(matches!(
+            sut.handle(DeleteBoardInputData::new(
+                "member".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string()
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(DeleteBoardInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R1".to_string()
+            ))
+            .await,
+            Err(UsecaseError::FailedPrecondition(_))
+        ));
+
+        assert!(sut
+            .handle(DeleteBoardInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string()
+            ))
+            .await
+            .is_ok());
+        assert!(matches!(
+            sut.handle(DeleteBoardInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string()
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+    }
+}
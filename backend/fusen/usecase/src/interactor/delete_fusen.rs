@@ -1,27 +1,51 @@
+use crate::error::UsecaseError;
 use crate::port::{DeleteFusenInputData, DeleteFusenOutputData, Port};
-use anyhow::{Error, Result};
+use async_trait::async_trait;
 use derive_new::new;
 use domain::entity::*;
 use domain::repository::*;
 use domain::vo::*;
+use futures::FutureExt;
 
 #[derive(new)]
-pub struct DeleteFusenInteractor<S>
+pub struct DeleteFusenInteractor<C, U>
 where
-    S: GetRepository<Fusen> + DeleteRepository<Fusen>,
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + DeleteRepository<Fusen>,
 {
-    fusen_repository: S,
+    clock: C,
+    unit_of_work: U,
 }
 
-impl<S> Port<DeleteFusenInputData, DeleteFusenOutputData> for DeleteFusenInteractor<S>
+#[async_trait]
+impl<C, U> Port<DeleteFusenInputData, DeleteFusenOutputData> for DeleteFusenInteractor<C, U>
 where
-    S: GetRepository<Fusen> + DeleteRepository<Fusen>,
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + DeleteRepository<Fusen>,
 {
-    fn handle(&self, input: DeleteFusenInputData) -> Result<DeleteFusenOutputData, Error> {
+    async fn handle(
+        &self,
+        input: DeleteFusenInputData,
+    ) -> Result<DeleteFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
         let id = input.id.parse::<Id<Fusen>>()?;
 
-        let fusen = self.fusen_repository.get(id)?;
-        self.fusen_repository.delete(fusen)?;
+        let now = self.clock.now();
+
+        // 取得から削除までを同じトランザクションで行う
+        self.unit_of_work
+            .run(move |repositories| {
+                async move {
+                    let mut fusen = repositories.get(id).await?;
+                    fusen.authorize(&owner_id)?;
+                    fusen.record_deleted(now);
+                    repositories.delete(fusen).await
+                }
+                .boxed()
+            })
+            .await?;
 
         Ok(DeleteFusenOutputData::new())
     }
@@ -30,63 +54,107 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::bail;
+    use domain::error::DomainError;
+    use domain::event::{FusenDomainEvent, FusenEvent, FusenEventKind};
     use domain::vo::Id;
+    use futures::future::BoxFuture;
     use std::collections::HashMap;
     use std::sync::Arc;
     use std::sync::Mutex;
 
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
     struct MockFusenRepository {
         store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+        // 削除と同じトランザクションで書き出されるイベント
+        events: Arc<Mutex<Vec<FusenDomainEvent>>>,
     }
     impl MockFusenRepository {
         fn new() -> Self {
             let m = HashMap::new();
             Self {
                 store: Arc::new(Mutex::new(m)),
+                events: Arc::new(Mutex::new(Vec::new())),
             }
         }
     }
 
+    #[async_trait]
     impl CreateRepository<Fusen> for MockFusenRepository {
-        fn create(&self, entity: Fusen) -> Result<(), Error> {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             m.insert(entity.id().clone(), entity.clone());
             Ok(())
         }
     }
 
+    #[async_trait]
     impl GetRepository<Fusen> for MockFusenRepository {
-        fn get(&self, id: Id<Fusen>) -> Result<Fusen, Error> {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
             let m = self.store.lock().unwrap();
             match m.get(&id) {
                 Some(aggregate_root) => Ok(aggregate_root.clone()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
+    #[async_trait]
     impl DeleteRepository<Fusen> for MockFusenRepository {
-        fn delete(&self, entity: Fusen) -> Result<(), Error> {
+        async fn delete(&self, entity: Fusen) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             match m.remove(&entity.id().clone()) {
-                Some(_) => Ok(()),
-                None => bail!("not found entity"),
+                Some(_) => {
+                    let mut events = self.events.lock().unwrap();
+                    events.extend(entity.events().iter().cloned());
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
-    #[test]
-    fn test_delete_fusen_handle() {
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_fusen_handle() {
         let fusen_repository = MockFusenRepository::new();
         let fusen_a = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("any".parse::<FusenTitle>().unwrap())
             .note("any".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
         let fusen_b = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title(
                 "Clean Architecture using Rust"
                     .parse::<FusenTitle>()
@@ -97,28 +165,76 @@ mod tests {
                     .parse::<FusenNote>()
                     .unwrap(),
             )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
-        fusen_repository.create(fusen_a.clone()).unwrap();
-        fusen_repository.create(fusen_b.clone()).unwrap();
+        fusen_repository.create(fusen_a.clone()).await.unwrap();
+        fusen_repository.create(fusen_b.clone()).await.unwrap();
 
-        let sut = DeleteFusenInteractor::new(fusen_repository);
+        let events = fusen_repository.events.clone();
+        let sut = DeleteFusenInteractor::new(MockClock {}, MockUnitOfWork { fusen_repository });
 
         assert_eq!(
-            sut.handle(DeleteFusenInputData::new(fusen_a.id().to_string()))
-                .unwrap(),
+            sut.handle(DeleteFusenInputData::new(
+                "owner".to_string(),
+                fusen_a.id().to_string()
+            ))
+            .await
+            .unwrap(),
             DeleteFusenOutputData::new()
         );
+        assert_eq!(
+            FusenEvent::from(events.lock().unwrap().last().unwrap()),
+            FusenEvent::new(
+                FusenEventKind::Deleted,
+                fusen_a.clone(),
+                "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+            )
+        );
+
+        assert!(matches!(
+            sut.handle(DeleteFusenInputData::new(
+                "another".to_string(),
+                fusen_b.id().to_string()
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
 
         // ok
         assert!(sut
-            .handle(DeleteFusenInputData::new(fusen_b.id().to_string()))
+            .handle(DeleteFusenInputData::new(
+                "owner".to_string(),
+                fusen_b.id().to_string()
+            ))
+            .await
             .is_ok());
 
         // err
         assert!(sut
-            .handle(DeleteFusenInputData::new("NOTFOUND_ID".to_string()))
+            .handle(DeleteFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string()
+            ))
+            .await
             .is_err());
+        assert!(matches!(
+            sut.handle(DeleteFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string()
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(DeleteFusenInputData::new(
+                "owner".to_string(),
+                fusen_a.id().to_string()
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
     }
 }
@@ -0,0 +1,291 @@
+use crate::error::UsecaseError;
+use crate::interactor::attachment::authorize_member;
+use crate::port::{DownloadAttachmentInputData, DownloadAttachmentOutputData, Port};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+
+const CHUNK_BUFFER_SIZE: usize = 16;
+
+#[derive(new)]
+pub struct DownloadAttachmentInteractor<B, S, A, Bl>
+where
+    B: GetRepository<Board>,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+    Bl: BlobStore,
+{
+    board_repository: B,
+    fusen_repository: S,
+    attachment_repository: A,
+    blob_store: Bl,
+}
+
+#[async_trait]
+impl<B, S, A, Bl> Port<DownloadAttachmentInputData, DownloadAttachmentOutputData>
+    for DownloadAttachmentInteractor<B, S, A, Bl>
+where
+    B: GetRepository<Board>,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+    Bl: BlobStore,
+{
+    async fn handle(
+        &self,
+        input: DownloadAttachmentInputData,
+    ) -> Result<DownloadAttachmentOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let id = input.id.parse::<Id<Attachment>>()?;
+
+        let attachment = self.attachment_repository.get(id).await?;
+        // 削除された fusen の添付ファイルは回収されるまでの間も参照させない
+        let fusen = self
+            .fusen_repository
+            .get(attachment.fusen_id().clone())
+            .await?;
+        authorize_member(&self.board_repository, &fusen, &owner_id).await?;
+
+        let mut content = self.blob_store.get(&attachment.blob_key()).await?;
+        let expected = attachment.checksum().clone();
+        let (sender, receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+        tokio::spawn(async move {
+            let mut hasher = Sha256::new();
+            while let Some(chunk) = content.next().await {
+                let chunk = chunk.map_err(UsecaseError::from);
+                if let Ok(chunk) = &chunk {
+                    hasher.update(chunk);
+                }
+                let failed = chunk.is_err();
+                // 受信側が切断されたら読み込みをやめる
+                if sender.send(chunk).await.is_err() || failed {
+                    return;
+                }
+            }
+
+            // 壊れた内容を受け取ったことをクライアントに知らせる
+            if Checksum::from_sha256(&hasher.finalize()).ok() != Some(expected) {
+                let _ = sender
+                    .send(Err(UsecaseError::Unexpected(anyhow!(
+                        "attachment checksum mismatch"
+                    ))))
+                    .await;
+            }
+        });
+
+        Ok(DownloadAttachmentOutputData::new(attachment, receiver))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use futures::stream;
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            Ok(BoardBuilder::default()
+                .id(id)
+                .owner_id("owner".parse::<OwnerId>().unwrap())
+                .name("board".parse::<BoardName>().unwrap())
+                .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap())
+        }
+    }
+
+    struct MockFusenRepository {}
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            match id.to_string().as_str() {
+                "01F8MECHZX3TBDSZ7XRADM79XE" => Ok(FusenBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    // 01FNATTACHXKQ7B1GZ1Z0K0T8S は削除済みの fusen の添付ファイル
+    struct MockAttachmentRepository {
+        checksum: String,
+    }
+
+    #[async_trait]
+    impl AttachmentRepository for MockAttachmentRepository {
+        async fn add(&self, _attachment: Attachment) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn get(&self, id: Id<Attachment>) -> Result<Attachment, DomainError> {
+            let fusen_id = match id.to_string().as_str() {
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R" => "01F8MECHZX3TBDSZ7XRADM79XE",
+                "01FNATTACHXKQ7B1GZ1Z0K0T8S" => "01F8MECHZX3TBDSZ7XRADM79XF",
+                _ => return Err(DomainError::NotFound("not found entity".to_string())),
+            };
+            Ok(AttachmentBuilder::default()
+                .id(id)
+                .fusen_id(fusen_id.parse::<Id<Fusen>>().unwrap())
+                .file_name("screenshot.png".parse::<FileName>().unwrap())
+                .content_type("image/png".parse::<ContentType>().unwrap())
+                .size(3u64)
+                .checksum(self.checksum.parse::<Checksum>().unwrap())
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap())
+        }
+
+        async fn list(&self, _fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn orphaned(&self, _limit: usize) -> Result<Vec<Attachment>, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn remove(&self, _ids: Vec<Id<Attachment>>) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+    }
+
+    struct MockBlobStore {}
+
+    #[async_trait]
+    impl BlobStore for MockBlobStore {
+        async fn put(&self, _key: &str, _content: BlobStream) -> Result<u64, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn get(&self, _key: &str) -> Result<BlobStream, DomainError> {
+            Ok(stream::iter(vec![Ok(b"ab".to_vec()), Ok(b"c".to_vec())]).boxed())
+        }
+
+        async fn delete(&self, _key: &str) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+    }
+
+    fn new_sut(
+        checksum: &str,
+    ) -> DownloadAttachmentInteractor<
+        MockBoardRepository,
+        MockFusenRepository,
+        MockAttachmentRepository,
+        MockBlobStore,
+    > {
+        DownloadAttachmentInteractor::new(
+            MockBoardRepository {},
+            MockFusenRepository {},
+            MockAttachmentRepository {
+                checksum: checksum.to_string(),
+            },
+            MockBlobStore {},
+        )
+    }
+
+    async fn collect(
+        mut output: DownloadAttachmentOutputData,
+    ) -> Vec<Result<Vec<u8>, UsecaseError>> {
+        let mut chunks = Vec::new();
+        while let Some(chunk) = output.chunks.recv().await {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_handle() {
+        let sut = new_sut("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+
+        let output = sut
+            .handle(DownloadAttachmentInputData::new(
+                "member".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(output.attachment.file_name().to_string(), "screenshot.png");
+        assert_eq!(
+            collect(output)
+                .await
+                .into_iter()
+                .map(Result::unwrap)
+                .collect::<Vec<_>>(),
+            vec![b"ab".to_vec(), b"c".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_handle_err() {
+        let sut = new_sut(&"0".repeat(64));
+
+        let chunks = collect(
+            sut.handle(DownloadAttachmentInputData::new(
+                "owner".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            ))
+            .await
+            .unwrap(),
+        )
+        .await;
+        assert_eq!(chunks.len(), 3);
+        assert!(matches!(
+            chunks.last(),
+            Some(Err(UsecaseError::Unexpected(_)))
+        ));
+
+        assert!(matches!(
+            sut.handle(DownloadAttachmentInputData::new(
+                "owner".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8S".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.handle(DownloadAttachmentInputData::new(
+                "owner".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8T".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.handle(DownloadAttachmentInputData::new(
+                "another".to_string(),
+                "01FNATTACHXKQ7B1GZ1Z0K0T8R".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(DownloadAttachmentInputData::new(
+                "owner".to_string(),
+                "invalid".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+}
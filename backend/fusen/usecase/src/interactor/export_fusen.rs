@@ -0,0 +1,209 @@
+use crate::error::UsecaseError;
+use crate::port::{ExportFusenInputData, ExportFusenOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use tokio::sync::mpsc;
+
+const EXPORT_PAGE_SIZE: usize = 100;
+const FUSEN_BUFFER_SIZE: usize = 64;
+
+#[derive(new)]
+pub struct ExportFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: ListRepository<Fusen> + Clone + 'static,
+{
+    board_repository: B,
+    fusen_repository: S,
+}
+
+#[async_trait]
+impl<B, S> Port<ExportFusenInputData, ExportFusenOutputData> for ExportFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: ListRepository<Fusen> + Clone + 'static,
+{
+    async fn handle(
+        &self,
+        input: ExportFusenInputData,
+    ) -> Result<ExportFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let board = self
+            .board_repository
+            .get(input.board_id.parse::<Id<Board>>()?)
+            .await?;
+        board.authorize(&owner_id)?;
+
+        let board_id = board.id().clone();
+        let fusen_repository = self.fusen_repository.clone();
+        let (sender, receiver) = mpsc::channel(FUSEN_BUFFER_SIZE);
+        // 一度に読み込まず、受信側に合わせてページ単位で取得する
+        tokio::spawn(async move {
+            let mut cursor = None;
+            loop {
+                let fusens = match fusen_repository
+                    .list(ListQuery::new(
+                        cursor.take(),
+                        EXPORT_PAGE_SIZE,
+                        SortOrder::Asc,
+                        None,
+                        None,
+                        Some(board_id.clone()),
+                    ))
+                    .await
+                {
+                    Ok(fusens) => fusens,
+                    Err(e) => {
+                        let _ = sender.send(Err(UsecaseError::from(e))).await;
+                        break;
+                    }
+                };
+                let last_page = fusens.len() < EXPORT_PAGE_SIZE;
+                cursor = fusens.last().map(|fusen| fusen.id().clone());
+
+                for fusen in fusens {
+                    // 受信側が切断されたら書き出しをやめる
+                    if sender.send(Ok(fusen)).await.is_err() {
+                        return;
+                    }
+                }
+                if last_page {
+                    break;
+                }
+            }
+        });
+
+        Ok(ExportFusenOutputData::new(receiver))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    // ページをまたいで取得できるよう、ページサイズより多く返す
+    #[derive(Clone)]
+    struct MockFusenRepository {
+        fusens: Vec<Fusen>,
+    }
+
+    impl MockFusenRepository {
+        fn new(count: usize) -> Self {
+            let fusens = (0..count)
+                .map(|n| {
+                    FusenBuilder::default()
+                        .id(format!("01F8MECHZX3TBDSZ7XRA{:06}", n)
+                            .parse::<Id<Fusen>>()
+                            .unwrap())
+                        .owner_id("owner".parse::<OwnerId>().unwrap())
+                        .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                        .title(format!("title {}", n).parse::<FusenTitle>().unwrap())
+                        .note("note".parse::<FusenNote>().unwrap())
+                        .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .build()
+                        .unwrap()
+                })
+                .collect();
+            Self { fusens }
+        }
+    }
+
+    #[async_trait]
+    impl ListRepository<Fusen> for MockFusenRepository {
+        async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+            Ok(self
+                .fusens
+                .iter()
+                .filter(|fusen| Some(fusen.board_id()) == query.board_id.as_ref())
+                .filter(|fusen| match &query.cursor {
+                    Some(cursor) => fusen.id().to_string() > cursor.to_string(),
+                    None => true,
+                })
+                .take(query.limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_fusen_handle() {
+        let sut = ExportFusenInteractor::new(
+            MockBoardRepository {},
+            MockFusenRepository::new(EXPORT_PAGE_SIZE + 1),
+        );
+
+        let mut output = sut
+            .handle(ExportFusenInputData::new(
+                "member".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let mut titles = Vec::new();
+        while let Some(fusen) = output.fusens.recv().await {
+            titles.push(fusen.unwrap().title().to_string());
+        }
+        assert_eq!(titles.len(), EXPORT_PAGE_SIZE + 1);
+        assert_eq!(titles[0], "title 0".to_string());
+        assert_eq!(
+            titles[EXPORT_PAGE_SIZE],
+            format!("title {}", EXPORT_PAGE_SIZE)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_fusen_handle_err() {
+        let sut = ExportFusenInteractor::new(MockBoardRepository {}, MockFusenRepository::new(1));
+
+        assert!(matches!(
+            sut.handle(ExportFusenInputData::new(
+                "another".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(ExportFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R9".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.handle(ExportFusenInputData::new(
+                "owner".to_string(),
+                "invalid".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+}
@@ -1,40 +1,91 @@
+use crate::error::UsecaseError;
 use crate::port::{GetFusenInputData, GetFusenOutputData, Port};
-use anyhow::{Error, Result};
+use async_trait::async_trait;
 use derive_new::new;
 use domain::entity::*;
 use domain::repository::*;
 use domain::vo::*;
 
 #[derive(new)]
-pub struct GetFusenInteractor<S>
+pub struct GetFusenInteractor<R, B, S>
 where
+    R: NoteRenderer,
+    B: GetRepository<Board>,
     S: GetRepository<Fusen>,
 {
+    note_renderer: R,
+    board_repository: B,
     fusen_repository: S,
 }
 
-impl<S> Port<GetFusenInputData, GetFusenOutputData> for GetFusenInteractor<S>
+#[async_trait]
+impl<R, B, S> Port<GetFusenInputData, GetFusenOutputData> for GetFusenInteractor<R, B, S>
 where
+    R: NoteRenderer,
+    B: GetRepository<Board>,
     S: GetRepository<Fusen>,
 {
-    fn handle(&self, input: GetFusenInputData) -> Result<GetFusenOutputData, Error> {
+    async fn handle(&self, input: GetFusenInputData) -> Result<GetFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
         let id = input.id.parse::<Id<Fusen>>()?;
+        let render_format = input
+            .render_format
+            .map(|format| format.parse::<RenderFormat>())
+            .transpose()?;
 
-        let fusen = self.fusen_repository.get(id)?;
+        let fusen = self.fusen_repository.get(id).await?;
+        // 作成者以外でも board のメンバーであれば参照できる
+        if fusen.authorize(&owner_id).is_err() {
+            self.board_repository
+                .get(fusen.board_id().clone())
+                .await?
+                .authorize(&owner_id)?;
+        }
+
+        let rendered_note =
+            render_format.map(|format| self.note_renderer.render(fusen.note(), format));
 
-        Ok(GetFusenOutputData::new(fusen))
+        Ok(GetFusenOutputData::new(fusen, rendered_note))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::bail;
+    use domain::error::DomainError;
     use domain::vo::Id;
     use std::collections::HashMap;
     use std::sync::Arc;
     use std::sync::Mutex;
 
+    struct MockNoteRenderer {}
+
+    impl NoteRenderer for MockNoteRenderer {
+        fn render(&self, note: &FusenNote, format: RenderFormat) -> String {
+            format!("{}:{}", format.to_string(), note.to_string())
+        }
+    }
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
     struct MockFusenRepository {
         store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
     }
@@ -47,45 +98,54 @@ mod tests {
         }
     }
 
+    #[async_trait]
     impl CreateRepository<Fusen> for MockFusenRepository {
-        fn create(&self, entity: Fusen) -> Result<(), Error> {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             m.insert(entity.id().clone(), entity.clone());
             Ok(())
         }
     }
 
+    #[async_trait]
     impl GetRepository<Fusen> for MockFusenRepository {
-        fn get(&self, id: Id<Fusen>) -> Result<Fusen, Error> {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
             let m = self.store.lock().unwrap();
             match m.get(&id) {
                 Some(aggregate_root) => Ok(aggregate_root.clone()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
+    #[async_trait]
     impl DeleteRepository<Fusen> for MockFusenRepository {
-        fn delete(&self, entity: Fusen) -> Result<(), Error> {
+        async fn delete(&self, entity: Fusen) -> Result<(), DomainError> {
             let mut m = self.store.lock().unwrap();
             match m.remove(&entity.id().clone()) {
                 Some(_) => Ok(()),
-                None => bail!("not found entity"),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
             }
         }
     }
 
-    #[test]
-    fn test_get_fusen_handle() {
+    #[tokio::test]
+    async fn test_get_fusen_handle() {
         let fusen_repository = MockFusenRepository::new();
         let fusen_a = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title("any".parse::<FusenTitle>().unwrap())
             .note("any".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
         let fusen_b = FusenBuilder::default()
             .id("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
             .title(
                 "Clean Architecture using Rust"
                     .parse::<FusenTitle>()
@@ -96,28 +156,106 @@ mod tests {
                     .parse::<FusenNote>()
                     .unwrap(),
             )
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
             .build()
             .unwrap();
 
-        fusen_repository.create(fusen_a.clone()).unwrap();
-        fusen_repository.create(fusen_b.clone()).unwrap();
+        fusen_repository.create(fusen_a.clone()).await.unwrap();
+        fusen_repository.create(fusen_b.clone()).await.unwrap();
 
-        let sut = GetFusenInteractor::new(fusen_repository);
+        let sut = GetFusenInteractor::new(
+            MockNoteRenderer {},
+            MockBoardRepository {},
+            fusen_repository,
+        );
 
         assert_eq!(
-            sut.handle(GetFusenInputData::new(fusen_a.id().to_string()))
-                .unwrap(),
-            GetFusenOutputData::new(fusen_a.clone())
+            sut.handle(GetFusenInputData::new(
+                "owner".to_string(),
+                fusen_a.id().to_string(),
+                None
+            ))
+            .await
+            .unwrap(),
+            GetFusenOutputData::new(fusen_a.clone(), None)
         );
 
         // ok
         assert!(sut
-            .handle(GetFusenInputData::new(fusen_b.id().to_string()))
+            .handle(GetFusenInputData::new(
+                "owner".to_string(),
+                fusen_b.id().to_string(),
+                None
+            ))
+            .await
             .is_ok());
 
+        assert!(sut
+            .handle(GetFusenInputData::new(
+                "member".to_string(),
+                fusen_a.id().to_string(),
+                None
+            ))
+            .await
+            .is_ok());
+
+        assert_eq!(
+            sut.handle(GetFusenInputData::new(
+                "owner".to_string(),
+                fusen_a.id().to_string(),
+                Some("html".to_string())
+            ))
+            .await
+            .unwrap()
+            .rendered_note,
+            Some("html:any".to_string())
+        );
+
         // err
+        assert!(matches!(
+            sut.handle(GetFusenInputData::new(
+                "owner".to_string(),
+                fusen_a.id().to_string(),
+                Some("markdown".to_string())
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(GetFusenInputData::new(
+                "another".to_string(),
+                fusen_a.id().to_string(),
+                None
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
         assert!(sut
-            .handle(GetFusenInputData::new("NOTFOUND_ID".to_string()))
+            .handle(GetFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string(),
+                None
+            ))
+            .await
             .is_err());
+        assert!(matches!(
+            sut.handle(GetFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string(),
+                None
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(GetFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XZ".to_string(),
+                None
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
     }
 }
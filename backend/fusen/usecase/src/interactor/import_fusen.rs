@@ -0,0 +1,391 @@
+use crate::error::UsecaseError;
+use crate::port::{ImportFusenFailure, ImportFusenInputData, ImportFusenOutputData};
+use crate::port::{ImportFusenRecord, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+use futures::FutureExt;
+
+#[derive(new)]
+pub struct ImportFusenInteractor<C, B, U>
+where
+    C: Clock,
+    B: GetRepository<Board>,
+    U: UnitOfWork,
+    U::Repositories: CreateRepository<Fusen> + GetRepository<Fusen> + UpdateRepository<Fusen>,
+{
+    clock: C,
+    board_repository: B,
+    unit_of_work: U,
+}
+
+#[async_trait]
+impl<C, B, U> Port<ImportFusenInputData, ImportFusenOutputData> for ImportFusenInteractor<C, B, U>
+where
+    C: Clock,
+    B: GetRepository<Board>,
+    U: UnitOfWork,
+    U::Repositories: CreateRepository<Fusen> + GetRepository<Fusen> + UpdateRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: ImportFusenInputData,
+    ) -> Result<ImportFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let board = self
+            .board_repository
+            .get(input.board_id.parse::<Id<Board>>()?)
+            .await?;
+        board.authorize(&owner_id)?;
+        let now = self.clock.now();
+
+        let mut fusens = Vec::new();
+        let mut failures = Vec::new();
+        for (index, record) in input.records.into_iter().enumerate() {
+            let id = record.id.clone();
+            match self.import(&owner_id, &board, record, now).await {
+                Ok(fusen) => fusens.push(fusen),
+                // レコード単位の問題は報告して残りの取り込みを続ける
+                Err(
+                    e @ (UsecaseError::InvalidArgument(_)
+                    | UsecaseError::InvalidTitle(_)
+                    | UsecaseError::PermissionDenied(_)
+                    | UsecaseError::FailedPrecondition(_)),
+                ) => failures.push(ImportFusenFailure::new(index, id, e.to_string())),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(ImportFusenOutputData::new(fusens, failures))
+    }
+}
+
+impl<C, B, U> ImportFusenInteractor<C, B, U>
+where
+    C: Clock,
+    B: GetRepository<Board>,
+    U: UnitOfWork,
+    U::Repositories: CreateRepository<Fusen> + GetRepository<Fusen> + UpdateRepository<Fusen>,
+{
+    async fn import(
+        &self,
+        owner_id: &OwnerId,
+        board: &Board,
+        record: ImportFusenRecord,
+        now: Timestamp,
+    ) -> Result<Fusen, UsecaseError> {
+        let id = record.id.parse::<Id<Fusen>>()?;
+        let title = record.title.parse::<FusenTitle>()?;
+        let note = record.note.parse::<FusenNote>()?;
+        let create_at = parse_timestamp(record.create_time, now)?;
+        let update_at = parse_timestamp(record.update_time, now)?;
+
+        // 同じ id の fusen は上書きするので、何度取り込んでも同じ結果になる。
+        // 取得から保存までを同じトランザクションで行う
+        let owner_id = owner_id.clone();
+        let board_id = board.id().clone();
+        let fusen = self
+            .unit_of_work
+            .run(move |repositories| {
+                async move {
+                    match repositories.get(id.clone()).await {
+                        Ok(mut fusen) => {
+                            fusen.authorize(&owner_id)?;
+                            if fusen.board_id() != &board_id {
+                                return Err(DomainError::FailedPrecondition(format!(
+                                    "fusen {} belongs to another board",
+                                    id.to_string()
+                                )));
+                            }
+                            fusen.set_title(title);
+                            fusen.set_note(note);
+                            fusen.set_update_at(update_at);
+                            fusen.record_updated(now);
+                            repositories.update(fusen.clone()).await?;
+                            Ok(fusen)
+                        }
+                        Err(DomainError::NotFound(_)) => {
+                            let mut fusen = FusenBuilder::default()
+                                .id(id)
+                                .owner_id(owner_id)
+                                .board_id(board_id)
+                                .title(title)
+                                .note(note)
+                                .create_at(create_at)
+                                .update_at(update_at)
+                                .build()
+                                .unwrap();
+                            fusen.record_created(now);
+                            repositories.create(fusen.clone()).await?;
+                            Ok(fusen)
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                .boxed()
+            })
+            .await?;
+
+        Ok(fusen)
+    }
+}
+
+fn parse_timestamp(value: Option<String>, default: Timestamp) -> Result<Timestamp, UsecaseError> {
+    match value {
+        Some(value) => Ok(value.parse::<Timestamp>()?),
+        None => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::event::FusenDomainEvent;
+    use futures::future::BoxFuture;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" | "01FMB0ARDXKQ7B1GZ1Z0K0T8R1" => {
+                    Ok(BoardBuilder::default()
+                        .id(id)
+                        .owner_id("owner".parse::<OwnerId>().unwrap())
+                        .name("board".parse::<BoardName>().unwrap())
+                        .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                        .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                        .build()
+                        .unwrap())
+                }
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockFusenRepository {
+        store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+    }
+
+    #[async_trait]
+    impl CreateRepository<Fusen> for MockFusenRepository {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            m.insert(entity.id().clone(), entity);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UpdateRepository<Fusen> for MockFusenRepository {
+        async fn update(&self, entity: Fusen) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            match m.get_mut(entity.id()) {
+                Some(aggregate_root) => {
+                    *aggregate_root = entity;
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
+        }
+    }
+
+    fn record(id: &str, title: &str) -> ImportFusenRecord {
+        ImportFusenRecord::new(
+            id.to_string(),
+            title.to_string(),
+            "note".to_string(),
+            Some("2021-10-17T07:08:37Z".to_string()),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_import_fusen_handle() {
+        let fusen_repository = MockFusenRepository::default();
+        let sut = ImportFusenInteractor::new(
+            MockClock {},
+            MockBoardRepository {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+        let input = ImportFusenInputData::new(
+            "owner".to_string(),
+            "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+            vec![
+                record("01F8MECHZX3TBDSZ7XRADM79XE", "first"),
+                record("01F8MECHZX3TBDSZ7XRADM79XF", "second"),
+            ],
+        );
+
+        let output = sut.handle(input.clone()).await.unwrap();
+        assert_eq!(output.fusens.len(), 2);
+        assert!(output.failures.is_empty());
+        assert_eq!(
+            output.fusens[0].create_at().to_string(),
+            "2021-10-17T07:08:37Z".to_string()
+        );
+        assert_eq!(
+            output.fusens[0].update_at().to_string(),
+            "2021-10-18T00:00:00Z".to_string()
+        );
+        assert!(output
+            .fusens
+            .iter()
+            .all(|fusen| matches!(fusen.events().last(), Some(FusenDomainEvent::Created(_)))));
+
+        // 同じ内容を再度取り込んでも fusen は増えない
+        let output = sut.handle(input).await.unwrap();
+        assert_eq!(output.fusens.len(), 2);
+        assert_eq!(fusen_repository.store.lock().unwrap().len(), 2);
+        assert_eq!(
+            fusen_repository
+                .get("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+                .await
+                .unwrap()
+                .title()
+                .to_string(),
+            "second".to_string()
+        );
+
+        assert!(output
+            .fusens
+            .iter()
+            .all(|fusen| matches!(fusen.events().last(), Some(FusenDomainEvent::Updated(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_import_fusen_handle_failures() {
+        let fusen_repository = MockFusenRepository::default();
+        let sut = ImportFusenInteractor::new(
+            MockClock {},
+            MockBoardRepository {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+        sut.handle(ImportFusenInputData::new(
+            "owner".to_string(),
+            "01FMB0ARDXKQ7B1GZ1Z0K0T8R1".to_string(),
+            vec![record("01F8MECHZX3TBDSZ7XRADM79XG", "other board")],
+        ))
+        .await
+        .unwrap();
+
+        let output = sut
+            .handle(ImportFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                vec![
+                    record("01F8MECHZX3TBDSZ7XRADM79XE", ""),
+                    record("invalid", "title"),
+                    record("01F8MECHZX3TBDSZ7XRADM79XF", "title"),
+                    ImportFusenRecord::new(
+                        "01F8MECHZX3TBDSZ7XRADM79XH".to_string(),
+                        "title".to_string(),
+                        "note".to_string(),
+                        Some("yesterday".to_string()),
+                        None,
+                    ),
+                    record("01F8MECHZX3TBDSZ7XRADM79XG", "moved"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(output.fusens.len(), 1);
+        assert_eq!(
+            output
+                .failures
+                .iter()
+                .map(|failure| (failure.index, failure.id.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, "01F8MECHZX3TBDSZ7XRADM79XE"),
+                (1, "invalid"),
+                (3, "01F8MECHZX3TBDSZ7XRADM79XH"),
+                (4, "01F8MECHZX3TBDSZ7XRADM79XG"),
+            ]
+        );
+        assert!(output.failures[0].reason.starts_with("invalid argument"));
+        assert!(output.failures[3].reason.starts_with("failed precondition"));
+        assert_eq!(fusen_repository.store.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_fusen_handle_err() {
+        let sut = ImportFusenInteractor::new(
+            MockClock {},
+            MockBoardRepository {},
+            MockUnitOfWork {
+                fusen_repository: MockFusenRepository::default(),
+            },
+        );
+
+        assert!(matches!(
+            sut.handle(ImportFusenInputData::new(
+                "another".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                vec![record("01F8MECHZX3TBDSZ7XRADM79XE", "title")],
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(ImportFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R9".to_string(),
+                vec![],
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+    }
+}
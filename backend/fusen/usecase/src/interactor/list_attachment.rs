@@ -0,0 +1,171 @@
+use crate::error::UsecaseError;
+use crate::interactor::attachment::authorize_member;
+use crate::port::{ListAttachmentInputData, ListAttachmentOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+
+#[derive(new)]
+pub struct ListAttachmentInteractor<B, S, A>
+where
+    B: GetRepository<Board>,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+{
+    board_repository: B,
+    fusen_repository: S,
+    attachment_repository: A,
+}
+
+#[async_trait]
+impl<B, S, A> Port<ListAttachmentInputData, ListAttachmentOutputData>
+    for ListAttachmentInteractor<B, S, A>
+where
+    B: GetRepository<Board>,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+{
+    async fn handle(
+        &self,
+        input: ListAttachmentInputData,
+    ) -> Result<ListAttachmentOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let fusen_id = input.fusen_id.parse::<Id<Fusen>>()?;
+
+        let fusen = self.fusen_repository.get(fusen_id).await?;
+        authorize_member(&self.board_repository, &fusen, &owner_id).await?;
+
+        let attachments = self
+            .attachment_repository
+            .list(fusen.id().clone())
+            .await?;
+
+        Ok(ListAttachmentOutputData::new(attachments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            Ok(BoardBuilder::default()
+                .id(id)
+                .owner_id("owner".parse::<OwnerId>().unwrap())
+                .name("board".parse::<BoardName>().unwrap())
+                .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap())
+        }
+    }
+
+    struct MockFusenRepository {}
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            match id.to_string().as_str() {
+                "01F8MECHZX3TBDSZ7XRADM79XE" => Ok(FusenBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockAttachmentRepository {}
+
+    #[async_trait]
+    impl AttachmentRepository for MockAttachmentRepository {
+        async fn add(&self, _attachment: Attachment) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn get(&self, _id: Id<Attachment>) -> Result<Attachment, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn list(&self, fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError> {
+            Ok(vec![AttachmentBuilder::default()
+                .id("01FNATTACHXKQ7B1GZ1Z0K0T8R".parse::<Id<Attachment>>().unwrap())
+                .fusen_id(fusen_id)
+                .file_name("report.pdf".parse::<FileName>().unwrap())
+                .content_type("application/pdf".parse::<ContentType>().unwrap())
+                .size(3u64)
+                .checksum(
+                    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                        .parse::<Checksum>()
+                        .unwrap(),
+                )
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap()])
+        }
+
+        async fn orphaned(&self, _limit: usize) -> Result<Vec<Attachment>, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn remove(&self, _ids: Vec<Id<Attachment>>) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_attachment_handle() {
+        let sut = ListAttachmentInteractor::new(
+            MockBoardRepository {},
+            MockFusenRepository {},
+            MockAttachmentRepository {},
+        );
+
+        let output = sut
+            .handle(ListAttachmentInputData::new(
+                "member".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            output
+                .attachments
+                .iter()
+                .map(|attachment| attachment.file_name().to_string())
+                .collect::<Vec<_>>(),
+            vec!["report.pdf".to_string()]
+        );
+
+        assert!(matches!(
+            sut.handle(ListAttachmentInputData::new(
+                "another".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(ListAttachmentInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::UsecaseError;
+use crate::port::{ListBoardInputData, ListBoardOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(new)]
+pub struct ListBoardInteractor<S>
+where
+    S: ListRepository<Board>,
+{
+    board_repository: S,
+}
+
+#[async_trait]
+impl<S> Port<ListBoardInputData, ListBoardOutputData> for ListBoardInteractor<S>
+where
+    S: ListRepository<Board>,
+{
+    async fn handle(&self, input: ListBoardInputData) -> Result<ListBoardOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let limit = match input.page_size {
+            n if n < 0 => {
+                return Err(UsecaseError::InvalidArgument(format!(
+                    "invalid page size {}",
+                    n
+                )))
+            }
+            0 => DEFAULT_PAGE_SIZE,
+            n => (n as usize).min(MAX_PAGE_SIZE),
+        };
+        // 自分が owner またはメンバーの board を返す
+        let query = ListQuery::new(None, limit + 1, SortOrder::Asc, None, Some(owner_id), None);
+        let query = match input.page_token.as_str() {
+            "" => query,
+            token => query.with_page_token(token)?,
+        };
+        let mut boards = self.board_repository.list(query.clone()).await?;
+
+        let next_page_token = if boards.len() > limit {
+            boards.truncate(limit);
+            boards
+                .last()
+                .map(|b| query.page_token(b.id()))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(ListBoardOutputData::new(boards, next_page_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+
+    struct MockBoardRepository {
+        boards: Vec<Board>,
+    }
+
+    #[async_trait]
+    impl ListRepository<Board> for MockBoardRepository {
+        async fn list(&self, query: ListQuery<Board>) -> Result<Vec<Board>, DomainError> {
+            Ok(self
+                .boards
+                .iter()
+                .filter(|b| match &query.owner_id {
+                    Some(owner_id) => b.is_member(owner_id),
+                    None => true,
+                })
+                .filter(|b| match &query.cursor {
+                    Some(cursor) => b.id().to_string() > cursor.to_string(),
+                    None => true,
+                })
+                .take(query.limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn new_board(id: &str, owner_id: &str) -> Board {
+        BoardBuilder::default()
+            .id(id.parse::<Id<Board>>().unwrap())
+            .owner_id(owner_id.parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_board_handle() {
+        let sut = ListBoardInteractor::new(MockBoardRepository {
+            boards: vec![
+                new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "owner"),
+                new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R1", "another"),
+                new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R2", "owner"),
+            ],
+        });
+
+        let first = sut
+            .handle(ListBoardInputData::new(
+                "owner".to_string(),
+                1,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            first.boards,
+            vec![new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R0", "owner")]
+        );
+        assert!(!first.next_page_token.is_empty());
+
+        let second = sut
+            .handle(ListBoardInputData::new(
+                "owner".to_string(),
+                1,
+                first.next_page_token,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            second,
+            ListBoardOutputData::new(
+                vec![new_board("01FMB0ARDXKQ7B1GZ1Z0K0T8R2", "owner")],
+                "".to_string()
+            )
+        );
+
+        let member = sut
+            .handle(ListBoardInputData::new(
+                "member".to_string(),
+                0,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(member.boards.len(), 3);
+
+        assert!(matches!(
+            sut.handle(ListBoardInputData::new(
+                "owner".to_string(),
+                -1,
+                "".to_string()
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,365 @@
+use crate::error::UsecaseError;
+use crate::port::{ListFusenInputData, ListFusenOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(new)]
+pub struct ListFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: ListRepository<Fusen>,
+{
+    board_repository: B,
+    fusen_repository: S,
+}
+
+#[async_trait]
+impl<B, S> Port<ListFusenInputData, ListFusenOutputData> for ListFusenInteractor<B, S>
+where
+    B: GetRepository<Board>,
+    S: ListRepository<Fusen>,
+{
+    async fn handle(&self, input: ListFusenInputData) -> Result<ListFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let board = self
+            .board_repository
+            .get(input.board_id.parse::<Id<Board>>()?)
+            .await?;
+        board.authorize(&owner_id)?;
+        let limit = match input.page_size {
+            n if n < 0 => {
+                return Err(UsecaseError::InvalidArgument(format!(
+                    "invalid page size {}",
+                    n
+                )))
+            }
+            0 => DEFAULT_PAGE_SIZE,
+            n => (n as usize).min(MAX_PAGE_SIZE),
+        };
+        let keyword = match input.keyword.trim() {
+            "" => None,
+            keyword => Some(keyword.to_string()),
+        };
+
+        // 1件多く取得して次のページの有無を判定する
+        // board のメンバーには他のメンバーが作成した fusen も見せる
+        let query = ListQuery::new(
+            None,
+            limit + 1,
+            input.order,
+            keyword,
+            None,
+            Some(board.id().clone()),
+        );
+        let query = match input.page_token.as_str() {
+            "" => query,
+            token => query.with_page_token(token)?,
+        };
+        let mut fusens = self.fusen_repository.list(query.clone()).await?;
+
+        let next_page_token = if fusens.len() > limit {
+            fusens.truncate(limit);
+            fusens
+                .last()
+                .map(|f| query.page_token(f.id()))
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(ListFusenOutputData::new(fusens, next_page_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use domain::vo::Id;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockFusenRepository {
+        store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+    }
+    impl MockFusenRepository {
+        fn new() -> Self {
+            let m = HashMap::new();
+            Self {
+                store: Arc::new(Mutex::new(m)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CreateRepository<Fusen> for MockFusenRepository {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            m.insert(entity.id().clone(), entity.clone());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ListRepository<Fusen> for MockFusenRepository {
+        async fn list(&self, query: ListQuery<Fusen>) -> Result<Vec<Fusen>, DomainError> {
+            let m = self.store.lock().unwrap();
+            let mut fusens = m.values().cloned().collect::<Vec<_>>();
+            fusens.sort_by_key(|f| f.id().to_string());
+            if query.order == SortOrder::Desc {
+                fusens.reverse();
+            }
+            Ok(fusens
+                .into_iter()
+                .skip_while(|f| match &query.cursor {
+                    Some(cursor) => match query.order {
+                        SortOrder::Asc => f.id().to_string() <= cursor.to_string(),
+                        SortOrder::Desc => f.id().to_string() >= cursor.to_string(),
+                    },
+                    None => false,
+                })
+                .filter(|f| match &query.board_id {
+                    Some(board_id) => f.board_id() == board_id,
+                    None => true,
+                })
+                .filter(|f| match &query.keyword {
+                    Some(keyword) => f.title().to_string().contains(keyword.as_str()),
+                    None => true,
+                })
+                .take(query.limit)
+                .collect())
+        }
+    }
+
+    fn new_fusen(id: &str, title: &str) -> Fusen {
+        FusenBuilder::default()
+            .id(id.parse::<Id<Fusen>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .title(title.parse::<FusenTitle>().unwrap())
+            .note("any".parse::<FusenNote>().unwrap())
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    async fn new_repository() -> MockFusenRepository {
+        let fusen_repository = MockFusenRepository::new();
+        fusen_repository
+            .create(new_fusen("01F8MECHZX3TBDSZ7XRADM79XE", "apple"))
+            .await
+            .unwrap();
+        fusen_repository
+            .create(new_fusen("01F8MECHZX3TBDSZ7XRADM79XF", "banana"))
+            .await
+            .unwrap();
+        fusen_repository
+            .create(new_fusen("01F8MECHZX3TBDSZ7XRADM79XG", "cherry"))
+            .await
+            .unwrap();
+        fusen_repository
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle() {
+        let sut = ListFusenInteractor::new(MockBoardRepository {}, new_repository().await);
+
+        let first = sut
+            .handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                2,
+                "".to_string(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            first.fusens,
+            vec![
+                new_fusen("01F8MECHZX3TBDSZ7XRADM79XE", "apple"),
+                new_fusen("01F8MECHZX3TBDSZ7XRADM79XF", "banana"),
+            ]
+        );
+        // page token は id をそのまま露出しない
+        assert!(!first.next_page_token.is_empty());
+        assert!(!first.next_page_token.contains("01F8MECHZX3TBDSZ7XRADM79XF"));
+
+        let second = sut
+            .handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                2,
+                first.next_page_token.clone(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            second,
+            ListFusenOutputData::new(
+                vec![new_fusen("01F8MECHZX3TBDSZ7XRADM79XG", "cherry")],
+                "".to_string(),
+            )
+        );
+
+        // 並び順や keyword を変えて page token を使い回すことはできない
+        for (order, keyword) in [(SortOrder::Desc, ""), (SortOrder::Asc, "apple")] {
+            assert!(matches!(
+                sut.handle(ListFusenInputData::new(
+                    "owner".to_string(),
+                    "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                    2,
+                    first.next_page_token.clone(),
+                    order,
+                    keyword.to_string(),
+                ))
+                .await,
+                Err(UsecaseError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle_desc() {
+        let sut = ListFusenInteractor::new(MockBoardRepository {}, new_repository().await);
+
+        let output = sut
+            .handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                0,
+                "".to_string(),
+                SortOrder::Desc,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            output
+                .fusens
+                .iter()
+                .map(|f| f.id().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "01F8MECHZX3TBDSZ7XRADM79XG".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            ]
+        );
+        assert_eq!(output.next_page_token, "".to_string());
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle_keyword() {
+        let sut = ListFusenInteractor::new(MockBoardRepository {}, new_repository().await);
+
+        let output = sut
+            .handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                0,
+                "".to_string(),
+                SortOrder::Asc,
+                "  an  ".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            output.fusens,
+            vec![new_fusen("01F8MECHZX3TBDSZ7XRADM79XF", "banana")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle_err() {
+        let sut = ListFusenInteractor::new(MockBoardRepository {}, new_repository().await);
+
+        assert!(sut
+            .handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                -1,
+                "".to_string(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await
+            .is_err());
+        assert!(matches!(
+            sut.handle(ListFusenInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                -1,
+                "".to_string(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(ListFusenInputData::new(
+                "another".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                0,
+                "".to_string(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_fusen_handle_member() {
+        let sut = ListFusenInteractor::new(MockBoardRepository {}, new_repository().await);
+
+        let output = sut
+            .handle(ListFusenInputData::new(
+                "member".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                0,
+                "".to_string(),
+                SortOrder::Asc,
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(output.fusens.len(), 3);
+    }
+}
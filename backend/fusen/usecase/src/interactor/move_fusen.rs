@@ -0,0 +1,315 @@
+use crate::error::UsecaseError;
+use crate::port::{MoveFusenInputData, MoveFusenOutputData, Port};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use futures::FutureExt;
+use std::collections::HashSet;
+
+const MAX_PLACEMENTS: usize = 100;
+
+#[derive(new)]
+pub struct MoveFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + MoveAllRepository<Fusen>,
+{
+    clock: C,
+    unit_of_work: U,
+}
+
+#[async_trait]
+impl<C, U> Port<MoveFusenInputData, MoveFusenOutputData> for MoveFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + MoveAllRepository<Fusen>,
+{
+    async fn handle(&self, input: MoveFusenInputData) -> Result<MoveFusenOutputData, UsecaseError> {
+        if input.placements.is_empty() || input.placements.len() > MAX_PLACEMENTS {
+            return Err(UsecaseError::InvalidArgument(format!(
+                "placements must be 1 to {}",
+                MAX_PLACEMENTS
+            )));
+        }
+
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let now = self.clock.now();
+
+        // 入力の検証を終えてからトランザクションを開始する
+        let mut ids = HashSet::new();
+        let mut placements = Vec::with_capacity(input.placements.len());
+        for placement in input.placements {
+            // 表記の揺れを吸収した正規形で重複を判定する
+            let id = placement.id.parse::<Id<Fusen>>()?;
+            if !ids.insert(id.clone()) {
+                return Err(UsecaseError::InvalidArgument(format!(
+                    "duplicate fusen {}",
+                    id.to_string()
+                )));
+            }
+
+            let size = match placement.size {
+                Some((width, height)) => Some(FusenSize::new(width, height)?),
+                None => None,
+            };
+            placements.push((
+                id,
+                Position::new(placement.x, placement.y)?,
+                ZIndex::new(placement.z_index)?,
+                size,
+            ));
+        }
+
+        // 取得と更新を同じトランザクションで行い、ひとつでも失敗した場合はどれも動かさない
+        let fusens = self
+            .unit_of_work
+            .run(move |repositories| {
+                async move {
+                    let mut fusens = Vec::with_capacity(placements.len());
+                    for (id, position, z_index, size) in placements {
+                        let mut fusen = repositories.get(id).await?;
+                        fusen.authorize(&owner_id)?;
+                        fusen.set_position(position);
+                        fusen.set_z_index(z_index);
+                        if let Some(size) = size {
+                            fusen.set_size(size);
+                        }
+                        fusen.set_update_at(now);
+                        fusen.record_updated(now);
+                        fusens.push(fusen);
+                    }
+
+                    repositories.move_all(fusens.clone()).await?;
+                    Ok(fusens)
+                }
+                .boxed()
+            })
+            .await?;
+
+        Ok(MoveFusenOutputData::new(fusens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::FusenPlacement;
+    use domain::error::DomainError;
+    use domain::event::FusenDomainEvent;
+    use futures::future::BoxFuture;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockFusenRepository {
+        store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+    }
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MoveAllRepository<Fusen> for MockFusenRepository {
+        async fn move_all(&self, entities: Vec<Fusen>) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            for entity in entities {
+                m.insert(entity.id().clone(), entity);
+            }
+            Ok(())
+        }
+    }
+
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
+        }
+    }
+
+    fn new_repository() -> MockFusenRepository {
+        let repository = MockFusenRepository::default();
+        for (id, owner_id) in &[
+            ("01F8MECHZX3TBDSZ7XRADM79XE", "owner"),
+            ("01F8MECHZX3TBDSZ7XRADM79XF", "owner"),
+            ("01F8MECHZX3TBDSZ7XRADM79XG", "another"),
+        ] {
+            let fusen = FusenBuilder::default()
+                .id(id.parse::<Id<Fusen>>().unwrap())
+                .owner_id(owner_id.parse::<OwnerId>().unwrap())
+                .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                .title("title".parse::<FusenTitle>().unwrap())
+                .note("note".parse::<FusenNote>().unwrap())
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap();
+            repository
+                .store
+                .lock()
+                .unwrap()
+                .insert(fusen.id().clone(), fusen);
+        }
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_move_fusen_handle() {
+        let fusen_repository = new_repository();
+        let sut = MoveFusenInteractor::new(
+            MockClock {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+
+        let output = sut
+            .handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    FusenPlacement::new("01F8MECHZX3TBDSZ7XRADM79XE".to_string(), 10, 20, 2, None),
+                    FusenPlacement::new(
+                        "01F8MECHZX3TBDSZ7XRADM79XF".to_string(),
+                        -30,
+                        40,
+                        1,
+                        Some((120, 80)),
+                    ),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(output.fusens.len(), 2);
+        assert_eq!(output.fusens[0].position(), &Position::new(10, 20).unwrap());
+        assert_eq!(output.fusens[0].size(), &FusenSize::default());
+        assert_eq!(output.fusens[0].z_index(), &ZIndex::new(2).unwrap());
+        assert_eq!(output.fusens[1].size(), &FusenSize::new(120, 80).unwrap());
+        assert_eq!(
+            output.fusens[1].update_at().to_string(),
+            "2021-10-18T00:00:00Z".to_string()
+        );
+        assert_eq!(
+            fusen_repository
+                .get("01F8MECHZX3TBDSZ7XRADM79XF".parse::<Id<Fusen>>().unwrap())
+                .await
+                .unwrap()
+                .position(),
+            &Position::new(-30, 40).unwrap()
+        );
+        assert!(output
+            .fusens
+            .iter()
+            .all(|fusen| matches!(fusen.events(), [FusenDomainEvent::Updated(_)])));
+    }
+
+    #[tokio::test]
+    async fn test_move_fusen_handle_err() {
+        let fusen_repository = new_repository();
+        let sut = MoveFusenInteractor::new(
+            MockClock {},
+            MockUnitOfWork {
+                fusen_repository: fusen_repository.clone(),
+            },
+        );
+        let placement = |id: &str, x: i32| FusenPlacement::new(id.to_string(), x, 0, 0, None);
+
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new("owner".to_string(), vec![]))
+                .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    placement("01F8MECHZX3TBDSZ7XRADM79XE", 0),
+                    placement("01F8MECHZX3TBDSZ7XRADM79XE", 1)
+                ],
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        // 大文字と小文字で書き分けても同じ fusen は重複として扱う
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    placement("01F8MECHZX3TBDSZ7XRADM79XE", 0),
+                    placement("01f8mechzx3tbdsz7xradm79xe", 1)
+                ],
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        // 1 件でも範囲外があれば何も更新しない
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    placement("01F8MECHZX3TBDSZ7XRADM79XE", 10),
+                    placement("01F8MECHZX3TBDSZ7XRADM79XF", i32::MAX)
+                ],
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![
+                    placement("01F8MECHZX3TBDSZ7XRADM79XE", 10),
+                    placement("01F8MECHZX3TBDSZ7XRADM79XG", 10)
+                ],
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(MoveFusenInputData::new(
+                "owner".to_string(),
+                vec![placement("01F8MECHZX3TBDSZ7XRADM79XZ", 10)],
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+
+        let stored = fusen_repository
+            .get("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stored.position(), &Position::default());
+        assert!(stored.events().is_empty());
+    }
+}
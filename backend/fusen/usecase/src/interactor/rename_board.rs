@@ -0,0 +1,155 @@
+use crate::error::UsecaseError;
+use crate::port::{Port, RenameBoardInputData, RenameBoardOutputData};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+
+#[derive(new)]
+pub struct RenameBoardInteractor<C, S>
+where
+    C: Clock,
+    S: GetRepository<Board> + UpdateRepository<Board>,
+{
+    clock: C,
+    board_repository: S,
+}
+
+#[async_trait]
+impl<C, S> Port<RenameBoardInputData, RenameBoardOutputData> for RenameBoardInteractor<C, S>
+where
+    C: Clock,
+    S: GetRepository<Board> + UpdateRepository<Board>,
+{
+    async fn handle(
+        &self,
+        input: RenameBoardInputData,
+    ) -> Result<RenameBoardOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let id = input.id.parse::<Id<Board>>()?;
+        let name = input.name.parse::<BoardName>()?;
+
+        let mut board = self.board_repository.get(id).await?;
+        board.authorize_owner(&owner_id)?;
+        board.set_name(name);
+        board.set_update_at(self.clock.now());
+
+        self.board_repository.update(board.clone()).await?;
+        let aggregate_root = self.board_repository.get(board.id().clone()).await?;
+
+        Ok(RenameBoardOutputData::new(aggregate_root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    #[derive(new)]
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T07:08:37Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockBoardRepository {
+        store: Arc<Mutex<HashMap<Id<Board>, Board>>>,
+    }
+    impl MockBoardRepository {
+        fn new(board: Board) -> Self {
+            let mut m = HashMap::new();
+            m.insert(board.id().clone(), board);
+            Self {
+                store: Arc::new(Mutex::new(m)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UpdateRepository<Board> for MockBoardRepository {
+        async fn update(&self, entity: Board) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            match m.get_mut(entity.id()) {
+                Some(aggregate_root) => {
+                    *aggregate_root = entity;
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rename_board_handle() {
+        let board = BoardBuilder::default()
+            .id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+            .owner_id("owner".parse::<OwnerId>().unwrap())
+            .name("board".parse::<BoardName>().unwrap())
+            .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+            .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let sut = RenameBoardInteractor::new(MockClock::new(), MockBoardRepository::new(board));
+
+        let output = sut
+            .handle(RenameBoardInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "renamed".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.board.name().to_string(), "renamed".to_string());
+        assert_eq!(
+            output.board.update_at().to_string(),
+            "2021-10-18T07:08:37Z".to_string()
+        );
+
+        // err
+        assert!(matches!(
+            sut.handle(RenameBoardInputData::new(
+                "member".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "renamed".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            sut.handle(RenameBoardInputData::new(
+                "owner".to_string(),
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0".to_string(),
+                "".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(RenameBoardInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XZ".to_string(),
+                "renamed".to_string(),
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,378 @@
+use crate::error::UsecaseError;
+use crate::port::{Port, UpdateFusenInputData, UpdateFusenOutputData};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::repository::*;
+use domain::vo::*;
+use futures::FutureExt;
+
+#[derive(new)]
+pub struct UpdateFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + UpdateRepository<Fusen>,
+{
+    clock: C,
+    unit_of_work: U,
+}
+
+#[async_trait]
+impl<C, U> Port<UpdateFusenInputData, UpdateFusenOutputData> for UpdateFusenInteractor<C, U>
+where
+    C: Clock,
+    U: UnitOfWork,
+    U::Repositories: GetRepository<Fusen> + UpdateRepository<Fusen>,
+{
+    async fn handle(
+        &self,
+        input: UpdateFusenInputData,
+    ) -> Result<UpdateFusenOutputData, UsecaseError> {
+        if input.title.is_none() && input.note.is_none() && input.color.is_none() {
+            return Err(UsecaseError::InvalidArgument(
+                "no fields to update".to_string(),
+            ));
+        }
+
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let id = input.id.parse::<Id<Fusen>>()?;
+
+        let title = input
+            .title
+            .map(|title| title.parse::<FusenTitle>())
+            .transpose()?;
+        let note = input
+            .note
+            .map(|note| note.parse::<FusenNote>())
+            .transpose()?;
+        let color = input
+            .color
+            .map(|color| color.parse::<FusenColor>())
+            .transpose()?;
+        let now = self.clock.now();
+
+        // 取得から読み直しまでを同じトランザクションで行う
+        let aggregate_root = self
+            .unit_of_work
+            .run(move |repositories| {
+                async move {
+                    let mut fusen = repositories.get(id).await?;
+                    fusen.authorize(&owner_id)?;
+                    if let Some(title) = title {
+                        fusen.set_title(title);
+                    }
+                    if let Some(note) = note {
+                        fusen.set_note(note);
+                    }
+                    if let Some(color) = color {
+                        fusen.set_color(color);
+                    }
+                    fusen.set_update_at(now);
+                    fusen.record_updated(now);
+
+                    repositories.update(fusen.clone()).await?;
+                    repositories.get(fusen.id().clone()).await
+                }
+                .boxed()
+            })
+            .await?;
+
+        Ok(UpdateFusenOutputData::new(aggregate_root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::error::DomainError;
+    use domain::event::{FusenDomainEvent, FusenEvent, FusenEventKind};
+    use domain::vo::Id;
+    use futures::future::BoxFuture;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    struct MockClock {}
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockFusenRepository {
+        store: Arc<Mutex<HashMap<Id<Fusen>, Fusen>>>,
+    }
+    impl MockFusenRepository {
+        fn new() -> Self {
+            let m = HashMap::new();
+            Self {
+                store: Arc::new(Mutex::new(m)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CreateRepository<Fusen> for MockFusenRepository {
+        async fn create(&self, entity: Fusen) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            m.insert(entity.id().clone(), entity.clone());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            let m = self.store.lock().unwrap();
+            match m.get(&id) {
+                Some(aggregate_root) => Ok(aggregate_root.clone()),
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UpdateRepository<Fusen> for MockFusenRepository {
+        async fn update(&self, entity: Fusen) -> Result<(), DomainError> {
+            let mut m = self.store.lock().unwrap();
+            match m.get_mut(entity.id()) {
+                Some(aggregate_root) => {
+                    *aggregate_root = entity;
+                    Ok(())
+                }
+                None => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockUnitOfWork {
+        fusen_repository: MockFusenRepository,
+    }
+
+    #[async_trait]
+    impl UnitOfWork for MockUnitOfWork {
+        type Repositories = MockFusenRepository;
+
+        async fn run<T, F>(&self, work: F) -> Result<T, DomainError>
+        where
+            T: Send + 'static,
+            F: for<'a> FnOnce(&'a Self::Repositories) -> BoxFuture<'a, Result<T, DomainError>>
+                + Send
+                + 'static,
+        {
+            work(&self.fusen_repository).await
+        }
+    }
+
+    async fn new_unit_of_work() -> MockUnitOfWork {
+        let fusen_repository = MockFusenRepository::new();
+        fusen_repository
+            .create(
+                FusenBuilder::default()
+                    .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        MockUnitOfWork { fusen_repository }
+    }
+
+    #[tokio::test]
+    async fn test_update_fusen_handle() {
+        let sut = UpdateFusenInteractor::new(MockClock {}, new_unit_of_work().await);
+
+        // title のみ
+        let output = sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                Some("new title".to_string()),
+                None,
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.fusen.title().to_string(), "new title".to_string());
+        assert_eq!(output.fusen.note().to_string(), "note".to_string());
+        assert_eq!(
+            output.fusen.create_at().to_string(),
+            "2021-10-17T07:08:37Z".to_string()
+        );
+        assert_eq!(
+            output.fusen.update_at().to_string(),
+            "2021-10-18T00:00:00Z".to_string()
+        );
+        // 保存した fusen が持つ FusenUpdated から Watch のイベントを作る
+        assert!(matches!(
+            output.fusen.events(),
+            [FusenDomainEvent::Updated(_)]
+        ));
+        assert_eq!(
+            FusenEvent::from(&output.fusen.events()[0]),
+            FusenEvent::new(
+                FusenEventKind::Updated,
+                output.fusen.clone(),
+                *output.fusen.update_at()
+            )
+        );
+
+        // note のみ
+        let output = sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                None,
+                Some("new note".to_string()),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.fusen.title().to_string(), "new title".to_string());
+        assert_eq!(output.fusen.note().to_string(), "new note".to_string());
+
+        // 両方
+        let output = sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                Some("title".to_string()),
+                Some("note".to_string()),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.fusen.title().to_string(), "title".to_string());
+        assert_eq!(output.fusen.note().to_string(), "note".to_string());
+        assert_eq!(
+            output.fusen.id().to_string(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+
+        // color のみ
+        let output = sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                None,
+                None,
+                Some("pink".to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.fusen.color(), &FusenColor::Pink);
+        assert_eq!(output.fusen.title().to_string(), "title".to_string());
+    }
+
+    #[tokio::test]
+    async fn test_update_fusen_handle_err() {
+        let sut = UpdateFusenInteractor::new(MockClock {}, new_unit_of_work().await);
+
+        assert!(sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                None,
+                None,
+                None,
+            ))
+            .await
+            .is_err());
+        assert!(sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                Some("".to_string()),
+                None,
+                None,
+            ))
+            .await
+            .is_err());
+        assert!(sut
+            .handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string(),
+                Some("title".to_string()),
+                None,
+                None,
+            ))
+            .await
+            .is_err());
+        assert!(matches!(
+            sut.handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                None,
+                None,
+                None,
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                None,
+                None,
+                Some("black".to_string()),
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sut.handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XZ".to_string(),
+                Some("title".to_string()),
+                None,
+                None,
+            ))
+            .await,
+            Err(UsecaseError::NotFound(_))
+        ));
+        assert!(matches!(
+            sut.handle(UpdateFusenInputData::new(
+                "owner".to_string(),
+                "NOTFOUND_ID".to_string(),
+                Some("title".to_string()),
+                None,
+                None,
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_fusen_handle_permission_denied() {
+        let sut = UpdateFusenInteractor::new(MockClock {}, new_unit_of_work().await);
+
+        assert!(matches!(
+            sut.handle(UpdateFusenInputData::new(
+                "another".to_string(),
+                "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+                Some("new title".to_string()),
+                None,
+                None,
+            ))
+            .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        let stored = sut
+            .unit_of_work
+            .fusen_repository
+            .get("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stored.title().to_string(), "title".to_string());
+        assert!(stored.events().is_empty());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::error::UsecaseError;
+use crate::interactor::AttachmentLimits;
+use crate::port::{Port, UploadAttachmentInputData, UploadAttachmentOutputData};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::*;
+use domain::error::DomainError;
+use domain::repository::*;
+use domain::vo::*;
+use futures::stream::{self, StreamExt};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::Receiver;
+
+#[derive(new)]
+pub struct UploadAttachmentInteractor<I, C, S, A, Bl>
+where
+    I: IdRepository,
+    C: Clock,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+    Bl: BlobStore,
+{
+    id_repository: I,
+    clock: C,
+    fusen_repository: S,
+    attachment_repository: A,
+    blob_store: Bl,
+    limits: AttachmentLimits,
+}
+
+#[async_trait]
+impl<I, C, S, A, Bl> Port<UploadAttachmentInputData, UploadAttachmentOutputData>
+    for UploadAttachmentInteractor<I, C, S, A, Bl>
+where
+    I: IdRepository,
+    C: Clock,
+    S: GetRepository<Fusen>,
+    A: AttachmentRepository,
+    Bl: BlobStore,
+{
+    async fn handle(
+        &self,
+        input: UploadAttachmentInputData,
+    ) -> Result<UploadAttachmentOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let fusen_id = input.fusen_id.parse::<Id<Fusen>>()?;
+        let file_name = input.file_name.parse::<FileName>()?;
+        let content_type = input.content_type.parse::<ContentType>()?;
+        if !self.limits.allows(&content_type) {
+            return Err(UsecaseError::InvalidArgument(format!(
+                "content type {} is not allowed",
+                content_type.essence()
+            )));
+        }
+
+        let fusen = self.fusen_repository.get(fusen_id).await?;
+        fusen.authorize(&owner_id)?;
+
+        let id = self.id_repository.generate::<Attachment>()?;
+        let received = Arc::new(Mutex::new(Received::default()));
+        let content = receive(input.chunks, self.limits.max_size, received.clone());
+        let size = match self.blob_store.put(&id.to_string(), content).await {
+            Ok(size) => size,
+            // 受信側で中断した場合はその理由を返す
+            Err(e) => return Err(received.lock().unwrap().error.take().unwrap_or_else(|| e.into())),
+        };
+        let digest = std::mem::take(&mut received.lock().unwrap().hasher).finalize();
+
+        let attachment = AttachmentBuilder::default()
+            .id(id)
+            .fusen_id(fusen.id().clone())
+            .file_name(file_name)
+            .content_type(content_type)
+            .size(size)
+            .checksum(Checksum::from_sha256(&digest)?)
+            .create_at(self.clock.now())
+            .build()
+            .map_err(anyhow::Error::from)?;
+
+        // 記録できなかった内容は参照されないので消しておく
+        if let Err(e) = self.attachment_repository.add(attachment.clone()).await {
+            let _ = self.blob_store.delete(&attachment.blob_key()).await;
+            return Err(e.into());
+        }
+
+        Ok(UploadAttachmentOutputData::new(attachment))
+    }
+}
+
+#[derive(Default)]
+struct Received {
+    hasher: Sha256,
+    size: u64,
+    error: Option<UsecaseError>,
+}
+
+// BlobStore に渡しながら大きさを数え、checksum を計算する
+fn receive(
+    chunks: Receiver<Result<Vec<u8>, UsecaseError>>,
+    max_size: u64,
+    received: Arc<Mutex<Received>>,
+) -> BlobStream {
+    stream::unfold(chunks, move |mut chunks| {
+        let received = received.clone();
+        async move {
+            let chunk = chunks.recv().await?;
+            let mut received = received.lock().unwrap();
+            let item = match chunk {
+                Ok(chunk) if received.size + chunk.len() as u64 > max_size => {
+                    let message = format!("attachment exceeds {} bytes", max_size);
+                    received.error = Some(UsecaseError::InvalidArgument(message.clone()));
+                    Err(DomainError::InvalidArgument(message))
+                }
+                Ok(chunk) => {
+                    received.size += chunk.len() as u64;
+                    received.hasher.update(&chunk);
+                    Ok(chunk)
+                }
+                Err(e) => {
+                    let message = e.to_string();
+                    received.error = Some(e);
+                    Err(DomainError::InvalidArgument(message))
+                }
+            };
+            Some((item, chunks))
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use std::collections::HashMap;
+    use tokio::sync::mpsc;
+
+    struct MockIdRepository {}
+
+    impl IdRepository for MockIdRepository {
+        fn generate<T>(&self) -> Result<Id<T>, DomainError> {
+            Ok("01FNATTACHXKQ7B1GZ1Z0K0T8R".parse::<Id<T>>().unwrap())
+        }
+    }
+
+    struct MockClock {}
+
+    impl Clock for MockClock {
+        fn now(&self) -> Timestamp {
+            "2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        }
+    }
+
+    struct MockFusenRepository {}
+
+    #[async_trait]
+    impl GetRepository<Fusen> for MockFusenRepository {
+        async fn get(&self, id: Id<Fusen>) -> Result<Fusen, DomainError> {
+            match id.to_string().as_str() {
+                "01F8MECHZX3TBDSZ7XRADM79XE" => Ok(FusenBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                    .title("title".parse::<FusenTitle>().unwrap())
+                    .note("note".parse::<FusenNote>().unwrap())
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockAttachmentRepository {
+        attachments: Arc<Mutex<Vec<Attachment>>>,
+    }
+
+    #[async_trait]
+    impl AttachmentRepository for MockAttachmentRepository {
+        async fn add(&self, attachment: Attachment) -> Result<(), DomainError> {
+            self.attachments.lock().unwrap().push(attachment);
+            Ok(())
+        }
+
+        async fn get(&self, _id: Id<Attachment>) -> Result<Attachment, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn list(&self, _fusen_id: Id<Fusen>) -> Result<Vec<Attachment>, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn orphaned(&self, _limit: usize) -> Result<Vec<Attachment>, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn remove(&self, _ids: Vec<Id<Attachment>>) -> Result<(), DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockBlobStore {
+        blobs: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    }
+
+    #[async_trait]
+    impl BlobStore for MockBlobStore {
+        async fn put(&self, key: &str, content: BlobStream) -> Result<u64, DomainError> {
+            let content = content.try_concat().await?;
+            let size = content.len() as u64;
+            self.blobs.lock().unwrap().insert(key.to_string(), content);
+            Ok(size)
+        }
+
+        async fn get(&self, _key: &str) -> Result<BlobStream, DomainError> {
+            Err(DomainError::Unavailable("not supported".to_string()))
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), DomainError> {
+            self.blobs.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    fn input(
+        owner_id: &str,
+        content_type: &str,
+        chunks: Vec<Result<Vec<u8>, UsecaseError>>,
+    ) -> UploadAttachmentInputData {
+        let (sender, receiver) = mpsc::channel(chunks.len().max(1));
+        for chunk in chunks {
+            sender.try_send(chunk).unwrap();
+        }
+        UploadAttachmentInputData::new(
+            owner_id.to_string(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string(),
+            "screenshot.png".to_string(),
+            content_type.to_string(),
+            receiver,
+        )
+    }
+
+    fn new_sut(
+        attachment_repository: MockAttachmentRepository,
+        blob_store: MockBlobStore,
+    ) -> UploadAttachmentInteractor<
+        MockIdRepository,
+        MockClock,
+        MockFusenRepository,
+        MockAttachmentRepository,
+        MockBlobStore,
+    > {
+        UploadAttachmentInteractor::new(
+            MockIdRepository {},
+            MockClock {},
+            MockFusenRepository {},
+            attachment_repository,
+            blob_store,
+            AttachmentLimits::new(4, vec!["image/*".to_string()]),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_handle() {
+        let attachment_repository = MockAttachmentRepository::default();
+        let blob_store = MockBlobStore::default();
+        let sut = new_sut(attachment_repository.clone(), blob_store.clone());
+
+        let output = sut
+            .handle(input(
+                "owner",
+                "image/png",
+                vec![Ok(b"ab".to_vec()), Ok(b"c".to_vec())],
+            ))
+            .await
+            .unwrap();
+
+        let attachment = output.attachment;
+        assert_eq!(attachment.fusen_id().to_string(), "01F8MECHZX3TBDSZ7XRADM79XE");
+        assert_eq!(attachment.size(), &3);
+        assert_eq!(
+            attachment.checksum().to_string(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string()
+        );
+        assert_eq!(
+            attachment.create_at(),
+            &"2021-10-18T00:00:00Z".parse::<Timestamp>().unwrap()
+        );
+        assert_eq!(
+            blob_store.blobs.lock().unwrap().get(&attachment.blob_key()),
+            Some(&b"abc".to_vec())
+        );
+        assert_eq!(
+            attachment_repository.attachments.lock().unwrap().clone(),
+            vec![attachment]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_handle_err() {
+        let blob_store = MockBlobStore::default();
+        let sut = new_sut(MockAttachmentRepository::default(), blob_store.clone());
+
+        assert!(matches!(
+            sut.handle(input("owner", "text/html", vec![Ok(b"a".to_vec())]))
+                .await,
+            Err(UsecaseError::InvalidArgument(message)) if message == "content type text/html is not allowed"
+        ));
+        assert!(matches!(
+            sut.handle(input(
+                "owner",
+                "image/png",
+                vec![Ok(b"abc".to_vec()), Ok(b"de".to_vec())]
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(message)) if message == "attachment exceeds 4 bytes"
+        ));
+        assert!(matches!(
+            sut.handle(input(
+                "owner",
+                "image/png",
+                vec![
+                    Ok(b"a".to_vec()),
+                    Err(UsecaseError::Unavailable("cancelled".to_string()))
+                ]
+            ))
+            .await,
+            Err(UsecaseError::Unavailable(_))
+        ));
+        assert!(matches!(
+            sut.handle(input("another", "image/png", vec![Ok(b"a".to_vec())]))
+                .await,
+            Err(UsecaseError::PermissionDenied(_))
+        ));
+        // 中断した内容は保存しない
+        assert!(blob_store.blobs.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+use crate::error::UsecaseError;
+use crate::port::{Port, WatchFusenInputData, WatchFusenOutputData};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::entity::Board;
+use domain::error::DomainError;
+use domain::event::FusenEvent;
+use domain::repository::{GetRepository, SubscribeRepository};
+use domain::vo::{EventId, OwnerId};
+use futures::StreamExt;
+use tokio::sync::mpsc;
+
+const EVENT_BUFFER_SIZE: usize = 64;
+
+#[derive(new)]
+pub struct WatchFusenInteractor<B, S>
+where
+    B: GetRepository<Board> + Clone + 'static,
+    S: SubscribeRepository<FusenEvent>,
+{
+    board_repository: B,
+    event_repository: S,
+}
+
+#[async_trait]
+impl<B, S> Port<WatchFusenInputData, WatchFusenOutputData> for WatchFusenInteractor<B, S>
+where
+    B: GetRepository<Board> + Clone + 'static,
+    S: SubscribeRepository<FusenEvent>,
+{
+    async fn handle(
+        &self,
+        input: WatchFusenInputData,
+    ) -> Result<WatchFusenOutputData, UsecaseError> {
+        let owner_id = input.owner_id.parse::<OwnerId>()?;
+        let after = match input.after_event_id.as_str() {
+            "" => None,
+            id => Some(id.parse::<EventId>()?),
+        };
+
+        let mut events = self.event_repository.subscribe(after).await?;
+
+        let board_repository = self.board_repository.clone();
+        let (sender, receiver) = mpsc::channel(EVENT_BUFFER_SIZE);
+        tokio::spawn(async move {
+            loop {
+                // メンバーでない board のイベントしか来ない間は send しないので、切断も待ち受ける
+                let event = tokio::select! {
+                    _ = sender.closed() => break,
+                    event = events.next() => match event {
+                        Some(event) => event,
+                        None => break,
+                    },
+                };
+                // List と同じく board のメンバーにだけ流す。
+                // メンバーから外された場合にすぐ止まるよう、イベントごとに board を確認する
+                let event = match event {
+                    Ok((id, event)) => {
+                        match board_repository.get(event.fusen().board_id().clone()).await {
+                            Ok(board) if board.is_member(&owner_id) => Ok((id, event)),
+                            Ok(_) | Err(DomainError::NotFound(_)) => continue,
+                            Err(e) => Err(e),
+                        }
+                    }
+                    Err(e) => Err(e),
+                };
+                let failed = event.is_err();
+                // 受信側が切断されたら購読をやめる
+                if sender
+                    .send(event.map_err(UsecaseError::from))
+                    .await
+                    .is_err()
+                    || failed
+                {
+                    break;
+                }
+            }
+        });
+
+        Ok(WatchFusenOutputData::new(receiver))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entity::{BoardBuilder, Fusen, FusenBuilder};
+    use domain::event::FusenEventKind;
+    use domain::repository::EventStream;
+    use domain::vo::{BoardName, FusenNote, FusenTitle, Id, Timestamp};
+    use futures::stream;
+    use tokio::sync::oneshot;
+
+    #[derive(Clone)]
+    struct MockBoardRepository {}
+
+    #[async_trait]
+    impl GetRepository<Board> for MockBoardRepository {
+        async fn get(&self, id: Id<Board>) -> Result<Board, DomainError> {
+            match id.to_string().as_str() {
+                "01FMB0ARDXKQ7B1GZ1Z0K0T8R0" => Ok(BoardBuilder::default()
+                    .id(id)
+                    .owner_id("owner".parse::<OwnerId>().unwrap())
+                    .name("board".parse::<BoardName>().unwrap())
+                    .member_ids(vec!["member".parse::<OwnerId>().unwrap()])
+                    .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                    .build()
+                    .unwrap()),
+                _ => Err(DomainError::NotFound("not found entity".to_string())),
+            }
+        }
+    }
+
+    struct MockEventRepository {}
+
+    #[async_trait]
+    impl SubscribeRepository<FusenEvent> for MockEventRepository {
+        async fn subscribe(
+            &self,
+            after: Option<EventId>,
+        ) -> Result<EventStream<FusenEvent>, DomainError> {
+            let after = after.map(|id| id.value()).unwrap_or(0);
+            let events = (1..=3)
+                .filter(|id| *id > after)
+                .map(|id| Ok((EventId::new(id), new_event())))
+                .collect::<Vec<_>>();
+
+            Ok(stream::iter(events).boxed())
+        }
+    }
+
+    // 購読が捨てられたら dropped に知らせる。イベントは流し終えても終わらない
+    struct PendingEventRepository {
+        dropped: std::sync::Mutex<Option<oneshot::Sender<()>>>,
+    }
+
+    #[async_trait]
+    impl SubscribeRepository<FusenEvent> for PendingEventRepository {
+        async fn subscribe(
+            &self,
+            _after: Option<EventId>,
+        ) -> Result<EventStream<FusenEvent>, DomainError> {
+            let dropped = self.dropped.lock().unwrap().take();
+            let events = vec![Ok((EventId::new(1), new_event()))];
+
+            Ok(stream::iter(events)
+                .chain(stream::pending())
+                .map(move |event| {
+                    let _ = &dropped;
+                    event
+                })
+                .boxed())
+        }
+    }
+
+    fn new_event() -> FusenEvent {
+        FusenEvent::new(
+            FusenEventKind::Created,
+            FusenBuilder::default()
+                .id("01F8MECHZX3TBDSZ7XRADM79XE".parse::<Id<Fusen>>().unwrap())
+                .owner_id("owner".parse::<OwnerId>().unwrap())
+                .board_id("01FMB0ARDXKQ7B1GZ1Z0K0T8R0".parse::<Id<Board>>().unwrap())
+                .title("title".parse::<FusenTitle>().unwrap())
+                .note("note".parse::<FusenNote>().unwrap())
+                .create_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .update_at("2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap(),
+            "2021-10-17T07:08:37Z".parse::<Timestamp>().unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle() {
+        let sut = WatchFusenInteractor::new(MockBoardRepository {}, MockEventRepository {});
+
+        let mut output = sut
+            .handle(WatchFusenInputData::new(
+                "owner".to_string(),
+                "1".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let mut ids = Vec::new();
+        while let Some(event) = output.events.recv().await {
+            let (id, event) = event.unwrap();
+            assert_eq!(event, new_event());
+            ids.push(id);
+        }
+        assert_eq!(ids, vec![EventId::new(2), EventId::new(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_member() {
+        let sut = WatchFusenInteractor::new(MockBoardRepository {}, MockEventRepository {});
+
+        // 他のメンバーが作成した fusen のイベントも受け取る
+        let mut output = sut
+            .handle(WatchFusenInputData::new(
+                "member".to_string(),
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let mut count = 0;
+        while let Some(event) = output.events.recv().await {
+            assert_eq!(event.unwrap().1.fusen().owner_id().to_string(), "owner");
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_other_owner() {
+        let sut = WatchFusenInteractor::new(MockBoardRepository {}, MockEventRepository {});
+
+        let mut output = sut
+            .handle(WatchFusenInputData::new(
+                "another".to_string(),
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(output.events.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_disconnected() {
+        let (dropped, stopped) = oneshot::channel();
+        let sut = WatchFusenInteractor::new(
+            MockBoardRepository {},
+            PendingEventRepository {
+                dropped: std::sync::Mutex::new(Some(dropped)),
+            },
+        );
+
+        // 流すイベントがないまま切断されても購読をやめる
+        let output = sut
+            .handle(WatchFusenInputData::new(
+                "another".to_string(),
+                "".to_string(),
+            ))
+            .await
+            .unwrap();
+        drop(output);
+
+        assert!(stopped.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watch_fusen_handle_err() {
+        let sut = WatchFusenInteractor::new(MockBoardRepository {}, MockEventRepository {});
+
+        assert!(matches!(
+            sut.handle(WatchFusenInputData::new(
+                "owner".to_string(),
+                "invalid".to_string()
+            ))
+            .await,
+            Err(UsecaseError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct BatchCreateFusenItem {
+    pub board_id: String,
+    pub title: String,
+    pub note: String,
+}
+
+// partial_success が false の場合は 1 件でも失敗すると何も作成しない
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct BatchCreateFusenInputData {
+    pub owner_id: String,
+    pub items: Vec<BatchCreateFusenItem>,
+    pub partial_success: bool,
+}
+
+impl InputData for BatchCreateFusenInputData {}
+
+// results は items と同じ順に並ぶ
+#[derive(new, Debug)]
+pub struct BatchCreateFusenOutputData {
+    pub results: Vec<Result<Fusen, UsecaseError>>,
+}
+
+impl OutputData for BatchCreateFusenOutputData {}
@@ -0,0 +1,22 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+// partial_success が false の場合は 1 件でも失敗すると何も削除しない
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct BatchDeleteFusenInputData {
+    pub owner_id: String,
+    pub ids: Vec<String>,
+    pub partial_success: bool,
+}
+
+impl InputData for BatchDeleteFusenInputData {}
+
+// results は ids と同じ順に並び、削除した fusen の削除直前の内容を持つ
+#[derive(new, Debug)]
+pub struct BatchDeleteFusenOutputData {
+    pub results: Vec<Result<Fusen, UsecaseError>>,
+}
+
+impl OutputData for BatchDeleteFusenOutputData {}
@@ -0,0 +1,22 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+// partial_success が false の場合は 1 件でも取得できないとエラーになる
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct BatchGetFusenInputData {
+    pub owner_id: String,
+    pub ids: Vec<String>,
+    pub partial_success: bool,
+}
+
+impl InputData for BatchGetFusenInputData {}
+
+// results は ids と同じ順に並ぶ
+#[derive(new, Debug)]
+pub struct BatchGetFusenOutputData {
+    pub results: Vec<Result<Fusen, UsecaseError>>,
+}
+
+impl OutputData for BatchGetFusenOutputData {}
@@ -0,0 +1,19 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Board;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct CreateBoardInputData {
+    pub owner_id: String,
+    pub name: String,
+    pub member_ids: Vec<String>,
+}
+
+impl InputData for CreateBoardInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct CreateBoardOutputData {
+    pub board: Board,
+}
+
+impl OutputData for CreateBoardOutputData {}
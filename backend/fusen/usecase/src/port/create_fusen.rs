@@ -4,6 +4,8 @@ use domain::entity::Fusen;
 
 #[derive(new, Clone, Debug, PartialEq)]
 pub struct CreateFusenInputData {
+    pub owner_id: String,
+    pub board_id: String,
     pub title: String,
     pub note: String,
 }
@@ -0,0 +1,15 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct DeleteBoardInputData {
+    pub owner_id: String,
+    pub id: String,
+}
+
+impl InputData for DeleteBoardInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct DeleteBoardOutputData {}
+
+impl OutputData for DeleteBoardOutputData {}
@@ -3,6 +3,7 @@ use derive_new::new;
 
 #[derive(new, Clone, Debug, PartialEq)]
 pub struct DeleteFusenInputData {
+    pub owner_id: String,
     pub id: String,
 }
 
@@ -0,0 +1,22 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Attachment;
+use tokio::sync::mpsc::Receiver;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct DownloadAttachmentInputData {
+    pub owner_id: String,
+    pub id: String,
+}
+
+impl InputData for DownloadAttachmentInputData {}
+
+#[derive(new, Debug)]
+pub struct DownloadAttachmentOutputData {
+    pub attachment: Attachment,
+    // 内容が attachment の checksum と一致しない場合は最後にエラーを返す
+    pub chunks: Receiver<Result<Vec<u8>, UsecaseError>>,
+}
+
+impl OutputData for DownloadAttachmentOutputData {}
@@ -0,0 +1,20 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+use tokio::sync::mpsc::Receiver;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ExportFusenInputData {
+    pub owner_id: String,
+    pub board_id: String,
+}
+
+impl InputData for ExportFusenInputData {}
+
+#[derive(new, Debug)]
+pub struct ExportFusenOutputData {
+    pub fusens: Receiver<Result<Fusen, UsecaseError>>,
+}
+
+impl OutputData for ExportFusenOutputData {}
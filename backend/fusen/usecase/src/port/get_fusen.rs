@@ -4,7 +4,9 @@ use domain::entity::Fusen;
 
 #[derive(new, Clone, Debug, PartialEq)]
 pub struct GetFusenInputData {
+    pub owner_id: String,
     pub id: String,
+    pub render_format: Option<String>,
 }
 
 impl InputData for GetFusenInputData {}
@@ -12,6 +14,8 @@ impl InputData for GetFusenInputData {}
 #[derive(new, Clone, Debug, PartialEq)]
 pub struct GetFusenOutputData {
     pub fusen: Fusen,
+    // render_format を指定した場合のみ変換した note を返す
+    pub rendered_note: Option<String>,
 }
 
 impl OutputData for GetFusenOutputData {}
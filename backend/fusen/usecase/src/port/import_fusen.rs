@@ -0,0 +1,38 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+// 日時が無い場合は取り込んだ時刻を使う
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ImportFusenRecord {
+    pub id: String,
+    pub title: String,
+    pub note: String,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ImportFusenInputData {
+    pub owner_id: String,
+    pub board_id: String,
+    pub records: Vec<ImportFusenRecord>,
+}
+
+impl InputData for ImportFusenInputData {}
+
+// index は ImportFusenInputData.records の位置
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ImportFusenFailure {
+    pub index: usize,
+    pub id: String,
+    pub reason: String,
+}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ImportFusenOutputData {
+    pub fusens: Vec<Fusen>,
+    pub failures: Vec<ImportFusenFailure>,
+}
+
+impl OutputData for ImportFusenOutputData {}
@@ -0,0 +1,18 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Attachment;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListAttachmentInputData {
+    pub owner_id: String,
+    pub fusen_id: String,
+}
+
+impl InputData for ListAttachmentInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListAttachmentOutputData {
+    pub attachments: Vec<Attachment>,
+}
+
+impl OutputData for ListAttachmentOutputData {}
@@ -0,0 +1,20 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Board;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListBoardInputData {
+    pub owner_id: String,
+    pub page_size: i32,
+    pub page_token: String,
+}
+
+impl InputData for ListBoardInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListBoardOutputData {
+    pub boards: Vec<Board>,
+    pub next_page_token: String,
+}
+
+impl OutputData for ListBoardOutputData {}
@@ -0,0 +1,24 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+use domain::repository::SortOrder;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListFusenInputData {
+    pub owner_id: String,
+    pub board_id: String,
+    pub page_size: i32,
+    pub page_token: String,
+    pub order: SortOrder,
+    pub keyword: String,
+}
+
+impl InputData for ListFusenInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct ListFusenOutputData {
+    pub fusens: Vec<Fusen>,
+    pub next_page_token: String,
+}
+
+impl OutputData for ListFusenOutputData {}
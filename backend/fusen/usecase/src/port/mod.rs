@@ -1,11 +1,43 @@
 #[allow(clippy::module_inception)]
 mod port;
 
+mod batch_create_fusen;
+mod batch_delete_fusen;
+mod batch_get_fusen;
+mod create_board;
 mod create_fusen;
+mod delete_board;
 mod delete_fusen;
+mod download_attachment;
+mod export_fusen;
 mod get_fusen;
+mod import_fusen;
+mod list_attachment;
+mod list_board;
+mod list_fusen;
+mod move_fusen;
+mod rename_board;
+mod update_fusen;
+mod upload_attachment;
+mod watch_fusen;
 
+pub use self::batch_create_fusen::*;
+pub use self::batch_delete_fusen::*;
+pub use self::batch_get_fusen::*;
+pub use self::create_board::*;
 pub use self::create_fusen::*;
+pub use self::delete_board::*;
 pub use self::delete_fusen::*;
+pub use self::download_attachment::*;
+pub use self::export_fusen::*;
 pub use self::get_fusen::*;
+pub use self::import_fusen::*;
+pub use self::list_attachment::*;
+pub use self::list_board::*;
+pub use self::list_fusen::*;
+pub use self::move_fusen::*;
 pub use self::port::*;
+pub use self::rename_board::*;
+pub use self::update_fusen::*;
+pub use self::upload_attachment::*;
+pub use self::watch_fusen::*;
@@ -0,0 +1,28 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+// size が None の場合は大きさを変えない
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct FusenPlacement {
+    pub id: String,
+    pub x: i32,
+    pub y: i32,
+    pub z_index: i32,
+    pub size: Option<(i32, i32)>,
+}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct MoveFusenInputData {
+    pub owner_id: String,
+    pub placements: Vec<FusenPlacement>,
+}
+
+impl InputData for MoveFusenInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct MoveFusenOutputData {
+    pub fusens: Vec<Fusen>,
+}
+
+impl OutputData for MoveFusenOutputData {}
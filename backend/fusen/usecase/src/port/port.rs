@@ -1,9 +1,11 @@
-use anyhow::{Error, Result};
+use crate::error::UsecaseError;
+use async_trait::async_trait;
 
-pub trait InputData {}
-pub trait OutputData {}
+pub trait InputData: Send + Sync {}
+pub trait OutputData: Send + Sync {}
 
 #[mockall::automock]
-pub trait Port<Input: InputData, Output: OutputData> {
-    fn handle(&self, input: Input) -> Result<Output, Error>;
+#[async_trait]
+pub trait Port<Input: InputData + 'static, Output: OutputData + 'static>: Send + Sync {
+    async fn handle(&self, input: Input) -> Result<Output, UsecaseError>;
 }
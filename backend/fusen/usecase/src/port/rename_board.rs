@@ -0,0 +1,19 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Board;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct RenameBoardInputData {
+    pub owner_id: String,
+    pub id: String,
+    pub name: String,
+}
+
+impl InputData for RenameBoardInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct RenameBoardOutputData {
+    pub board: Board,
+}
+
+impl OutputData for RenameBoardOutputData {}
@@ -0,0 +1,22 @@
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Fusen;
+
+// None のフィールドは更新しない
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct UpdateFusenInputData {
+    pub owner_id: String,
+    pub id: String,
+    pub title: Option<String>,
+    pub note: Option<String>,
+    pub color: Option<String>,
+}
+
+impl InputData for UpdateFusenInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct UpdateFusenOutputData {
+    pub fusen: Fusen,
+}
+
+impl OutputData for UpdateFusenOutputData {}
@@ -0,0 +1,24 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::entity::Attachment;
+use tokio::sync::mpsc::Receiver;
+
+#[derive(new, Debug)]
+pub struct UploadAttachmentInputData {
+    pub owner_id: String,
+    pub fusen_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    // 受け取った順に連結したものを内容とする。エラーを送ると保存せずに中断する
+    pub chunks: Receiver<Result<Vec<u8>, UsecaseError>>,
+}
+
+impl InputData for UploadAttachmentInputData {}
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct UploadAttachmentOutputData {
+    pub attachment: Attachment,
+}
+
+impl OutputData for UploadAttachmentOutputData {}
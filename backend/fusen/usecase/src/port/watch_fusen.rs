@@ -0,0 +1,22 @@
+use crate::error::UsecaseError;
+use crate::port::{InputData, OutputData};
+use derive_new::new;
+use domain::event::FusenEvent;
+use domain::vo::EventId;
+use tokio::sync::mpsc::Receiver;
+
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct WatchFusenInputData {
+    pub owner_id: String,
+    // 空の場合は購読開始以降のイベントのみ
+    pub after_event_id: String,
+}
+
+impl InputData for WatchFusenInputData {}
+
+#[derive(new, Debug)]
+pub struct WatchFusenOutputData {
+    pub events: Receiver<Result<(EventId, FusenEvent), UsecaseError>>,
+}
+
+impl OutputData for WatchFusenOutputData {}
@@ -30,7 +30,7 @@ mod tests {
     fn get_dummy_tag(dummy_data: &str) -> Tag {
         let hash = from_str!(TagHash, dummy_data);
         let name = from_str!(TagName, dummy_data);
-        let fusen_id = from_str!(FusenId, dummy_data);
+        let fusen_id = from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE");
         TagBuilder::default()
             .hash(hash)
             .name(name)
@@ -128,19 +128,22 @@ mod tests {
         let tag1_fusen_id_1_and_2 = TagBuilder::default()
             .hash(from_str!(TagHash, "tag1"))
             .name(from_str!(TagName, "tag1"))
-            .fusen_ids(vec![from_str!(FusenId, "f1"), from_str!(FusenId, "f2")])
+            .fusen_ids(vec![
+                from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE"),
+                from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XF"),
+            ])
             .build()
             .unwrap();
         let tag2_fusen_id_1 = TagBuilder::default()
             .hash(from_str!(TagHash, "tag2"))
             .name(from_str!(TagName, "tag2"))
-            .fusen_ids(vec![from_str!(FusenId, "f1")])
+            .fusen_ids(vec![from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE")])
             .build()
             .unwrap();
         let tag3_fusen_id_3 = TagBuilder::default()
             .hash(from_str!(TagHash, "tag3"))
             .name(from_str!(TagName, "tag3"))
-            .fusen_ids(vec![from_str!(FusenId, "f3")])
+            .fusen_ids(vec![from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XG")])
             .build()
             .unwrap();
 
@@ -148,12 +151,16 @@ mod tests {
         sut.create(tag2_fusen_id_1.clone()).unwrap();
         sut.create(tag3_fusen_id_3.clone()).unwrap();
 
-        let tags_with_f1 = sut.get_by_fusen_id(from_str!(FusenId, "f1")).unwrap();
+        let tags_with_f1 = sut
+            .get_by_fusen_id(from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE"))
+            .unwrap();
         for tag in tags_with_f1 {
             assert!(vec![tag1_fusen_id_1_and_2.clone(), tag2_fusen_id_1.clone()].contains(&tag));
         }
 
-        let tags_with_f2 = sut.get_by_fusen_id(from_str!(FusenId, "f2")).unwrap();
+        let tags_with_f2 = sut
+            .get_by_fusen_id(from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XF"))
+            .unwrap();
         assert_eq!(tags_with_f2, vec![tag1_fusen_id_1_and_2.clone()])
     }
 
@@ -170,7 +177,10 @@ mod tests {
         let new_tag = TagBuilder::default()
             .hash(from_str!(TagHash, "dummy"))
             .name(from_str!(TagName, "tag1"))
-            .fusen_ids(vec![from_str!(FusenId, "f1"), from_str!(FusenId, "f2")])
+            .fusen_ids(vec![
+                from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE"),
+                from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XF"),
+            ])
             .build()
             .unwrap();
         sut.update_tag(new_tag.clone()).unwrap();
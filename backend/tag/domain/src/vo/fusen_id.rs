@@ -1,7 +1,8 @@
 use crate::vo::ValueObject;
-use anyhow::Error;
+use anyhow::{bail, Error};
 use std::str::FromStr;
 use std::string::ToString;
+use ulid::Ulid;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FusenId(String);
@@ -12,7 +13,15 @@ impl FromStr for FusenId {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.to_string()))
+        // fusen の Id と同じく ULID のみを受け付け、大文字の正規形で保持する
+        if !s.starts_with(|c: char| ('0'..='7').contains(&c)) {
+            bail!("invalid fusen id {}", s)
+        }
+
+        match Ulid::from_string(s) {
+            Ok(ulid) => Ok(Self(ulid.to_string())),
+            Err(_) => bail!("invalid fusen id {}", s),
+        }
     }
 }
 
@@ -21,3 +30,27 @@ impl ToString for FusenId {
         self.0.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fusen_id() {
+        assert!("01F8MECHZX3TBDSZ7XRADM79XE".parse::<FusenId>().is_ok());
+        assert_eq!(
+            "01f8mechzx3tbdsz7xradm79xe"
+                .parse::<FusenId>()
+                .unwrap()
+                .to_string(),
+            "01F8MECHZX3TBDSZ7XRADM79XE".to_string()
+        );
+
+        assert!("".parse::<FusenId>().is_err());
+        assert!("f1".parse::<FusenId>().is_err());
+        assert!("81F8MECHZX3TBDSZ7XRADM79XE".parse::<FusenId>().is_err());
+        assert!("0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+            .parse::<FusenId>()
+            .is_err());
+    }
+}
@@ -43,7 +43,7 @@ mod tests {
     fn get_dummy_tag(dummy_data: &str) -> Tag {
         let hash = from_str!(TagHash, dummy_data);
         let name = from_str!(TagName, dummy_data);
-        let fusen_id = from_str!(FusenId, dummy_data);
+        let fusen_id = from_str!(FusenId, "01F8MECHZX3TBDSZ7XRADM79XE");
         TagBuilder::default()
             .hash(hash)
             .name(name)
@@ -121,13 +121,13 @@ mod tests {
         let expected_output = GetTagOutputData {
             hash: from_str!(String, "dummy_tag"),
             name: from_str!(String, "dummy_tag"),
-            fusen_ids: vec![from_str!(String, "dummy_tag")],
+            fusen_ids: vec![from_str!(String, "01F8MECHZX3TBDSZ7XRADM79XE")],
         };
         assert_eq!(output, expected_output);
         let not_expected_output = GetTagOutputData {
             hash: from_str!(String, "fake_dummy_tag"),
             name: from_str!(String, "fake_dummy_tag"),
-            fusen_ids: vec![from_str!(String, "fake_dummy_tag")],
+            fusen_ids: vec![from_str!(String, "01F8MECHZX3TBDSZ7XRADM79XF")],
         };
         assert_ne!(output, not_expected_output);
     }